@@ -1,49 +1,111 @@
 use proc_macro::{self, TokenStream};
+use proc_macro2::Span;
 use quote::quote;
 use syn::parse::{Parse, ParseStream, Result};
 use syn::punctuated::Punctuated;
-use syn::{parse_macro_input, Expr, Token};
+use syn::{parse_macro_input, Attribute, Error, Expr, Token};
 
 type Delimited<T> = Punctuated<T, Token![,]>;
-type Vector = Delimited<Expr>;
-type Matrix = Punctuated<Vector, Token![;]>;
+
+struct Row {
+    attrs: Vec<Attribute>,
+    elems: Vec<Expr>,
+    span: Span,
+}
 
 struct Input {
-    matrix: Matrix,
+    rows: Vec<Row>,
+}
+
+impl Parse for Row {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        for attr in &attrs {
+            if !attr.path.is_ident("cfg") {
+                return Err(Error::new_spanned(
+                    attr,
+                    "only `#[cfg(..)]` attributes are supported on rows",
+                ));
+            }
+        }
+
+        let span = input.span();
+        let mut elems = Vec::new();
+        while !input.is_empty() && !input.peek(Token![;]) {
+            elems.push(input.parse()?);
+            if input.is_empty() || input.peek(Token![;]) {
+                break;
+            }
+            if !input.peek(Token![,]) {
+                return Err(input.error("expected `,` or `;`"));
+            }
+            input.parse::<Token![,]>()?;
+        }
+        if elems.is_empty() {
+            return Err(Error::new(span, "expected at least one element in row"));
+        }
+        Ok(Self { attrs, elems, span })
+    }
 }
 
 impl Parse for Input {
     fn parse(input: ParseStream) -> Result<Self> {
-        let matrix = Matrix::parse_terminated_with(input, Vector::parse_separated_nonempty)?;
-        Ok(Self { matrix })
+        let mut rows = Vec::new();
+        while !input.is_empty() {
+            rows.push(input.parse()?);
+            if input.is_empty() {
+                break;
+            }
+            input.parse::<Token![;]>()?;
+        }
+        Ok(Self { rows })
     }
 }
 
 impl Input {
-    fn into_rows(self) -> Vec<Vec<Expr>> {
-        self.matrix
-            .into_iter()
-            .map(|vector| vector.into_iter().collect())
-            .collect()
+    fn into_rows(self) -> Result<Vec<Row>> {
+        let rows = self.rows;
+
+        // Every row must have the same number of elements as the first.
+        if let Some(first) = rows.first() {
+            let n = first.elems.len();
+            for row in &rows {
+                if row.elems.len() != n {
+                    let msg = format!(
+                        "expected {} element{} in row, found {}",
+                        n,
+                        if n == 1 { "" } else { "s" },
+                        row.elems.len()
+                    );
+                    return Err(Error::new(row.span, msg));
+                }
+            }
+        }
+
+        Ok(rows)
     }
 }
 
 #[proc_macro]
 pub fn matrix(input: TokenStream) -> TokenStream {
-    let rows = parse_macro_input!(input as Input).into_rows();
+    let rows = match parse_macro_input!(input as Input).into_rows() {
+        Ok(rows) => rows,
+        Err(err) => return err.to_compile_error().into(),
+    };
 
     // Get the length of the first row, i.e. the number of columns
-    let n = rows.first().map_or(0, Vec::len);
+    let n = rows.first().map_or(0, |row| row.elems.len());
 
-    // Transpose from row-major order to column-major order
+    // Transpose from row-major order to column-major order, any attributes on
+    // a row are applied to each of its elements.
     let columns: Delimited<_> = (0..n)
         .map(|column| {
-            let column: Vector = rows
-                .iter()
-                .filter_map(|row| row.get(column))
-                .cloned()
-                .collect();
-            quote! { [ #column ] }
+            let column = rows.iter().map(|row| {
+                let attrs = &row.attrs;
+                let elem = &row.elems[column];
+                quote! { #(#attrs)* #elem }
+            });
+            quote! { [ #(#column),* ] }
         })
         .collect();
 
@@ -1,5 +1,6 @@
+use core::fmt;
 use core::iter::*;
-use core::slice;
+use core::mem;
 
 use crate::Stride;
 
@@ -11,16 +12,43 @@ use crate::Stride;
 ///
 /// This struct is created by the [`iter()`][`Stride::iter()`] method on
 /// strided slices.
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct Iter<'a, T, const S: usize> {
-    iter: StepBy<slice::Iter<'a, T>>,
+    // The underlying data starting at the next element to be yielded.
+    data: &'a [T],
 }
 
 impl<'a, T, const S: usize> Iter<'a, T, S> {
     pub(crate) fn new(stride: &'a Stride<T, S>) -> Self {
-        Self {
-            iter: stride.data.iter().step_by(S),
-        }
+        Self { data: &stride.data }
+    }
+
+    /// Returns the remaining elements of this iterator as a strided slice.
+    ///
+    /// *See [`slice::Iter::as_slice()`].*
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5, 6]);
+    /// let mut iter = stride.iter();
+    /// assert_eq!(iter.as_stride(), &[1, 3, 5]);
+    /// iter.next();
+    /// assert_eq!(iter.as_stride(), &[3, 5]);
+    /// ```
+    pub fn as_stride(&self) -> &'a Stride<T, S> {
+        Stride::new(self.data)
+    }
+}
+
+impl<T, const S: usize> fmt::Debug for Iter<'_, T, S>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Iter").field(&self.as_stride()).finish()
     }
 }
 
@@ -28,7 +56,9 @@ impl<'a, T, const S: usize> Iterator for Iter<'a, T, S> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.iter.next()
+        let (first, rest) = self.data.split_first()?;
+        self.data = rest.get(S - 1..).unwrap_or(&[]);
+        Some(first)
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -47,13 +77,16 @@ impl<'a, T, const S: usize> Iterator for Iter<'a, T, S> {
 
 impl<'a, T, const S: usize> DoubleEndedIterator for Iter<'a, T, S> {
     fn next_back(&mut self) -> Option<Self::Item> {
-        self.iter.next_back()
+        let i = self.len().checked_sub(1)? * S;
+        let (rest, last) = self.data.split_at(i);
+        self.data = rest;
+        last.first()
     }
 }
 
 impl<'a, T, const S: usize> ExactSizeIterator for Iter<'a, T, S> {
     fn len(&self) -> usize {
-        self.iter.len()
+        self.as_stride().len()
     }
 }
 
@@ -76,24 +109,66 @@ impl<'a, T, const S: usize> IntoIterator for &'a Stride<T, S> {
 ///
 /// This struct is created by the [`iter_mut()`][`Stride::iter_mut()`] method on
 /// strided slices.
-#[derive(Debug)]
 pub struct IterMut<'a, T, const S: usize> {
-    iter: StepBy<slice::IterMut<'a, T>>,
+    // The underlying data starting at the next element to be yielded.
+    data: &'a mut [T],
 }
 
 impl<'a, T, const S: usize> IterMut<'a, T, S> {
     pub(crate) fn new(stride: &'a mut Stride<T, S>) -> Self {
         Self {
-            iter: stride.data.iter_mut().step_by(S),
+            data: &mut stride.data,
         }
     }
+
+    /// Returns the remaining elements of this iterator as a strided slice.
+    ///
+    /// *See [`slice::IterMut::as_slice()`].*
+    pub fn as_stride(&self) -> &Stride<T, S> {
+        Stride::new(self.data)
+    }
+
+    /// Converts this iterator into a mutable strided slice of the remaining
+    /// elements.
+    ///
+    /// *See [`slice::IterMut::into_slice()`].*
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let slice = &mut [1, 2, 3, 4, 5, 6];
+    /// let mut iter = Stride::<_, 2>::new_mut(slice).iter_mut();
+    /// iter.next();
+    /// let rest = iter.into_stride();
+    /// rest[0] = 7;
+    /// assert_eq!(slice, &[1, 2, 7, 4, 5, 6]);
+    /// ```
+    pub fn into_stride(self) -> &'a mut Stride<T, S> {
+        Stride::new_mut(self.data)
+    }
+}
+
+impl<T, const S: usize> fmt::Debug for IterMut<'_, T, S>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IterMut").field(&self.as_stride()).finish()
+    }
 }
 
 impl<'a, T, const S: usize> Iterator for IterMut<'a, T, S> {
     type Item = &'a mut T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.iter.next()
+        let (first, rest) = mem::take(&mut self.data).split_first_mut()?;
+        self.data = match rest.get_mut(S - 1..) {
+            Some(rest) => rest,
+            None => &mut [],
+        };
+        Some(first)
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -112,13 +187,16 @@ impl<'a, T, const S: usize> Iterator for IterMut<'a, T, S> {
 
 impl<'a, T, const S: usize> DoubleEndedIterator for IterMut<'a, T, S> {
     fn next_back(&mut self) -> Option<Self::Item> {
-        self.iter.next_back()
+        let i = self.len().checked_sub(1)? * S;
+        let (rest, last) = mem::take(&mut self.data).split_at_mut(i);
+        self.data = rest;
+        last.first_mut()
     }
 }
 
 impl<'a, T, const S: usize> ExactSizeIterator for IterMut<'a, T, S> {
     fn len(&self) -> usize {
-        self.iter.len()
+        self.as_stride().len()
     }
 }
 
@@ -132,3 +210,152 @@ impl<'a, T, const S: usize> IntoIterator for &'a mut Stride<T, S> {
         self.iter_mut()
     }
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// Chunk iteration
+////////////////////////////////////////////////////////////////////////////////
+
+/// Divides a stride into two at an index.
+///
+/// Unlike indexing with `..mid` this works even if the underlying slice does
+/// not end on a multiple of the stride.
+fn split_at<T, const S: usize>(
+    stride: &Stride<T, S>,
+    mid: usize,
+) -> (&Stride<T, S>, &Stride<T, S>) {
+    let (head, tail) = stride.data.split_at((mid * S).min(stride.data.len()));
+    (Stride::new(head), Stride::new(tail))
+}
+
+/// An iterator over a stride in non-overlapping chunks, starting at the
+/// beginning of the stride.
+///
+/// This struct is created by the [`chunks()`][`Stride::chunks()`] method on
+/// strided slices.
+#[derive(Debug, Clone)]
+pub struct Chunks<'a, T, const S: usize> {
+    stride: &'a Stride<T, S>,
+    size: usize,
+}
+
+impl<'a, T, const S: usize> Chunks<'a, T, S> {
+    pub(crate) fn new(stride: &'a Stride<T, S>, size: usize) -> Self {
+        Self { stride, size }
+    }
+}
+
+impl<'a, T, const S: usize> Iterator for Chunks<'a, T, S> {
+    type Item = &'a Stride<T, S>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.stride.is_empty() {
+            return None;
+        }
+        let mid = self.size.min(self.stride.len());
+        let (head, tail) = split_at(self.stride, mid);
+        self.stride = tail;
+        Some(head)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<'a, T, const S: usize> DoubleEndedIterator for Chunks<'a, T, S> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let len = self.stride.len();
+        if len == 0 {
+            return None;
+        }
+        let rem = len % self.size;
+        let mid = len - if rem == 0 { self.size } else { rem };
+        let (head, tail) = split_at(self.stride, mid);
+        self.stride = head;
+        Some(tail)
+    }
+}
+
+impl<'a, T, const S: usize> ExactSizeIterator for Chunks<'a, T, S> {
+    fn len(&self) -> usize {
+        self.stride.len().div_ceil(self.size)
+    }
+}
+
+impl<'a, T, const S: usize> FusedIterator for Chunks<'a, T, S> {}
+
+/// An iterator over a stride in non-overlapping chunks, starting at the end of
+/// the stride.
+///
+/// This struct is created by the [`rchunks()`][`Stride::rchunks()`] method on
+/// strided slices.
+#[derive(Debug, Clone)]
+pub struct RChunks<'a, T, const S: usize> {
+    stride: &'a Stride<T, S>,
+    size: usize,
+}
+
+impl<'a, T, const S: usize> RChunks<'a, T, S> {
+    pub(crate) fn new(stride: &'a Stride<T, S>, size: usize) -> Self {
+        Self { stride, size }
+    }
+}
+
+impl<'a, T, const S: usize> Iterator for RChunks<'a, T, S> {
+    type Item = &'a Stride<T, S>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let len = self.stride.len();
+        if len == 0 {
+            return None;
+        }
+        let mid = len - self.size.min(len);
+        let (head, tail) = split_at(self.stride, mid);
+        self.stride = head;
+        Some(tail)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<'a, T, const S: usize> DoubleEndedIterator for RChunks<'a, T, S> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let len = self.stride.len();
+        if len == 0 {
+            return None;
+        }
+        let rem = len % self.size;
+        let mid = if rem == 0 { self.size } else { rem };
+        let (head, tail) = split_at(self.stride, mid);
+        self.stride = tail;
+        Some(head)
+    }
+}
+
+impl<'a, T, const S: usize> ExactSizeIterator for RChunks<'a, T, S> {
+    fn len(&self) -> usize {
+        self.stride.len().div_ceil(self.size)
+    }
+}
+
+impl<'a, T, const S: usize> FusedIterator for RChunks<'a, T, S> {}
@@ -48,7 +48,7 @@ mod ops;
 use core::fmt;
 
 pub use crate::index::StrideIndex;
-pub use crate::iter::{Iter, IterMut};
+pub use crate::iter::{Chunks, Iter, IterMut, RChunks};
 
 /// A constant strided slice.
 #[repr(transparent)]
@@ -315,6 +315,66 @@ impl<T, const S: usize> Stride<T, S> {
     pub fn iter_mut(&mut self) -> IterMut<T, S> {
         IterMut::new(self)
     }
+
+    /// Returns an iterator over `size` elements of the stride at a time,
+    /// starting at the beginning of the stride.
+    ///
+    /// The chunks are strided slices and do not overlap. If `size` does not
+    /// divide the length of the stride, then the last chunk will be shorter.
+    ///
+    /// *See [`slice::chunks()`].*
+    ///
+    /// # Panics
+    ///
+    /// If `size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+    /// let mut iter = stride.chunks(2);
+    /// assert_eq!(iter.next().unwrap(), &[1, 3]);
+    /// assert_eq!(iter.next().unwrap(), &[5, 7]);
+    /// assert_eq!(iter.next().unwrap(), &[9]);
+    /// assert!(iter.next().is_none());
+    /// ```
+    #[track_caller]
+    pub fn chunks(&self, size: usize) -> Chunks<'_, T, S> {
+        assert!(size != 0, "chunk size must be non-zero");
+        Chunks::new(self, size)
+    }
+
+    /// Returns an iterator over `size` elements of the stride at a time,
+    /// starting at the end of the stride.
+    ///
+    /// The chunks are strided slices and do not overlap. If `size` does not
+    /// divide the length of the stride, then the last chunk will be shorter.
+    ///
+    /// *See [`slice::rchunks()`].*
+    ///
+    /// # Panics
+    ///
+    /// If `size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stride::Stride;
+    /// #
+    /// let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+    /// let mut iter = stride.rchunks(2);
+    /// assert_eq!(iter.next().unwrap(), &[7, 9]);
+    /// assert_eq!(iter.next().unwrap(), &[3, 5]);
+    /// assert_eq!(iter.next().unwrap(), &[1]);
+    /// assert!(iter.next().is_none());
+    /// ```
+    #[track_caller]
+    pub fn rchunks(&self, size: usize) -> RChunks<'_, T, S> {
+        assert!(size != 0, "chunk size must be non-zero");
+        RChunks::new(self, size)
+    }
 }
 
 impl<T> Stride<T, 1> {
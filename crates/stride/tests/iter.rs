@@ -36,3 +36,131 @@ fn stride_iter_len() {
     let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5, 6]);
     assert_eq!(stride.iter().len(), 3);
 }
+
+#[test]
+fn stride_iter_clone() {
+    let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5, 6]);
+    let mut iter = stride.iter();
+    iter.next();
+    let vec: Vec<_> = iter.clone().collect();
+    assert_eq!(vec, [&3, &5]);
+    assert_eq!(iter.count(), 2);
+}
+
+#[test]
+fn stride_chunks() {
+    let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+    let chunks: Vec<_> = stride.chunks(2).collect();
+    assert_eq!(chunks.len(), 3);
+    assert_eq!(chunks[0], &[1, 3]);
+    assert_eq!(chunks[1], &[5, 7]);
+    assert_eq!(chunks[2], &[9]);
+    assert_eq!(stride.chunks(2).len(), 3);
+    assert_eq!(stride.chunks(5).len(), 1);
+    assert_eq!(stride.chunks(6).next().unwrap(), &[1, 3, 5, 7, 9]);
+}
+
+#[test]
+fn stride_chunks_rev() {
+    let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+    let chunks: Vec<_> = stride.chunks(2).rev().collect();
+    assert_eq!(chunks[0], &[9]);
+    assert_eq!(chunks[1], &[5, 7]);
+    assert_eq!(chunks[2], &[1, 3]);
+    let mut iter = stride.chunks(4);
+    assert_eq!(iter.next_back().unwrap(), &[9]);
+    assert_eq!(iter.next().unwrap(), &[1, 3, 5, 7]);
+    assert!(iter.next_back().is_none());
+    assert_eq!(stride.chunks(5).last().unwrap(), &[1, 3, 5, 7, 9]);
+}
+
+#[test]
+fn stride_rchunks() {
+    let stride = Stride::<_, 3>::new(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+    let chunks: Vec<_> = stride.rchunks(3).collect();
+    assert_eq!(chunks.len(), 2);
+    assert_eq!(chunks[0], &[4, 7, 10]);
+    assert_eq!(chunks[1], &[1]);
+    let chunks: Vec<_> = stride.rchunks(3).rev().collect();
+    assert_eq!(chunks[0], &[1]);
+    assert_eq!(chunks[1], &[4, 7, 10]);
+    assert_eq!(stride.rchunks(2).len(), 2);
+    assert_eq!(stride.rchunks(2).last().unwrap(), &[1, 4]);
+}
+
+#[test]
+fn stride_chunks_empty() {
+    let stride = Stride::<i32, 2>::new(&[]);
+    assert_eq!(stride.chunks(3).count(), 0);
+    assert_eq!(stride.rchunks(3).next_back(), None);
+}
+
+#[test]
+#[should_panic(expected = "chunk size must be non-zero")]
+fn stride_chunks_zero() {
+    let stride = Stride::<_, 2>::new(&[1, 2, 3, 4]);
+    stride.chunks(0);
+}
+
+#[test]
+#[should_panic(expected = "chunk size must be non-zero")]
+fn stride_rchunks_zero() {
+    let stride = Stride::<_, 2>::new(&[1, 2, 3, 4]);
+    stride.rchunks(0);
+}
+
+#[test]
+fn stride_chunks_uneven() {
+    let stride = Stride::<_, 3>::new(&[1, 2, 3, 4, 5, 6, 7, 8]);
+    let chunks: Vec<_> = stride.chunks(2).collect();
+    assert_eq!(chunks[0], &[1, 4]);
+    assert_eq!(chunks[1], &[7]);
+    assert_eq!(stride.chunks(3).next().unwrap(), &[1, 4, 7]);
+    assert_eq!(stride.rchunks(3).next().unwrap(), &[1, 4, 7]);
+}
+
+#[test]
+fn stride_iter_as_stride() {
+    let stride = Stride::<_, 3>::new(&[1, 2, 3, 4, 5, 6, 7]);
+    let mut iter = stride.iter();
+    assert_eq!(iter.as_stride(), &[1, 4, 7]);
+    assert_eq!(iter.next_back(), Some(&7));
+    assert_eq!(iter.as_stride(), &[1, 4]);
+    assert_eq!(iter.next(), Some(&1));
+    assert_eq!(iter.as_stride(), &[4]);
+    assert_eq!(iter.next(), Some(&4));
+    assert!(iter.as_stride().is_empty());
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+}
+
+#[test]
+fn stride_iter_debug() {
+    let stride = Stride::<_, 2>::new(&[1, 2, 3, 4, 5]);
+    let mut iter = stride.iter();
+    iter.next();
+    assert_eq!(format!("{:?}", iter), "Iter([3, 5])");
+}
+
+#[test]
+fn stride_iter_mut_into_stride() {
+    let slice = &mut [1, 2, 3, 4, 5, 6, 7];
+    let stride = Stride::<_, 3>::new_mut(slice);
+    let mut iter = stride.iter_mut();
+    *iter.next().unwrap() = 0;
+    *iter.next_back().unwrap() = 0;
+    assert_eq!(iter.len(), 1);
+    assert_eq!(iter.as_stride(), &[4]);
+    assert_eq!(format!("{:?}", iter), "IterMut([4])");
+    let rest = iter.into_stride();
+    rest[0] = 9;
+    assert_eq!(slice, &[0, 2, 3, 9, 5, 6, 0]);
+}
+
+#[test]
+fn stride_iter_mut_rev() {
+    let slice = &mut [1, 2, 3, 4, 5];
+    let stride = Stride::<_, 2>::new_mut(slice);
+    let vec: Vec<_> = stride.iter_mut().rev().map(|x| *x).collect();
+    assert_eq!(vec, [5, 3, 1]);
+}
@@ -0,0 +1,169 @@
+//! Affine transformations.
+
+use core::iter::Sum;
+use core::ops::{Add, Mul};
+
+use crate::{linalg, Float, Matrix, One, Vector, Zero};
+
+/// An affine transformation in `N` dimensions.
+///
+/// This is stored as a linear transformation followed by a translation, which
+/// takes up less space than the equivalent homogeneous matrix. Points are
+/// transformed using both parts, whereas vectors (directions) are only
+/// transformed using the linear part.
+///
+/// # Examples
+///
+/// ```
+/// # use vectrix::{matrix, vector, Affine2};
+/// #
+/// let a = Affine2::new(matrix![0, -1; 1, 0], vector![1, 1]);
+/// assert_eq!(a.transform_point(&vector![1, 0]), vector![1, 2]);
+/// assert_eq!(a.transform_vector(&vector![1, 0]), vector![0, 1]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Affine<T, const N: usize> {
+    linear: Matrix<T, N, N>,
+    translation: Vector<T, N>,
+}
+
+/// An affine transformation in two dimensions.
+pub type Affine2<T> = Affine<T, 2>;
+
+/// An affine transformation in three dimensions.
+pub type Affine3<T> = Affine<T, 3>;
+
+impl<T, const N: usize> Affine<T, N> {
+    /// Create a new affine transformation from a linear transformation and a
+    /// translation.
+    #[inline]
+    pub const fn new(linear: Matrix<T, N, N>, translation: Vector<T, N>) -> Self {
+        Self {
+            linear,
+            translation,
+        }
+    }
+
+    /// Returns the identity transformation.
+    #[must_use]
+    pub fn identity() -> Self
+    where
+        T: Copy + One + Zero,
+    {
+        Self::new(Matrix::identity(), Vector::zero())
+    }
+
+    /// Create a new affine transformation from a linear transformation only.
+    #[must_use]
+    pub fn from_linear(linear: Matrix<T, N, N>) -> Self
+    where
+        T: Copy + Zero,
+    {
+        Self::new(linear, Vector::zero())
+    }
+
+    /// Create a new affine transformation from a translation only.
+    #[must_use]
+    pub fn from_translation(translation: Vector<T, N>) -> Self
+    where
+        T: Copy + One + Zero,
+    {
+        Self::new(Matrix::identity(), translation)
+    }
+
+    /// Returns a reference to the linear part of this transformation.
+    #[inline]
+    pub const fn linear(&self) -> &Matrix<T, N, N> {
+        &self.linear
+    }
+
+    /// Returns a reference to the translation part of this transformation.
+    #[inline]
+    pub const fn translation(&self) -> &Vector<T, N> {
+        &self.translation
+    }
+}
+
+impl<T, const N: usize> Affine<T, N>
+where
+    T: Copy + Zero + Add<Output = T> + Mul<Output = T> + Sum,
+{
+    /// Transforms a point, applying both the linear part and the translation.
+    #[must_use]
+    pub fn transform_point(&self, point: &Vector<T, N>) -> Vector<T, N> {
+        self.linear * *point + self.translation
+    }
+
+    /// Transforms a vector, applying only the linear part.
+    #[must_use]
+    pub fn transform_vector(&self, vector: &Vector<T, N>) -> Vector<T, N> {
+        self.linear * *vector
+    }
+}
+
+impl<T: Float + Sum, const N: usize> Affine<T, N> {
+    /// Returns the inverse of this transformation.
+    ///
+    /// Returns `None` if the linear part is singular.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{matrix, vector, Affine2};
+    /// #
+    /// let a = Affine2::new(matrix![2.0, 0.0; 0.0, 4.0], vector![1.0, 1.0]);
+    /// let inv = a.inverse().unwrap();
+    /// assert_eq!(inv.transform_point(&vector![3.0, 5.0]), vector![1.0, 1.0]);
+    /// ```
+    #[must_use]
+    pub fn inverse(&self) -> Option<Self> {
+        let linear = linalg::solve(self.linear, Matrix::identity())?;
+        Some(Self::new(linear, -(linear * self.translation)))
+    }
+}
+
+impl<T, const N: usize> Mul for Affine<T, N>
+where
+    T: Copy + Zero + Add<Output = T> + Mul<Output = T> + Sum,
+{
+    type Output = Self;
+
+    /// Composes two transformations, the result applies `rhs` first and then
+    /// `self`.
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.linear * rhs.linear,
+            self.linear * rhs.translation + self.translation,
+        )
+    }
+}
+
+impl<T: Copy + Zero + One> From<Affine<T, 2>> for Matrix<T, 3, 3> {
+    /// Converts the transformation to a homogeneous matrix.
+    fn from(affine: Affine<T, 2>) -> Self {
+        homogeneous(&affine)
+    }
+}
+
+impl<T: Copy + Zero + One> From<Affine<T, 3>> for Matrix<T, 4, 4> {
+    /// Converts the transformation to a homogeneous matrix.
+    fn from(affine: Affine<T, 3>) -> Self {
+        homogeneous(&affine)
+    }
+}
+
+/// Returns the `P × P` homogeneous matrix for the transformation, `P` must be
+/// equal to `N + 1`.
+fn homogeneous<T, const N: usize, const P: usize>(affine: &Affine<T, N>) -> Matrix<T, P, P>
+where
+    T: Copy + Zero + One,
+{
+    let mut matrix = Matrix::identity();
+    for j in 0..N {
+        for i in 0..N {
+            matrix[(i, j)] = affine.linear[(i, j)];
+        }
+        matrix[(j, N)] = affine.translation[j];
+    }
+    matrix
+}
@@ -0,0 +1,130 @@
+//! Compile time assertions on matrix dimensions.
+
+/// Asserts at compile time that a matrix with `M` rows and `N` columns is
+/// square.
+///
+/// Referencing [`AssertSquare::OK`] in a function will cause compilation to
+/// fail if the assertion does not hold for the instantiated dimensions. This is
+/// useful for methods that are defined for any matrix size but only make sense
+/// for square matrices.
+///
+/// # Examples
+///
+/// ```
+/// # use vectrix::{AssertSquare, Matrix};
+/// #
+/// fn trace<const M: usize, const N: usize>(m: &Matrix<i64, M, N>) -> i64 {
+///     let () = AssertSquare::<M, N>::OK;
+///     (0..M).map(|i| m[(i, i)]).sum()
+/// }
+/// ```
+#[derive(Debug)]
+pub struct AssertSquare<const M: usize, const N: usize>;
+
+impl<const M: usize, const N: usize> AssertSquare<M, N> {
+    /// Evaluates to `()` if the assertion holds and fails to compile otherwise.
+    pub const OK: () = assert!(M == N, "the matrix must be square");
+}
+
+/// Asserts at compile time that a matrix with `M` rows and `N` columns is the
+/// same size as a matrix with `P` rows and `Q` columns.
+///
+/// Referencing [`AssertSameSize::OK`] in a function will cause compilation to
+/// fail if the assertion does not hold for the instantiated dimensions.
+///
+/// # Examples
+///
+/// ```
+/// # use vectrix::{AssertSameSize, Matrix};
+/// #
+/// fn first_eq<const M: usize, const N: usize, const P: usize, const Q: usize>(
+///     a: &Matrix<i64, M, N>,
+///     b: &Matrix<i64, P, Q>,
+/// ) -> bool {
+///     let () = AssertSameSize::<M, N, P, Q>::OK;
+///     a[0] == b[0]
+/// }
+/// ```
+#[derive(Debug)]
+pub struct AssertSameSize<const M: usize, const N: usize, const P: usize, const Q: usize>;
+
+impl<const M: usize, const N: usize, const P: usize, const Q: usize> AssertSameSize<M, N, P, Q> {
+    /// Evaluates to `()` if the assertion holds and fails to compile otherwise.
+    pub const OK: () = assert!(M == P && N == Q, "the matrices must be the same size");
+}
+
+/// Asserts at compile time that `I` is a valid index into the elements of a
+/// matrix with `M` rows and `N` columns.
+///
+/// Referencing [`AssertInBounds::OK`] in a function will cause compilation to
+/// fail if the assertion does not hold for the instantiated dimensions.
+///
+/// # Examples
+///
+/// Accessing an out of bounds component fails to compile.
+///
+/// ```compile_fail
+/// # use vectrix::vector;
+/// #
+/// let v = vector![1, 2, 3, 4, 5, 6, 7];
+/// v.comp::<7>();
+/// ```
+#[derive(Debug)]
+pub struct AssertInBounds<const I: usize, const M: usize, const N: usize>;
+
+impl<const I: usize, const M: usize, const N: usize> AssertInBounds<I, M, N> {
+    /// Evaluates to `()` if the assertion holds and fails to compile otherwise.
+    pub const OK: () = assert!(I < M * N, "the index must be in bounds");
+}
+
+/// Asserts at compile time that an array of length `L` has the same number of
+/// elements as a matrix with `M` rows and `N` columns.
+///
+/// Referencing [`AssertLength::OK`] in a function will cause compilation to
+/// fail if the assertion does not hold for the instantiated dimensions.
+///
+/// # Examples
+///
+/// Converting to an array of the wrong length fails to compile.
+///
+/// ```compile_fail
+/// # use vectrix::matrix;
+/// #
+/// let m = matrix![1, 2; 3, 4];
+/// let a: [i64; 5] = m.into_array();
+/// ```
+#[derive(Debug)]
+pub struct AssertLength<const L: usize, const M: usize, const N: usize>;
+
+impl<const L: usize, const M: usize, const N: usize> AssertLength<L, M, N> {
+    /// Evaluates to `()` if the assertion holds and fails to compile otherwise.
+    pub const OK: () = assert!(L == M * N, "the array length must equal the matrix size");
+}
+
+/// Asserts at compile time that a stride of `S` visits the main diagonal of a
+/// square matrix with `M` rows, i.e. that `S` is equal to `M + 1`.
+///
+/// Referencing [`AssertDiagonalStride::OK`] in a function will cause
+/// compilation to fail if the assertion does not hold for the instantiated
+/// dimensions.
+///
+/// # Examples
+///
+/// Using the wrong stride fails to compile.
+///
+/// ```compile_fail
+/// # use vectrix::matrix;
+/// #
+/// let mut m = matrix![1, 2; 3, 4];
+/// m.diagonal_mut::<2>();
+/// ```
+#[derive(Debug)]
+pub struct AssertDiagonalStride<const S: usize, const M: usize>;
+
+impl<const S: usize, const M: usize> AssertDiagonalStride<S, M> {
+    /// Evaluates to `()` if the assertion holds and fails to compile otherwise.
+    pub const OK: () = assert!(
+        S == M + 1,
+        "the stride must be one more than the number of rows"
+    );
+}
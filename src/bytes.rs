@@ -0,0 +1,67 @@
+//! Conversion to and from a stable byte representation.
+
+use crate::{new, Error, LeBytes, Matrix};
+
+impl<T: LeBytes, const M: usize, const N: usize> Matrix<T, M, N> {
+    /// Writes the elements of this matrix into the provided buffer, returning
+    /// the number of bytes written.
+    ///
+    /// Elements are written in column-major order, each using its
+    /// little-endian representation. The output is therefore the same on all
+    /// platforms which makes it suitable for checksums and hashing. Exactly
+    /// `M * N * T::SIZE` bytes are written to the start of the buffer.
+    ///
+    /// # Errors
+    ///
+    /// If the buffer is smaller than `M * N * T::SIZE` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let m = matrix![1_u16, 2; 3, 4];
+    /// let mut buf = [0; 8];
+    /// assert_eq!(m.write_into(&mut buf), Ok(8));
+    /// assert_eq!(buf, [1, 0, 3, 0, 2, 0, 4, 0]);
+    /// ```
+    pub fn write_into(&self, buf: &mut [u8]) -> Result<usize, Error> {
+        let len = M * N * T::SIZE;
+        if buf.len() < len {
+            return Err(Error::LengthMismatch);
+        }
+        for (elem, chunk) in self.iter().zip(buf.chunks_exact_mut(T::SIZE)) {
+            elem.write_le_bytes(chunk);
+        }
+        Ok(len)
+    }
+
+    /// Reads a matrix from the provided buffer.
+    ///
+    /// This is the inverse of [`.write_into()`][Matrix::write_into], the
+    /// elements are read in column-major order from the first
+    /// `M * N * T::SIZE` bytes of the buffer.
+    ///
+    /// # Errors
+    ///
+    /// If the buffer is smaller than `M * N * T::SIZE` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{matrix, Matrix};
+    /// #
+    /// let buf = [1, 0, 3, 0, 2, 0, 4, 0];
+    /// let m = Matrix::<u16, 2, 2>::read_from(&buf);
+    /// assert_eq!(m, Ok(matrix![1, 2; 3, 4]));
+    /// ```
+    pub fn read_from(buf: &[u8]) -> Result<Self, Error> {
+        let len = M * N * T::SIZE;
+        if buf.len() < len {
+            return Err(Error::LengthMismatch);
+        }
+        let iter = buf[..len].chunks_exact(T::SIZE).map(T::read_le_bytes);
+        // SAFETY: the buffer has at least `M * N` chunks of `T::SIZE` bytes.
+        Ok(unsafe { new::collect_unchecked(iter) })
+    }
+}
@@ -0,0 +1,75 @@
+//! The error type for fallible operations.
+
+use core::fmt;
+
+/// An error returned by fallible operations in this crate.
+///
+/// This type implements [`Display`][fmt::Display] and, when the `std` feature
+/// is enabled, [`std::error::Error`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Error {
+    /// An index was out of bounds.
+    IndexOutOfBounds,
+    /// Two or more indices referred to the same location.
+    OverlappingIndices,
+    /// The number of elements did not match the size of the matrix.
+    LengthMismatch,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::IndexOutOfBounds => f.write_str("an index is out of bounds"),
+            Self::OverlappingIndices => f.write_str("there were overlapping indices"),
+            Self::LengthMismatch => f.write_str("the length does not match the matrix size"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+/// An error returned when an iterator doesn't yield enough elements to fill a
+/// matrix.
+///
+/// This is returned by [`Matrix::try_from_iter()`][crate::Matrix::try_from_iter].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CollectError {
+    pub(crate) len: usize,
+    pub(crate) expected: usize,
+}
+
+impl CollectError {
+    /// Returns the number of elements the iterator yielded.
+    #[inline]
+    pub fn yielded(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the number of elements that were required to fill the matrix.
+    #[inline]
+    pub fn expected(&self) -> usize {
+        self.expected
+    }
+}
+
+impl fmt::Display for CollectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "iterator yielded {} elements but {} were expected",
+            self.len, self.expected
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CollectError {}
+
+impl From<CollectError> for Error {
+    #[inline]
+    fn from(_: CollectError) -> Self {
+        Self::LengthMismatch
+    }
+}
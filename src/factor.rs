@@ -0,0 +1,81 @@
+//! Elementary transformation matrices used as building blocks for
+//! decompositions.
+
+use core::iter::Sum;
+
+use crate::{Float, Matrix, Vector};
+
+impl<T: Float + Sum, const N: usize> Matrix<T, N, N> {
+    /// Returns the Householder reflection about the hyperplane orthogonal to
+    /// the given vector.
+    ///
+    /// The resulting matrix is `I - 2 * v * vᵀ / (vᵀ * v)`, it is symmetric,
+    /// orthogonal and its own inverse. If `v` is zero then the identity matrix
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{vector, Matrix};
+    /// #
+    /// let h = Matrix::householder(&vector![1.0, 0.0]);
+    /// assert_eq!(h * vector![3.0, 4.0], vector![-3.0, 4.0]);
+    /// ```
+    #[must_use]
+    pub fn householder(v: &Vector<T, N>) -> Self {
+        let mut matrix = Self::identity();
+        let norm_squared: T = v.iter().map(|&d| d * d).sum();
+        if norm_squared == T::zero() {
+            return matrix;
+        }
+        let scale = (T::one() + T::one()) / norm_squared;
+        for j in 0..N {
+            for i in 0..N {
+                matrix[(i, j)] = matrix[(i, j)] - scale * v[i] * v[j];
+            }
+        }
+        matrix
+    }
+
+    /// Returns the Givens rotation by `angle` radians in the plane spanned by
+    /// the `i`-th and `j`-th coordinate axes.
+    ///
+    /// The resulting matrix is the identity matrix except for the elements at
+    /// the intersections of the `i`-th and `j`-th rows and columns, which are
+    /// set so that multiplying a vector rotates it from axis `i` towards axis
+    /// `j`.
+    ///
+    /// # Panics
+    ///
+    /// If `i` or `j` is out of bounds or if `i == j`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{vector, Matrix};
+    /// #
+    /// let g = Matrix::<f64, 3, 3>::givens(0, 1, std::f64::consts::FRAC_PI_2);
+    /// let v = g * vector![1.0, 0.0, 5.0];
+    /// assert!((v - vector![0.0, 1.0, 5.0]).frobenius_norm() < 1e-12);
+    /// ```
+    #[must_use]
+    #[track_caller]
+    pub fn givens(i: usize, j: usize, angle: T) -> Self {
+        assert!(
+            i < N && j < N,
+            "axes ({}, {}) out of bounds for `Matrix<_, {}, {}>`",
+            i,
+            j,
+            N,
+            N
+        );
+        assert!(i != j, "axes must be different, both are {}", i);
+        let (sin, cos) = (angle.sin(), angle.cos());
+        let mut matrix = Self::identity();
+        matrix[(i, i)] = cos;
+        matrix[(j, j)] = cos;
+        matrix[(i, j)] = -sin;
+        matrix[(j, i)] = sin;
+        matrix
+    }
+}
@@ -2,16 +2,34 @@ use core::fmt;
 use core::fmt::Write;
 
 use crate::new;
-use crate::{Matrix, Vector};
+use crate::{Matrix, Row, Vector};
 
 ////////////////////////////////////////////////////////////////////////////////
 // Debug
 ////////////////////////////////////////////////////////////////////////////////
 
+/// Debug formats a row as a list.
+struct RowList<'a, T, const M: usize, const N: usize>(&'a Row<T, M, N>);
+
+impl<T: fmt::Debug, const M: usize, const N: usize> fmt::Debug for RowList<'_, T, M, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.0.iter()).finish()
+    }
+}
+
 impl<T: fmt::Debug, const M: usize, const N: usize> fmt::Debug for Matrix<T, M, N> {
+    /// Formats vectors as a list and matrices as a list of columns.
+    ///
+    /// If the `+` flag is given then matrices are formatted as a list of rows
+    /// instead. Like all other options the flag is also applied to the
+    /// elements.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if M == 1 || N == 1 {
             f.debug_list().entries(self.iter()).finish()
+        } else if f.sign_plus() {
+            f.debug_list()
+                .entries(self.iter_rows().map(RowList))
+                .finish()
         } else {
             fmt::Debug::fmt(&self.data, f)
         }
@@ -109,3 +127,88 @@ impl_fmt! { fmt::Octal, "{:.1$o}", "{:o}", "{:1$.2$o}", "{:1$o}" }
 impl_fmt! { fmt::LowerHex, "{:.1$x}", "{:x}", "{:1$.2$x}", "{:1$x}" }
 impl_fmt! { fmt::UpperHex, "{:.1$X}", "{:X}", "{:1$.2$X}", "{:1$X}" }
 impl_fmt! { fmt::Binary, "{:.1$b}", "{:b}", "{:1$.2$b}", "{:1$b}" }
+
+////////////////////////////////////////////////////////////////////////////////
+// Display with a custom element formatter
+////////////////////////////////////////////////////////////////////////////////
+
+/// Displays a matrix using a custom element formatter.
+///
+/// This `struct` is created by the [`format_with()`][Matrix::format_with]
+/// method on [`Matrix`].
+pub struct FormatWith<'a, T, F, const M: usize, const N: usize> {
+    matrix: &'a Matrix<T, M, N>,
+    fmt_fn: F,
+}
+
+/// Displays a single element using the custom element formatter.
+struct Element<'a, T, F>(&'a T, &'a F);
+
+impl<T, F> fmt::Display for Element<'_, T, F>
+where
+    F: Fn(&mut fmt::Formatter<'_>, &T) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (self.1)(f, self.0)
+    }
+}
+
+impl<T, F, const M: usize, const N: usize> fmt::Display for FormatWith<'_, T, F, M, N>
+where
+    F: Fn(&mut fmt::Formatter<'_>, &T) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let fmt_fn = &self.fmt_fn;
+        fmt_matrix(
+            self.matrix,
+            f,
+            |d| count_chars!("{}", Element(d, fmt_fn)),
+            |f, d, width| {
+                // The custom formatter may ignore the width so the elements
+                // are right-aligned manually.
+                let count = count_chars!("{}", Element(d, fmt_fn));
+                write!(f, "{:1$}{2}", "", width - count, Element(d, fmt_fn))
+            },
+        )
+    }
+}
+
+impl<T, const M: usize, const N: usize> Matrix<T, M, N> {
+    /// Returns a value that displays this matrix using the provided element
+    /// formatter.
+    ///
+    /// The matrix is displayed in the same box bracket format as the
+    /// [`Display`][fmt::Display] implementation, but each element is written
+    /// using `fmt_fn`. The elements in each column are right-aligned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let m = matrix![1200, 0; 5, 34000];
+    /// let s = m.format_with(|f, &x| match x {
+    ///     0 => f.write_str("-"),
+    ///     x if x >= 1000 => write!(f, "{},{:03}", x / 1000, x % 1000),
+    ///     x => write!(f, "{}", x),
+    /// });
+    /// assert_eq!(
+    ///     s.to_string(),
+    ///     "
+    ///  ┌               ┐
+    ///  │ 1,200       - │
+    ///  │     5  34,000 │
+    ///  └               ┘
+    /// "
+    /// );
+    /// ```
+    pub fn format_with<F>(&self, fmt_fn: F) -> FormatWith<'_, T, F, M, N>
+    where
+        F: Fn(&mut fmt::Formatter<'_>, &T) -> fmt::Result,
+    {
+        FormatWith {
+            matrix: self,
+            fmt_fn,
+        }
+    }
+}
@@ -1,9 +1,51 @@
+use core::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+
+use crate::index::private::Sealed;
 use crate::Matrix;
 
 mod private {
-    pub trait Sealed {}
-    impl Sealed for usize {}
-    impl Sealed for (usize, usize) {}
+    use core::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+
+    pub trait Sealed {
+        /// Returns the column-major position of the element at this location
+        /// in a matrix with `M` rows and `N` columns, or `None` if out of
+        /// bounds or the location does not refer to a single element.
+        fn element_index<const M: usize, const N: usize>(&self) -> Option<usize>;
+    }
+
+    impl Sealed for usize {
+        #[inline]
+        fn element_index<const M: usize, const N: usize>(&self) -> Option<usize> {
+            (*self < M * N).then_some(*self)
+        }
+    }
+
+    impl Sealed for (usize, usize) {
+        #[inline]
+        fn element_index<const M: usize, const N: usize>(&self) -> Option<usize> {
+            (self.0 < M && self.1 < N).then_some(self.1 * M + self.0)
+        }
+    }
+
+    macro_rules! impl_sealed_range {
+        ($($ty:ty),+) => ($(
+            impl Sealed for $ty {
+                #[inline]
+                fn element_index<const M: usize, const N: usize>(&self) -> Option<usize> {
+                    None
+                }
+            }
+        )+)
+    }
+
+    impl_sealed_range! {
+        Range<usize>,
+        RangeFrom<usize>,
+        RangeFull,
+        RangeInclusive<usize>,
+        RangeTo<usize>,
+        RangeToInclusive<usize>
+    }
 }
 
 /// A helper trait used for indexing operations.
@@ -110,12 +152,14 @@ unsafe impl<T, const M: usize, const N: usize> MatrixIndex<Matrix<T, M, N>> for
 
     #[inline]
     fn get(self, matrix: &Matrix<T, M, N>) -> Option<&Self::Output> {
-        matrix.as_slice().get(self.1 * M + self.0)
+        let i = self.element_index::<M, N>()?;
+        matrix.as_slice().get(i)
     }
 
     #[inline]
     fn get_mut(self, matrix: &mut Matrix<T, M, N>) -> Option<&mut Self::Output> {
-        matrix.as_mut_slice().get_mut(self.1 * M + self.0)
+        let i = self.element_index::<M, N>()?;
+        matrix.as_mut_slice().get_mut(i)
     }
 
     #[inline]
@@ -137,12 +181,93 @@ unsafe impl<T, const M: usize, const N: usize> MatrixIndex<Matrix<T, M, N>> for
     #[track_caller]
     #[inline]
     fn index(self, matrix: &Matrix<T, M, N>) -> &Self::Output {
-        &matrix.as_slice()[self.1 * M + self.0]
+        match self.get(matrix) {
+            Some(elem) => elem,
+            None => index_panic::<M, N>(self),
+        }
     }
 
     #[track_caller]
     #[inline]
     fn index_mut(self, matrix: &mut Matrix<T, M, N>) -> &mut Self::Output {
-        &mut matrix.as_mut_slice()[self.1 * M + self.0]
+        match self.get_mut(matrix) {
+            Some(elem) => elem,
+            None => index_panic::<M, N>(self),
+        }
+    }
+}
+
+// Ranges select a contiguous range of columns, which is the same as indexing
+// the underlying array of columns.
+macro_rules! impl_range_index {
+    ($($ty:ty),+) => ($(
+        unsafe impl<T, const M: usize, const N: usize> MatrixIndex<Matrix<T, M, N>> for $ty {
+            type Output = [[T; M]];
+
+            #[inline]
+            fn get(self, matrix: &Matrix<T, M, N>) -> Option<&Self::Output> {
+                matrix.data.get(self)
+            }
+
+            #[inline]
+            fn get_mut(self, matrix: &mut Matrix<T, M, N>) -> Option<&mut Self::Output> {
+                matrix.data.get_mut(self)
+            }
+
+            #[inline]
+            unsafe fn get_unchecked(self, matrix: *const Matrix<T, M, N>) -> *const Self::Output {
+                // SAFETY: it is the caller's responsibility not to call this
+                // with an out-of-bounds range or a dangling `matrix` pointer.
+                let data = unsafe { &(*matrix).data };
+                unsafe { data.get_unchecked(self) }
+            }
+
+            #[inline]
+            unsafe fn get_unchecked_mut(self, matrix: *mut Matrix<T, M, N>) -> *mut Self::Output {
+                // SAFETY: it is the caller's responsibility not to call this
+                // with an out-of-bounds range or a dangling `matrix` pointer.
+                let data = unsafe { &mut (*matrix).data };
+                unsafe { data.get_unchecked_mut(self) }
+            }
+
+            #[track_caller]
+            #[inline]
+            fn index(self, matrix: &Matrix<T, M, N>) -> &Self::Output {
+                &matrix.data[self]
+            }
+
+            #[track_caller]
+            #[inline]
+            fn index_mut(self, matrix: &mut Matrix<T, M, N>) -> &mut Self::Output {
+                &mut matrix.data[self]
+            }
+        }
+    )+)
+}
+
+impl_range_index! {
+    Range<usize>,
+    RangeFrom<usize>,
+    RangeFull,
+    RangeInclusive<usize>,
+    RangeTo<usize>,
+    RangeToInclusive<usize>
+}
+
+#[cold]
+#[track_caller]
+fn index_panic<const M: usize, const N: usize>((i, j): (usize, usize)) -> ! {
+    if N == 1 {
+        panic!("index ({}, {}) out of bounds for `Vector<_, {}>`", i, j, M);
+    } else if M == 1 {
+        panic!(
+            "index ({}, {}) out of bounds for `RowVector<_, {}>`",
+            i, j, N
+        );
+    } else {
+        panic!(
+            "index ({}, {}) out of bounds for `Matrix<_, {}, {}>`",
+            i, j, M, N
+        );
     }
 }
@@ -0,0 +1,246 @@
+//! Rigid transformations.
+
+use core::iter::Sum;
+use core::ops::{Add, Mul, Neg};
+
+use crate::{Affine, Float, Matrix, One, Rotation, Vector, Zero};
+
+/// A rigid transformation in `N` dimensions.
+///
+/// This is a rotation followed by a translation, so it preserves distances and
+/// angles. Because the rotation is guaranteed to be orthonormal, inverting an
+/// isometry only needs a transpose instead of a general matrix inverse.
+///
+/// # Examples
+///
+/// ```
+/// # use vectrix::{vector, Isometry2, Rotation2};
+/// #
+/// let rotation = Rotation2::from_angle(std::f64::consts::FRAC_PI_2);
+/// let iso = Isometry2::new(rotation, vector![1.0, 0.0]);
+/// let p = iso.transform_point(&vector![1.0, 0.0]);
+/// assert!((p - vector![1.0, 1.0]).l2_norm() < 1e-12);
+/// assert!((iso.inverse().transform_point(&p) - vector![1.0, 0.0]).l2_norm() < 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Isometry<T, const N: usize> {
+    rotation: Rotation<T, N>,
+    translation: Vector<T, N>,
+}
+
+/// A rigid transformation in two dimensions.
+pub type Isometry2<T> = Isometry<T, 2>;
+
+/// A rigid transformation in three dimensions.
+pub type Isometry3<T> = Isometry<T, 3>;
+
+impl<T, const N: usize> Isometry<T, N> {
+    /// Create a new rigid transformation from a rotation and a translation.
+    #[inline]
+    pub const fn new(rotation: Rotation<T, N>, translation: Vector<T, N>) -> Self {
+        Self {
+            rotation,
+            translation,
+        }
+    }
+
+    /// Returns the identity transformation.
+    #[must_use]
+    pub fn identity() -> Self
+    where
+        T: Copy + One + Zero,
+    {
+        Self::new(Rotation::identity(), Vector::zero())
+    }
+
+    /// Create a new rigid transformation from a rotation only.
+    #[must_use]
+    pub fn from_rotation(rotation: Rotation<T, N>) -> Self
+    where
+        T: Copy + Zero,
+    {
+        Self::new(rotation, Vector::zero())
+    }
+
+    /// Create a new rigid transformation from a translation only.
+    #[must_use]
+    pub fn from_translation(translation: Vector<T, N>) -> Self
+    where
+        T: Copy + One + Zero,
+    {
+        Self::new(Rotation::identity(), translation)
+    }
+
+    /// Returns a reference to the rotation part of this transformation.
+    #[inline]
+    pub const fn rotation(&self) -> &Rotation<T, N> {
+        &self.rotation
+    }
+
+    /// Returns a reference to the translation part of this transformation.
+    #[inline]
+    pub const fn translation(&self) -> &Vector<T, N> {
+        &self.translation
+    }
+}
+
+impl<T, const N: usize> Isometry<T, N>
+where
+    T: Copy + Zero + Add<Output = T> + Mul<Output = T> + Sum,
+{
+    /// Transforms a point, applying both the rotation and the translation.
+    #[must_use]
+    pub fn transform_point(&self, point: &Vector<T, N>) -> Vector<T, N> {
+        self.rotation * *point + self.translation
+    }
+
+    /// Transforms a vector, applying only the rotation.
+    #[must_use]
+    pub fn transform_vector(&self, vector: &Vector<T, N>) -> Vector<T, N> {
+        self.rotation * *vector
+    }
+
+    /// Returns the inverse of this transformation.
+    ///
+    /// This is always defined and is computed using the transpose of the
+    /// rotation.
+    #[must_use]
+    pub fn inverse(&self) -> Self
+    where
+        T: Neg<Output = T>,
+    {
+        let rotation = self.rotation.inverse();
+        Self::new(rotation, -(rotation * self.translation))
+    }
+}
+
+impl<T: Float + Sum> Isometry<T, 3> {
+    /// Returns the rigid transformation for the given twist, the exponential
+    /// map from the Lie algebra se(3) to SE(3).
+    ///
+    /// The twist is `[ρ, ω]` where `ω` is the rotation vector, see
+    /// [`Rotation::exp()`], and `ρ` is the translational part. The rotation
+    /// and translation are applied simultaneously, like a screw motion, so
+    /// the resulting translation is only `ρ` if `ω` is zero. This is the
+    /// inverse of [`log()`][Isometry::log].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{vector, Isometry3};
+    /// #
+    /// let twist = vector![1.0, 2.0, 3.0, 0.0, 0.0, 0.0];
+    /// let iso = Isometry3::exp(&twist);
+    /// assert_eq!(iso.translation(), &vector![1.0, 2.0, 3.0]);
+    /// ```
+    #[must_use]
+    pub fn exp(twist: &Vector<T, 6>) -> Self {
+        let [[r0, r1, r2, w0, w1, w2]] = twist.data;
+        let rho = Vector::from_column_major_order([[r0, r1, r2]]);
+        let omega = Vector::from_column_major_order([[w0, w1, w2]]);
+        let (b, c) = exp_coefficients(omega.l2_norm());
+        let k = omega.skew_symmetric();
+        let v = Matrix::<T, 3, 3>::identity() + k * b + k * k * c;
+        Self::new(Rotation::exp(&omega), v * rho)
+    }
+
+    /// Returns the twist of this rigid transformation, the logarithm map from
+    /// SE(3) to the Lie algebra se(3).
+    ///
+    /// The twist is `[ρ, ω]`, see [`exp()`][Isometry::exp]. Interpolating
+    /// the twist between two poses gives a constant velocity screw motion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{vector, Isometry3};
+    /// #
+    /// let twist = vector![1.0, -2.0, 0.5, 0.3, 0.2, -0.1];
+    /// assert!((Isometry3::exp(&twist).log() - twist).l2_norm() < 1e-12);
+    /// ```
+    #[must_use]
+    pub fn log(&self) -> Vector<T, 6> {
+        let omega = self.rotation.log();
+        let theta = omega.l2_norm();
+        let l = T::one();
+        let two = l + l;
+        let twelve = two * two * (two + l);
+        let theta2 = theta * theta;
+        // The coefficient `(1 - θ sin θ / (2 (1 - cos θ))) / θ²` is computed
+        // using the half angle `h` as `(1 - h cos h / sin h) / θ²` because
+        // `1 - cos θ` loses all its precision to cancellation for small θ.
+        let d = if theta2 <= T::epsilon().sqrt() {
+            let six = two * (two + l);
+            let seven_twenty = twelve * twelve * (two + two + l);
+            l / twelve + theta2 / seven_twenty + theta2 * theta2 / (seven_twenty * six * (six + l))
+        } else {
+            let half = theta / two;
+            (l - half * half.cos() / half.sin()) / theta2
+        };
+        let k = omega.skew_symmetric();
+        let v_inv = Matrix::<T, 3, 3>::identity() - k / two + k * k * d;
+        let [[r0, r1, r2]] = (v_inv * self.translation).data;
+        let [[w0, w1, w2]] = omega.data;
+        Vector::from_column_major_order([[r0, r1, r2, w0, w1, w2]])
+    }
+}
+
+/// Returns the coefficients `(1 - cos θ) / θ²` and `(θ - sin θ) / θ³` used by
+/// the exponential map, using their Taylor series for small angles where the
+/// direct formulas suffer from cancellation.
+fn exp_coefficients<T: Float>(theta: T) -> (T, T) {
+    let l = T::one();
+    let two = l + l;
+    let six = two * (two + l);
+    let theta2 = theta * theta;
+    if theta2 <= T::epsilon().sqrt() {
+        let twenty_four = six * two * two;
+        let one_twenty = twenty_four * (two + two + l);
+        (
+            l / two - theta2 / twenty_four,
+            l / six - theta2 / one_twenty,
+        )
+    } else {
+        // `1 - cos θ` is computed as `2 sin²(θ / 2)` to avoid cancellation.
+        let half = theta / two;
+        let sinc = half.sin() / half;
+        (sinc * sinc / two, (theta - theta.sin()) / (theta2 * theta))
+    }
+}
+
+impl<T, const N: usize> Mul for Isometry<T, N>
+where
+    T: Copy + Zero + Add<Output = T> + Mul<Output = T> + Sum,
+{
+    type Output = Self;
+
+    /// Composes two transformations, the result applies `rhs` first and then
+    /// `self`.
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.rotation * rhs.rotation,
+            self.rotation * rhs.translation + self.translation,
+        )
+    }
+}
+
+impl<T, const N: usize> From<Isometry<T, N>> for Affine<T, N> {
+    /// Converts the rigid transformation to a general affine transformation.
+    fn from(isometry: Isometry<T, N>) -> Self {
+        Affine::new(isometry.rotation.into_inner(), isometry.translation)
+    }
+}
+
+impl<T: Copy + Zero + One> From<Isometry<T, 2>> for Matrix<T, 3, 3> {
+    /// Converts the transformation to a homogeneous matrix.
+    fn from(isometry: Isometry<T, 2>) -> Self {
+        Affine::from(isometry).into()
+    }
+}
+
+impl<T: Copy + Zero + One> From<Isometry<T, 3>> for Matrix<T, 4, 4> {
+    /// Converts the transformation to a homogeneous matrix.
+    fn from(isometry: Isometry<T, 3>) -> Self {
+        Affine::from(isometry).into()
+    }
+}
@@ -1,12 +1,12 @@
 use core::fmt;
-use core::iter::{FusedIterator, Sum};
+use core::iter::{FusedIterator, Product, Sum};
 use core::marker::PhantomData;
 use core::mem::MaybeUninit;
-use core::ops::{Add, Range};
+use core::ops::{Add, Mul, Range};
 use core::ptr;
 
 use crate::new;
-use crate::{Column, Matrix, Row, Zero};
+use crate::{Column, Matrix, One, Row, Zero};
 
 ////////////////////////////////////////////////////////////////////////////////
 // Element iteration
@@ -181,6 +181,21 @@ where
     }
 }
 
+impl<T, const N: usize> Product<Matrix<T, N, N>> for Matrix<T, N, N>
+where
+    Self: Mul<Output = Self>,
+    T: Copy + Zero + One,
+{
+    /// Multiplies the matrices together from left to right, the product of
+    /// no matrices is the identity.
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Matrix::identity(), Mul::mul)
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Immutable row iteration
 ////////////////////////////////////////////////////////////////////////////////
@@ -422,3 +437,237 @@ impl<T, const M: usize, const N: usize> ExactSizeIterator for IterColumnsMut<'_,
 }
 
 impl<T, const M: usize, const N: usize> FusedIterator for IterColumnsMut<'_, T, M, N> {}
+
+////////////////////////////////////////////////////////////////////////////////
+// Row-major element iteration
+////////////////////////////////////////////////////////////////////////////////
+
+/// An iterator over the elements in a matrix in row-major order.
+///
+/// This `struct` is created by the [`iter_row_major()`][Matrix::iter_row_major]
+/// method on [`Matrix`].
+pub struct IterRowMajor<'a, T, const M: usize, const N: usize> {
+    matrix: &'a Matrix<T, M, N>,
+    alive: Range<usize>,
+}
+
+impl<'a, T, const M: usize, const N: usize> IterRowMajor<'a, T, M, N> {
+    pub(crate) fn new(matrix: &'a Matrix<T, M, N>) -> Self {
+        Self {
+            matrix,
+            alive: 0..(M * N),
+        }
+    }
+}
+
+impl<'a, T, const M: usize, const N: usize> Iterator for IterRowMajor<'a, T, M, N> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.alive.next().map(|k| &self.matrix[(k / N, k % N)])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.alive.len();
+        (len, Some(len))
+    }
+
+    fn count(self) -> usize {
+        self.alive.len()
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<T, const M: usize, const N: usize> DoubleEndedIterator for IterRowMajor<'_, T, M, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.alive.next_back().map(|k| &self.matrix[(k / N, k % N)])
+    }
+}
+
+impl<T, const M: usize, const N: usize> ExactSizeIterator for IterRowMajor<'_, T, M, N> {
+    fn len(&self) -> usize {
+        self.alive.len()
+    }
+}
+
+impl<T, const M: usize, const N: usize> FusedIterator for IterRowMajor<'_, T, M, N> {}
+
+////////////////////////////////////////////////////////////////////////////////
+// Index iteration
+////////////////////////////////////////////////////////////////////////////////
+
+/// An iterator over the `(row, column)` positions in a matrix in column-major
+/// order.
+///
+/// This `struct` is created by the [`indices()`][Matrix::indices] method on
+/// [`Matrix`].
+#[derive(Debug, Clone)]
+pub struct Indices<const M: usize, const N: usize> {
+    alive: Range<usize>,
+}
+
+impl<const M: usize, const N: usize> Indices<M, N> {
+    pub(crate) fn new() -> Self {
+        Self { alive: 0..(M * N) }
+    }
+}
+
+impl<const M: usize, const N: usize> Iterator for Indices<M, N> {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.alive.next().map(|k| (k % M, k / M))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.alive.len();
+        (len, Some(len))
+    }
+
+    fn count(self) -> usize {
+        self.alive.len()
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<const M: usize, const N: usize> DoubleEndedIterator for Indices<M, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.alive.next_back().map(|k| (k % M, k / M))
+    }
+}
+
+impl<const M: usize, const N: usize> ExactSizeIterator for Indices<M, N> {
+    fn len(&self) -> usize {
+        self.alive.len()
+    }
+}
+
+impl<const M: usize, const N: usize> FusedIterator for Indices<M, N> {}
+
+/// An iterator over the `(row, column)` positions in a matrix in row-major
+/// order.
+///
+/// This `struct` is created by the [`indices_row_major()`][Matrix::indices_row_major] method on
+/// [`Matrix`].
+#[derive(Debug, Clone)]
+pub struct IndicesRowMajor<const M: usize, const N: usize> {
+    alive: Range<usize>,
+}
+
+impl<const M: usize, const N: usize> IndicesRowMajor<M, N> {
+    pub(crate) fn new() -> Self {
+        Self { alive: 0..(M * N) }
+    }
+}
+
+impl<const M: usize, const N: usize> Iterator for IndicesRowMajor<M, N> {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.alive.next().map(|k| (k / N, k % N))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.alive.len();
+        (len, Some(len))
+    }
+
+    fn count(self) -> usize {
+        self.alive.len()
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<const M: usize, const N: usize> DoubleEndedIterator for IndicesRowMajor<M, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.alive.next_back().map(|k| (k / N, k % N))
+    }
+}
+
+impl<const M: usize, const N: usize> ExactSizeIterator for IndicesRowMajor<M, N> {
+    fn len(&self) -> usize {
+        self.alive.len()
+    }
+}
+
+impl<const M: usize, const N: usize> FusedIterator for IndicesRowMajor<M, N> {}
+
+////////////////////////////////////////////////////////////////////////////////
+// Row window iteration
+////////////////////////////////////////////////////////////////////////////////
+
+/// An iterator over overlapping windows of `K` consecutive rows in a matrix.
+///
+/// This `struct` is created by the [`windows_rows()`][Matrix::windows_rows]
+/// method on [`Matrix`].
+pub struct WindowsRows<'a, T, const M: usize, const N: usize, const K: usize> {
+    matrix: &'a Matrix<T, M, N>,
+    alive: Range<usize>,
+}
+
+impl<'a, T, const M: usize, const N: usize, const K: usize> WindowsRows<'a, T, M, N, K> {
+    pub(crate) fn new(matrix: &'a Matrix<T, M, N>) -> Self {
+        Self {
+            matrix,
+            alive: 0..(M + 1).saturating_sub(K),
+        }
+    }
+
+    fn window(&self, i: usize) -> [&'a Row<T, M, N>; K] {
+        let matrix = self.matrix;
+        core::array::from_fn(|k| matrix.row(i + k))
+    }
+}
+
+impl<'a, T, const M: usize, const N: usize, const K: usize> Iterator
+    for WindowsRows<'a, T, M, N, K>
+{
+    type Item = [&'a Row<T, M, N>; K];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.alive.next().map(|i| self.window(i))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.alive.len();
+        (len, Some(len))
+    }
+
+    fn count(self) -> usize {
+        self.alive.len()
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<T, const M: usize, const N: usize, const K: usize> DoubleEndedIterator
+    for WindowsRows<'_, T, M, N, K>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.alive.next_back().map(|i| self.window(i))
+    }
+}
+
+impl<T, const M: usize, const N: usize, const K: usize> ExactSizeIterator
+    for WindowsRows<'_, T, M, N, K>
+{
+    fn len(&self) -> usize {
+        self.alive.len()
+    }
+}
+
+impl<T, const M: usize, const N: usize, const K: usize> FusedIterator
+    for WindowsRows<'_, T, M, N, K>
+{
+}
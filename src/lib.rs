@@ -15,6 +15,29 @@
 //! cargo add vectrix --no-default-features --features=macro
 //! ```
 //!
+//! Floating point operations, for example norms and decompositions, use the
+//! standard library. In `no_std` environments enable the `libm` feature to
+//! use them.
+//!
+//! ```sh
+//! cargo add vectrix --no-default-features --features=macro,libm
+//! ```
+//!
+//! Matrix multiplication and dot products always accumulate each element
+//! strictly from left to right starting from the first product. Rust never
+//! contracts floating point arithmetic into fused multiply-add or reassociates
+//! it, so these results only depend on the inputs. Functions like `exp` and
+//! `sin` however use the platform's implementation and can differ between
+//! targets. For simulations that require bit-identical results across
+//! targets, for example lockstep multiplayer games, enable the
+//! `deterministic` feature to always use the portable `libm` implementations.
+//! Results can still differ on targets without IEEE 754 conforming floating
+//! point, such as x86 without SSE2, and in the payloads of NaNs.
+//!
+//! ```sh
+//! cargo add vectrix --features=deterministic
+//! ```
+//!
 //! # 🤸 Usage
 //!
 //! ## Types
@@ -74,6 +97,9 @@
 //!   filled with values computed by the provided closure.
 //! - [`::from_iter(..)`][`core::iter::FromIterator::from_iter`] → constructs a
 //!   new matrix from an iterator.
+//! - [`::from_column_major_order(..)`][`Matrix::from_column_major_order()`] →
+//!   constructs a new matrix from an array of columns, usable in `const`
+//!   contexts.
 //! - [`::new(..)`][`Matrix::new()`] → constructs a new vector using the
 //!   provided components.
 //!
@@ -121,6 +147,16 @@
 //!   assert_eq!(v.b, 0);
 //!   ```
 //!
+//!   Swizzle methods are available for vectors with two to four components.
+//!   ```
+//!   # use vectrix::*;
+//!   #
+//!   let v = vector![1, 2, 3];
+//!   assert_eq!(v.zyx(), vector![3, 2, 1]);
+//!   assert_eq!(v.xz(), vector![1, 3]);
+//!   assert_eq!(v.xyz0(), vector![1, 2, 3, 0]);
+//!   ```
+//!
 //! ## Accessing a row or column
 //!
 //! You can get a reference to particular row or column using the
@@ -139,6 +175,20 @@
 //! assert_eq!(m.column(1), &[2, 5]);
 //! ```
 //!
+//! Indexing with a range of `usize` selects a contiguous range of columns as a
+//! slice of column arrays.
+//!
+//! ```
+//! # use vectrix::*;
+//! #
+//! let m = matrix![
+//!     1, 2, 3;
+//!     4, 5, 6;
+//! ];
+//! assert_eq!(&m[1..], &[[2, 5], [3, 6]]);
+//! assert_eq!(m.get(2..4), None);
+//! ```
+//!
 //! ## Iteration
 //!
 //! Element-wise, column-major order iteration is provided using the following
@@ -181,7 +231,9 @@
 //! ## Debug
 //!
 //! The [`Debug`][`core::fmt::Debug`] implementation will print out vectors as
-//! lists and matrices as a list of lists in column-major order.
+//! lists and matrices as a list of lists in column-major order. The `+` flag
+//! switches matrices to a list of lists in row-major order, like other
+//! options it is also applied to the elements.
 //!
 //! ```
 //! # use vectrix::*;
@@ -190,6 +242,7 @@
 //! let m = matrix![1, 2; 3, 4];
 //! println!("vector: {:.2?}", v);
 //! println!("matrix: {:?}", m);
+//! println!("matrix: {:+?}", m);
 //! ```
 //!
 //! This will output:
@@ -197,6 +250,7 @@
 //! ```text
 //! vector: [1.10, 2.00]
 //! matrix: [[1, 3], [2, 4]]
+//! matrix: [[+1, +2], [+3, +4]]
 //! ```
 //!
 //! ## Display
@@ -283,27 +337,77 @@
 #[cfg(feature = "std")]
 extern crate std;
 
+mod affine;
+mod assert;
+mod bytes;
+mod error;
+mod factor;
 mod fmt;
 mod index;
+mod isometry;
 mod iter;
+mod linalg;
+mod mask;
 mod new;
 mod ops;
+mod permutation;
+mod plane;
+mod point;
+mod predicate;
+#[cfg(feature = "rand")]
+mod random;
+mod ray;
+mod rotation;
+#[cfg(feature = "std")]
+mod stack;
+#[cfg(feature = "testutils")]
+pub mod testutils;
 mod traits;
+mod transform;
+mod unit;
 mod vector;
 mod view;
 
-use core::iter::Sum;
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+use core::iter::{Sum, Zip};
+use core::mem;
 use core::ops::*;
 use core::slice;
 
+use stride::Stride;
+
 #[doc(hidden)]
 #[cfg(feature = "macro")]
 pub use vectrix_macro as proc_macro;
 
+pub use crate::affine::{Affine, Affine2, Affine3};
+pub use crate::assert::{
+    AssertDiagonalStride, AssertInBounds, AssertLength, AssertSameSize, AssertSquare,
+};
+pub use crate::error::{CollectError, Error};
+pub use crate::fmt::FormatWith;
 pub use crate::index::MatrixIndex;
-pub use crate::iter::{IntoIter, IterColumns, IterColumnsMut, IterRows, IterRowsMut};
-pub use crate::traits::{Abs, One, Zero};
-pub use crate::view::{Column, Row};
+pub use crate::isometry::{Isometry, Isometry2, Isometry3};
+pub use crate::iter::{
+    Indices, IndicesRowMajor, IntoIter, IterColumns, IterColumnsMut, IterRowMajor, IterRows,
+    IterRowsMut, WindowsRows,
+};
+pub use crate::permutation::Permutation;
+pub use crate::plane::Plane;
+pub use crate::point::{Point, Point2, Point3};
+pub use crate::ray::Ray;
+pub use crate::rotation::{EulerOrder, Rotation, Rotation2, Rotation3};
+#[cfg(feature = "std")]
+pub use crate::stack::TransformStack;
+pub use crate::traits::{
+    Abs, Checked, ConstOne, ConstZero, Det, Dot, Float, LeBytes, Norm, One, Trace, Transpose,
+    Widen, Zero,
+};
+pub use crate::transform::TrsDecomposition;
+pub use crate::unit::Unit;
+pub use crate::vector::{X, XY, XYZ, XYZW, XYZWA, XYZWAB};
+pub use crate::view::{BlockView, Column, Row};
 
 /// Represents a matrix with constant `M` rows and constant `N` columns.
 ///
@@ -311,7 +415,22 @@ pub use crate::view::{Column, Row};
 /// column-major order.
 ///
 /// See the [crate root][crate] for usage examples.
-#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+///
+/// # Layout
+///
+/// `Matrix<T, M, N>` is `#[repr(transparent)]` over `[[T; M]; N]`, so it is
+/// guaranteed to have the same size, alignment and ABI as that array. Since
+/// arrays have no padding between elements this is also the same layout as
+/// `[T; M * N]`, with element `(i, j)` at offset `j * M + i`. In particular:
+///
+/// - `size_of::<Matrix<T, M, N>>() == M * N * size_of::<T>()`
+/// - `align_of::<Matrix<T, M, N>>() == align_of::<T>()`
+/// - [`Vector<T, M>`] and [`RowVector<T, M>`] have the same layout as `[T; M]`.
+///
+/// Unsafe code may rely on these guarantees, for example when passing a
+/// pointer obtained from [`as_slice()`][Matrix::as_slice] to a C library that
+/// expects a column-major buffer.
+#[derive(Clone, Copy, PartialOrd, Ord)]
 #[repr(transparent)]
 pub struct Matrix<T, const M: usize, const N: usize> {
     data: [[T; M]; N],
@@ -323,13 +442,108 @@ pub type RowVector<T, const N: usize> = Matrix<T, 1, N>;
 /// A matrix with one column and `M` rows.
 pub type Vector<T, const M: usize> = Matrix<T, M, 1>;
 
+// Verify the layout guarantees documented on `Matrix` for a selection of
+// element types and sizes.
+const _: () = {
+    use core::mem::{align_of, size_of};
+
+    macro_rules! assert_layout {
+        ($($ty:ty),+) => {$(
+            assert!(size_of::<Matrix<$ty, 0, 0>>() == 0);
+            assert!(size_of::<Matrix<$ty, 2, 3>>() == 6 * size_of::<$ty>());
+            assert!(size_of::<Matrix<$ty, 3, 2>>() == size_of::<[$ty; 6]>());
+            assert!(size_of::<Vector<$ty, 4>>() == size_of::<[$ty; 4]>());
+            assert!(size_of::<RowVector<$ty, 4>>() == size_of::<[$ty; 4]>());
+            assert!(align_of::<Matrix<$ty, 0, 0>>() == align_of::<$ty>());
+            assert!(align_of::<Matrix<$ty, 2, 3>>() == align_of::<$ty>());
+        )+};
+    }
+
+    assert_layout!(
+        (),
+        bool,
+        u8,
+        u16,
+        u32,
+        u64,
+        u128,
+        f32,
+        f64,
+        (u8, u32),
+        [u16; 3]
+    );
+};
+
+////////////////////////////////////////////////////////////////////////////////
+// Equality and hashing
+////////////////////////////////////////////////////////////////////////////////
+
+// Equality and hashing are implemented over the flattened slice rather than
+// the array of arrays. For primitive element types the standard library then
+// compares the entire matrix using a single `memcmp` and hashes it using a
+// single write instead of one per column.
+
+impl<T, const M: usize, const N: usize> PartialEq for Matrix<T, M, N>
+where
+    T: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq, const M: usize, const N: usize> Eq for Matrix<T, M, N> {}
+
+impl<T, const M: usize, const N: usize> Hash for Matrix<T, M, N>
+where
+    T: Hash,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Hash::hash_slice(self.as_slice(), state)
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Matrix<T, M, N> methods
 ////////////////////////////////////////////////////////////////////////////////
 
+impl<T: Copy + ConstZero, const M: usize, const N: usize> Matrix<T, M, N> {
+    /// A matrix filled with [`T::ZERO`][`ConstZero::ZERO`].
+    ///
+    /// Unlike [`Matrix::zero()`] this is usable in `const` and `static`
+    /// contexts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{matrix, Matrix};
+    /// #
+    /// static Z: Matrix<f32, 2, 3> = Matrix::ZERO;
+    /// assert_eq!(Z, matrix![0.0, 0.0, 0.0; 0.0, 0.0, 0.0]);
+    /// ```
+    pub const ZERO: Self = Self {
+        data: [[T::ZERO; M]; N],
+    };
+}
+
 impl<T, const M: usize, const N: usize> Matrix<T, M, N> {
     /// Create a new matrix from an array of arrays in column-major order.
-    #[doc(hidden)]
+    ///
+    /// Each inner array is a column of the matrix. This is the same layout
+    /// that the matrix uses internally, so this constructor is free and can be
+    /// used in `const` contexts without the `macro` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{matrix, Matrix};
+    /// #
+    /// const M: Matrix<i64, 2, 3> = Matrix::from_column_major_order([[1, 4], [2, 5], [3, 6]]);
+    /// assert_eq!(M, matrix![1, 2, 3; 4, 5, 6]);
+    /// ```
+    #[must_use]
     #[inline]
     pub const fn from_column_major_order(data: [[T; M]; N]) -> Self {
         Self { data }
@@ -372,7 +586,7 @@ impl<T, const M: usize, const N: usize> Matrix<T, M, N> {
 
     /// Returns a raw pointer to the underlying data.
     #[inline]
-    fn as_ptr(&self) -> *const T {
+    const fn as_ptr(&self) -> *const T {
         self.data.as_ptr() as *const T
     }
 
@@ -384,7 +598,7 @@ impl<T, const M: usize, const N: usize> Matrix<T, M, N> {
 
     /// Views the underlying data as a contiguous slice.
     #[inline]
-    pub fn as_slice(&self) -> &[T] {
+    pub const fn as_slice(&self) -> &[T] {
         unsafe { slice::from_raw_parts(self.as_ptr(), M * N) }
     }
 
@@ -394,6 +608,124 @@ impl<T, const M: usize, const N: usize> Matrix<T, M, N> {
         unsafe { slice::from_raw_parts_mut(self.as_mut_ptr(), M * N) }
     }
 
+    /// Create a new matrix from a flat array in column-major order.
+    ///
+    /// This method fails to compile if `L` is not equal to `M * N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{matrix, Matrix};
+    /// #
+    /// let m = Matrix::<_, 2, 2>::from_array([1, 2, 3, 4]);
+    /// assert_eq!(m, matrix![1, 3; 2, 4]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn from_array<const L: usize>(array: [T; L]) -> Self {
+        let () = AssertLength::<L, M, N>::OK;
+        // SAFETY: `[T; L]` and `[[T; M]; N]` have the same layout since
+        // `L == M * N`, and `Matrix` is `repr(transparent)`.
+        unsafe { new::transmute_unchecked(array) }
+    }
+
+    /// Converts this matrix into a flat array in column-major order.
+    ///
+    /// This method fails to compile if `L` is not equal to `M * N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{matrix, Matrix};
+    /// #
+    /// let m: Matrix<f32, 4, 4> = Matrix::identity();
+    /// let a: [f32; 16] = m.into_array();
+    /// assert_eq!(a[5], 1.0);
+    /// ```
+    #[inline]
+    pub fn into_array<const L: usize>(self) -> [T; L] {
+        let () = AssertLength::<L, M, N>::OK;
+        // SAFETY: see `from_array`.
+        unsafe { new::transmute_unchecked(self) }
+    }
+
+    /// Views the underlying data as a flat array in column-major order.
+    ///
+    /// This method fails to compile if `L` is not equal to `M * N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let m = matrix![1, 2; 3, 4];
+    /// let a: &[i64; 4] = m.as_array();
+    /// assert_eq!(a, &[1, 3, 2, 4]);
+    /// ```
+    #[inline]
+    pub fn as_array<const L: usize>(&self) -> &[T; L] {
+        let () = AssertLength::<L, M, N>::OK;
+        // SAFETY: see `from_array`.
+        unsafe { &*(self.as_ptr() as *const [T; L]) }
+    }
+
+    /// Views the underlying data as a mutable flat array in column-major
+    /// order.
+    ///
+    /// This method fails to compile if `L` is not equal to `M * N`.
+    #[inline]
+    pub fn as_mut_array<const L: usize>(&mut self) -> &mut [T; L] {
+        let () = AssertLength::<L, M, N>::OK;
+        // SAFETY: see `from_array`.
+        unsafe { &mut *(self.as_mut_ptr() as *mut [T; L]) }
+    }
+
+    /// Returns the number of elements in the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{matrix, Matrix};
+    /// #
+    /// assert_eq!(matrix![1, 2, 3; 4, 5, 6].len(), 6);
+    /// assert_eq!(Matrix::<i64, 0, 3>::zero().len(), 0);
+    /// ```
+    #[inline]
+    pub const fn len(&self) -> usize {
+        M * N
+    }
+
+    /// Returns the number of rows and columns in the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// assert_eq!(matrix![1, 2, 3; 4, 5, 6].shape(), (2, 3));
+    /// ```
+    #[inline]
+    pub const fn shape(&self) -> (usize, usize) {
+        (M, N)
+    }
+
+    /// Returns `true` if the matrix has no elements.
+    ///
+    /// This is the case when either `M` or `N` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{matrix, Matrix};
+    /// #
+    /// assert!(Matrix::<i64, 0, 3>::zero().is_empty());
+    /// assert!(!matrix![1, 2, 3].is_empty());
+    /// ```
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        M == 0 || N == 0
+    }
+
     /// Returns a reference to an element in the matrix or `None` if out of
     /// bounds.
     #[inline]
@@ -404,6 +736,31 @@ impl<T, const M: usize, const N: usize> Matrix<T, M, N> {
         i.get(self)
     }
 
+    /// Returns a reference to the `i`-th element in column-major order or
+    /// `None` if out of bounds.
+    ///
+    /// This is the same as [`.get(i)`][Self::get] with a `usize` index but
+    /// it can be used in a `const` context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{matrix, Matrix};
+    /// #
+    /// const M: Matrix<i64, 2, 2> = Matrix::from_column_major_order([[1, 3], [2, 4]]);
+    /// const X: Option<&i64> = M.get_nth(1);
+    /// assert_eq!(X, Some(&3));
+    /// assert_eq!(M.get_nth(4), None);
+    /// ```
+    #[inline]
+    pub const fn get_nth(&self, i: usize) -> Option<&T> {
+        if i < M * N {
+            Some(&self.as_slice()[i])
+        } else {
+            None
+        }
+    }
+
     /// Returns a mutable reference to an element in the matrix or `None` if out
     /// of bounds.
     #[inline]
@@ -448,21 +805,150 @@ impl<T, const M: usize, const N: usize> Matrix<T, M, N> {
         unsafe { &mut *i.get_unchecked_mut(self) }
     }
 
+    /// Returns mutable references to many elements at once.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::IndexOutOfBounds`] if any of the indices are out of bounds.
+    /// - [`Error::OverlappingIndices`] if any two indices refer to the same
+    ///   element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let mut m = matrix![
+    ///     1, 2;
+    ///     3, 4;
+    /// ];
+    /// if let Ok([a, b]) = m.get_many_mut([(0, 1), (1, 0)]) {
+    ///     core::mem::swap(a, b);
+    /// }
+    /// assert_eq!(m, matrix![1, 3; 2, 4]);
+    /// assert!(m.get_many_mut([0, 0]).is_err());
+    /// ```
+    pub fn get_many_mut<I, const K: usize>(&mut self, indices: [I; K]) -> Result<[&mut T; K], Error>
+    where
+        I: MatrixIndex<Self, Output = T>,
+    {
+        let mut positions = [0; K];
+        for (k, index) in indices.iter().enumerate() {
+            let i = index
+                .element_index::<M, N>()
+                .ok_or(Error::IndexOutOfBounds)?;
+            if positions[..k].contains(&i) {
+                return Err(Error::OverlappingIndices);
+            }
+            positions[k] = i;
+        }
+        let ptr = self.as_mut_ptr();
+        // SAFETY: every position is in bounds and no two positions are the
+        // same so the references do not alias.
+        Ok(positions.map(|i| unsafe { &mut *ptr.add(i) }))
+    }
+
     /// Returns a reference to the `i`-th row of this matrix.
+    ///
+    /// # Panics
+    ///
+    /// If `i` is out of bounds.
+    #[track_caller]
     #[inline]
     pub fn row(&self, i: usize) -> &Row<T, M, N> {
-        Row::new(&self.as_slice()[i..])
+        row_assert::<M>(i);
+        // If the matrix has no columns then the row is empty.
+        Row::new(&self.as_slice()[i.min(M * N)..])
     }
 
     /// Returns a mutable reference to the `i`-th row of this matrix.
+    ///
+    /// # Panics
+    ///
+    /// If `i` is out of bounds.
+    #[track_caller]
     #[inline]
     pub fn row_mut(&mut self, i: usize) -> &mut Row<T, M, N> {
-        Row::new_mut(&mut self.as_mut_slice()[i..])
+        row_assert::<M>(i);
+        // If the matrix has no columns then the row is empty.
+        Row::new_mut(&mut self.as_mut_slice()[i.min(M * N)..])
+    }
+
+    /// Returns mutable references to many rows at once.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::IndexOutOfBounds`] if any of the indices are out of bounds.
+    /// - [`Error::OverlappingIndices`] if any two indices refer to the same
+    ///   row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let mut m = matrix![
+    ///     1, 2;
+    ///     3, 4;
+    /// ];
+    /// if let Ok([a, b]) = m.rows_many_mut([0, 1]) {
+    ///     a[1] += b[1];
+    /// }
+    /// assert_eq!(m, matrix![1, 6; 3, 4]);
+    /// ```
+    pub fn rows_many_mut<const K: usize>(
+        &mut self,
+        indices: [usize; K],
+    ) -> Result<[&mut Row<T, M, N>; K], Error> {
+        for (k, &i) in indices.iter().enumerate() {
+            if i >= M {
+                return Err(Error::IndexOutOfBounds);
+            }
+            if indices[..k].contains(&i) {
+                return Err(Error::OverlappingIndices);
+            }
+        }
+        let matrix = self as *mut Self;
+        // SAFETY: every row is in bounds and no two rows are the same, rows
+        // do not share any elements.
+        Ok(indices.map(|i| unsafe { (*matrix).row_mut(i) }))
+    }
+
+    /// Returns mutable references to two different rows of this matrix.
+    ///
+    /// # Panics
+    ///
+    /// If `a` and `b` are equal or if either is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let mut m = matrix![
+    ///     1, 2;
+    ///     3, 4;
+    /// ];
+    /// let (r0, r1) = m.rows_mut(0, 1);
+    /// for (a, b) in r1.iter_mut().zip(r0.iter()) {
+    ///     *a -= 3 * b;
+    /// }
+    /// assert_eq!(m, matrix![1, 2; 0, -2]);
+    /// ```
+    #[track_caller]
+    pub fn rows_mut(&mut self, a: usize, b: usize) -> (&mut Row<T, M, N>, &mut Row<T, M, N>) {
+        assert!(a != b, "rows must be different, both are {}", a);
+        row_assert::<M>(a);
+        row_assert::<M>(b);
+        let matrix = self as *mut Self;
+        // SAFETY: both rows are in bounds and are different, rows do not
+        // share any elements.
+        unsafe { ((*matrix).row_mut(a), (*matrix).row_mut(b)) }
     }
 
     /// Returns a reference to the `i`-th column of this matrix.
     #[inline]
-    pub fn column(&self, i: usize) -> &Column<T, M, N> {
+    pub const fn column(&self, i: usize) -> &Column<T, M, N> {
         Column::new(&self.data[i])
     }
 
@@ -472,87 +958,1716 @@ impl<T, const M: usize, const N: usize> Matrix<T, M, N> {
         Column::new_mut(&mut self.data[i])
     }
 
-    /// Returns an iterator over the underlying data.
-    #[inline]
-    pub fn iter(&self) -> slice::Iter<'_, T> {
-        self.as_slice().iter()
-    }
-
-    /// Returns a mutable iterator over the underlying data.
-    #[inline]
-    pub fn iter_mut(&mut self) -> slice::IterMut<'_, T> {
-        self.as_mut_slice().iter_mut()
-    }
-
-    /// Returns an iterator over the rows in this matrix.
-    #[inline]
-    pub fn iter_rows(&self) -> IterRows<'_, T, M, N> {
-        IterRows::new(self)
-    }
-
-    /// Returns a mutable iterator over the rows in this matrix.
-    #[inline]
-    pub fn iter_rows_mut(&mut self) -> IterRowsMut<'_, T, M, N> {
-        IterRowsMut::new(self)
-    }
-
-    /// Returns an iterator over the columns in this matrix.
-    #[inline]
-    pub fn iter_columns(&self) -> IterColumns<'_, T, M, N> {
-        IterColumns::new(self)
-    }
-
-    /// Returns a mutable iterator over the columns in this matrix.
+    /// Returns a copy of the `i`-th row of this matrix as a row vector.
+    ///
+    /// # Panics
+    ///
+    /// If `i` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{matrix, row_vector};
+    /// #
+    /// let m = matrix![1, 2, 3; 4, 5, 6];
+    /// assert_eq!(m.row_vector(1), row_vector![4, 5, 6]);
+    /// ```
+    #[must_use]
+    #[track_caller]
     #[inline]
-    pub fn iter_columns_mut(&mut self) -> IterColumnsMut<'_, T, M, N> {
-        IterColumnsMut::new(self)
+    pub fn row_vector(&self, i: usize) -> RowVector<T, N>
+    where
+        T: Copy,
+    {
+        row_assert::<M>(i);
+        RowVector::from_column_major_order(self.data.map(|column| [column[i]]))
     }
 
-    /// Returns a matrix of the same size as self, with function `f` applied to
-    /// each element in column-major order.
+    /// Returns a copy of the `i`-th column of this matrix as a column vector.
+    ///
+    /// # Panics
+    ///
+    /// If `i` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{matrix, vector};
+    /// #
+    /// let m = matrix![1, 2, 3; 4, 5, 6];
+    /// assert_eq!(m.column_vector(2), vector![3, 6]);
+    /// ```
+    #[must_use]
+    #[track_caller]
     #[inline]
-    pub fn map<F, U>(self, f: F) -> Matrix<U, M, N>
+    pub fn column_vector(&self, i: usize) -> Vector<T, M>
     where
-        F: FnMut(T) -> U,
+        T: Copy,
     {
-        // SAFETY: the iterator has the exact number of elements required.
-        unsafe { new::collect_unchecked(self.into_iter().map(f)) }
+        column_assert::<N>(i);
+        Vector::from_column_major_order([self.data[i]])
     }
 
-    /// Returns the L1 norm of the matrix.
+    /// Returns mutable references to two different columns of this matrix.
     ///
-    /// Also known as *Manhattan Distance* or *Taxicab norm*. L1 Norm is the sum
-    /// of the magnitudes of the vectors in a space.
+    /// # Panics
     ///
-    /// # Note
+    /// If `a` and `b` are equal or if either is out of bounds.
     ///
-    /// If the matrix is a *row vector* this method might not do what you what
-    /// you expect. For example:
+    /// # Examples
     ///
     /// ```
     /// # use vectrix::matrix;
     /// #
-    /// let row_vector = matrix![1, 2, 3];
-    /// assert_eq!(row_vector.l1_norm(), 3);
+    /// let mut m = matrix![
+    ///     1, 2;
+    ///     3, 4;
+    /// ];
+    /// let (c0, c1) = m.columns_mut(0, 1);
+    /// for (a, b) in c0.iter_mut().zip(c1.iter_mut()) {
+    ///     core::mem::swap(a, b);
+    /// }
+    /// assert_eq!(m, matrix![2, 1; 4, 3]);
+    /// ```
+    #[track_caller]
+    pub fn columns_mut(
+        &mut self,
+        a: usize,
+        b: usize,
+    ) -> (&mut Column<T, M, N>, &mut Column<T, M, N>) {
+        assert!(a != b, "columns must be different, both are {}", a);
+        column_assert::<N>(a);
+        column_assert::<N>(b);
+        let (lo, hi) = if a < b { (a, b) } else { (b, a) };
+        let (left, right) = self.data.split_at_mut(hi);
+        let (x, y) = (&mut left[lo], &mut right[0]);
+        let (x, y) = if a < b { (x, y) } else { (y, x) };
+        (Column::new_mut(x), Column::new_mut(y))
+    }
+
+    /// Swaps two rows in this matrix.
+    ///
+    /// If `a` and `b` are equal then the matrix is unchanged.
+    ///
+    /// # Panics
+    ///
+    /// If `a` or `b` are out of bounds.
+    ///
+    /// # Examples
     ///
-    /// let column_vector = matrix![1; 2; 3];
-    /// assert_eq!(column_vector.l1_norm(), 6);
     /// ```
-    pub fn l1_norm(&self) -> T
-    where
-        T: Copy + Ord + Abs + Zero + Sum<T>,
-    {
-        (0..N)
-            .map(|i| self.data[i].iter().copied().map(Abs::abs).sum())
-            .max()
-            .unwrap_or_else(Zero::zero)
+    /// # use vectrix::matrix;
+    /// #
+    /// let mut m = matrix![1, 2; 3, 4; 5, 6];
+    /// m.swap_rows(0, 2);
+    /// assert_eq!(m, matrix![5, 6; 3, 4; 1, 2]);
+    /// ```
+    #[track_caller]
+    #[inline]
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        row_assert::<M>(a);
+        row_assert::<M>(b);
+        for column in &mut self.data {
+            column.swap(a, b);
+        }
     }
-}
 
-////////////////////////////////////////////////////////////////////////////////
+    /// Swaps two columns in this matrix.
+    ///
+    /// If `a` and `b` are equal then the matrix is unchanged.
+    ///
+    /// # Panics
+    ///
+    /// If `a` or `b` are out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let mut m = matrix![1, 2, 3; 4, 5, 6];
+    /// m.swap_columns(0, 1);
+    /// assert_eq!(m, matrix![2, 1, 3; 5, 4, 6]);
+    /// ```
+    #[track_caller]
+    #[inline]
+    pub fn swap_columns(&mut self, a: usize, b: usize) {
+        column_assert::<N>(a);
+        column_assert::<N>(b);
+        self.data.swap(a, b);
+    }
+
+    /// Swaps two elements in this matrix given their row and column
+    /// positions.
+    ///
+    /// If `a` and `b` are equal then the matrix is unchanged.
+    ///
+    /// # Panics
+    ///
+    /// If `a` or `b` are out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let mut m = matrix![1, 2; 3, 4];
+    /// m.swap((0, 1), (1, 0));
+    /// assert_eq!(m, matrix![1, 3; 2, 4]);
+    /// ```
+    #[track_caller]
+    #[inline]
+    pub fn swap(&mut self, a: (usize, usize), b: (usize, usize)) {
+        // Index the matrix so that out of bounds positions panic with the
+        // usual message.
+        let _ = (&self[a], &self[b]);
+        self.as_mut_slice().swap(a.1 * M + a.0, b.1 * M + b.0);
+    }
+
+    /// Returns a view of the `P × Q` block of this matrix whose top left
+    /// element is at the given row and column position.
+    ///
+    /// # Panics
+    ///
+    /// If the block does not fit in the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let m = matrix![
+    ///     1, 2, 3;
+    ///     4, 5, 6;
+    ///     7, 8, 9;
+    /// ];
+    /// let block = m.block::<2, 2>(1, 1);
+    /// assert_eq!(block[(0, 1)], 6);
+    /// assert_eq!(block.get((2, 0)), None);
+    /// ```
+    #[track_caller]
+    #[inline]
+    pub fn block<const P: usize, const Q: usize>(
+        &self,
+        row: usize,
+        col: usize,
+    ) -> BlockView<'_, T, M, N, P, Q> {
+        if P > M || Q > N || row > M - P || col > N - Q {
+            block_panic::<M, N, P, Q>(row, col)
+        }
+        BlockView::new(self, row, col)
+    }
+
+    /// Returns a copy of the `P × Q` block of this matrix whose top left
+    /// element is at the given row and column position.
+    ///
+    /// # Panics
+    ///
+    /// If the block does not fit in the matrix.
+    ///
+    /// # Examples
+    ///
+    /// Extracting the rotation from a homogeneous transformation.
+    ///
+    /// ```
+    /// # use vectrix::{matrix, Matrix};
+    /// #
+    /// let m = matrix![
+    ///     0.0, -1.0, 0.0, 1.0;
+    ///     1.0, 0.0, 0.0, 2.0;
+    ///     0.0, 0.0, 1.0, 3.0;
+    ///     0.0, 0.0, 0.0, 1.0;
+    /// ];
+    /// let r: Matrix<_, 3, 3> = m.fixed_slice(0, 0);
+    /// assert_eq!(r, matrix![0.0, -1.0, 0.0; 1.0, 0.0, 0.0; 0.0, 0.0, 1.0]);
+    /// ```
+    #[must_use]
+    #[track_caller]
+    #[inline]
+    pub fn fixed_slice<const P: usize, const Q: usize>(
+        &self,
+        row: usize,
+        col: usize,
+    ) -> Matrix<T, P, Q>
+    where
+        T: Copy,
+    {
+        self.block::<P, Q>(row, col).to_matrix()
+    }
+
+    /// Shifts all the rows in this matrix up by one, placing `fill` in the
+    /// last row, and returns the first row that was shifted out.
+    ///
+    /// This treats the matrix as a fixed size history of row records, where
+    /// the first row is the oldest. Each column is shifted using a single
+    /// [`rotate_left()`][slice::rotate_left].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{matrix, row_vector};
+    /// #
+    /// let mut history = matrix![
+    ///     1, 10;
+    ///     2, 20;
+    ///     3, 30;
+    /// ];
+    /// let oldest = history.shift_rows_up(row_vector![4, 40]);
+    /// assert_eq!(oldest, row_vector![1, 10]);
+    /// assert_eq!(history, matrix![2, 20; 3, 30; 4, 40]);
+    /// ```
+    pub fn shift_rows_up(&mut self, fill: RowVector<T, N>) -> RowVector<T, N> {
+        if M == 0 {
+            return fill;
+        }
+        let iter = self.data.iter_mut().zip(fill).map(|(column, elem)| {
+            column.rotate_left(1);
+            mem::replace(&mut column[M - 1], elem)
+        });
+        // SAFETY: the iterator yields exactly one element per column.
+        unsafe { new::collect_unchecked(iter) }
+    }
+
+    /// Rotates the rows in this matrix up by `n % M`.
+    ///
+    /// After calling this method the row previously at index `n % M` is the
+    /// first row and the previous first row is at index `(M - n % M) % M`.
+    /// Each column is rotated using a single
+    /// [`rotate_left()`][slice::rotate_left].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let mut m = matrix![1, 2; 3, 4; 5, 6];
+    /// m.rotate_rows(1);
+    /// assert_eq!(m, matrix![3, 4; 5, 6; 1, 2]);
+    /// m.rotate_rows(5);
+    /// assert_eq!(m, matrix![1, 2; 3, 4; 5, 6]);
+    /// ```
+    pub fn rotate_rows(&mut self, n: usize) {
+        if M == 0 {
+            return;
+        }
+        for column in &mut self.data {
+            column.rotate_left(n % M);
+        }
+    }
+
+    /// Returns an iterator over the underlying data.
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.as_slice().iter()
+    }
+
+    /// Returns a mutable iterator over the underlying data.
+    #[inline]
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, T> {
+        self.as_mut_slice().iter_mut()
+    }
+
+    /// Returns an iterator over the rows in this matrix.
+    #[inline]
+    pub fn iter_rows(&self) -> IterRows<'_, T, M, N> {
+        IterRows::new(self)
+    }
+
+    /// Returns a mutable iterator over the rows in this matrix.
+    #[inline]
+    pub fn iter_rows_mut(&mut self) -> IterRowsMut<'_, T, M, N> {
+        IterRowsMut::new(self)
+    }
+
+    /// Calls a closure on each column in this matrix as an array.
+    ///
+    /// Since each column is contiguous and of a constant size the compiler is
+    /// often able to vectorize operations on the column much better than when
+    /// using the element iterators.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let m = matrix![1, 2, 3; 4, 5, 6];
+    /// let mut sums = Vec::new();
+    /// m.for_each_column(|column| sums.push(column.iter().sum::<i64>()));
+    /// assert_eq!(sums, [5, 7, 9]);
+    /// ```
+    #[inline]
+    pub fn for_each_column<F>(&self, f: F)
+    where
+        F: FnMut(&[T; M]),
+    {
+        self.data.iter().for_each(f)
+    }
+
+    /// Calls a closure on each column in this matrix as a mutable array.
+    ///
+    /// See [`.for_each_column()`][Matrix::for_each_column].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let mut m = matrix![1, 2, 3; 4, 5, 6];
+    /// m.for_each_column_mut(|column| column.reverse());
+    /// assert_eq!(m, matrix![4, 5, 6; 1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn for_each_column_mut<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut [T; M]),
+    {
+        self.data.iter_mut().for_each(f)
+    }
+
+    /// Folds every column in this matrix as an array into an accumulator by
+    /// applying an operation, returning the final result.
+    ///
+    /// See [`.for_each_column()`][Matrix::for_each_column].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let m = matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0];
+    /// let max = m.fold_columns(0.0, |acc, [a, b]| f64::max(acc, a * a + b * b));
+    /// assert_eq!(max, 45.0);
+    /// ```
+    #[inline]
+    pub fn fold_columns<B, F>(&self, init: B, f: F) -> B
+    where
+        F: FnMut(B, &[T; M]) -> B,
+    {
+        self.data.iter().fold(init, f)
+    }
+
+    /// Returns an iterator over the elements in this matrix in row-major
+    /// order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let m = matrix![1, 2, 3; 4, 5, 6];
+    /// let v: Vec<_> = m.iter_row_major().copied().collect();
+    /// assert_eq!(v, [1, 2, 3, 4, 5, 6]);
+    /// ```
+    #[inline]
+    pub fn iter_row_major(&self) -> IterRowMajor<'_, T, M, N> {
+        IterRowMajor::new(self)
+    }
+
+    /// Returns an iterator over the `(row, column)` positions in this matrix
+    /// in column-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let m = matrix![1, 2, 3; 4, 5, 6];
+    /// let v: Vec<_> = m.indices().collect();
+    /// assert_eq!(v, [(0, 0), (1, 0), (0, 1), (1, 1), (0, 2), (1, 2)]);
+    /// assert!(m.indices().zip(m.iter()).all(|(i, x)| m[i] == *x));
+    /// ```
+    #[inline]
+    pub fn indices(&self) -> Indices<M, N> {
+        Indices::new()
+    }
+
+    /// Returns an iterator over the `(row, column)` positions in this matrix
+    /// in row-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let m = matrix![1, 2, 3; 4, 5, 6];
+    /// let v: Vec<_> = m.indices_row_major().collect();
+    /// assert_eq!(v, [(0, 0), (0, 1), (0, 2), (1, 0), (1, 1), (1, 2)]);
+    /// ```
+    #[inline]
+    pub fn indices_row_major(&self) -> IndicesRowMajor<M, N> {
+        IndicesRowMajor::new()
+    }
+
+    /// Returns an iterator over the `(row, column)` positions and references
+    /// to the elements in this matrix in column-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let m = matrix![1, 2; 3, 4];
+    /// let v: Vec<_> = m.iter_indexed().collect();
+    /// assert_eq!(v, [((0, 0), &1), ((1, 0), &3), ((0, 1), &2), ((1, 1), &4)]);
+    /// ```
+    #[inline]
+    pub fn iter_indexed(&self) -> Zip<Indices<M, N>, slice::Iter<'_, T>> {
+        self.indices().zip(self.iter())
+    }
+
+    /// Returns an iterator over the `(row, column)` positions and mutable
+    /// references to the elements in this matrix in column-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let mut m = matrix![1, 2; 3, 4];
+    /// for ((i, j), x) in m.iter_indexed_mut() {
+    ///     *x += 10 * i + 100 * j;
+    /// }
+    /// assert_eq!(m, matrix![1, 102; 13, 114]);
+    /// ```
+    #[inline]
+    pub fn iter_indexed_mut(&mut self) -> Zip<Indices<M, N>, slice::IterMut<'_, T>> {
+        self.indices().zip(self.iter_mut())
+    }
+
+    /// Returns an iterator over the `(row, column)` positions and the elements
+    /// in this matrix in column-major order, consuming the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let m = matrix![1, 2; 3, 4];
+    /// let v: Vec<_> = m.into_iter_indexed().collect();
+    /// assert_eq!(v, [((0, 0), 1), ((1, 0), 3), ((0, 1), 2), ((1, 1), 4)]);
+    /// ```
+    #[inline]
+    pub fn into_iter_indexed(self) -> Zip<Indices<M, N>, IntoIter<T, M, N>> {
+        self.indices().zip(self)
+    }
+
+    /// Returns an iterator over all overlapping windows of `K` consecutive
+    /// rows in this matrix.
+    ///
+    /// Each window is an array of row views. If the matrix has fewer than `K`
+    /// rows then the iterator yields nothing.
+    ///
+    /// # Panics
+    ///
+    /// If `K` is zero.
+    ///
+    /// # Examples
+    ///
+    /// Computing the central difference along the rows of a grid.
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let m = matrix![
+    ///     1, 1;
+    ///     2, 4;
+    ///     4, 9;
+    ///     8, 16;
+    /// ];
+    /// let diffs: Vec<_> = m
+    ///     .windows_rows::<3>()
+    ///     .map(|[prev, _, next]| next[0] - prev[0])
+    ///     .collect();
+    /// assert_eq!(diffs, [3, 6]);
+    /// ```
+    #[track_caller]
+    #[inline]
+    pub fn windows_rows<const K: usize>(&self) -> WindowsRows<'_, T, M, N, K> {
+        if K == 0 {
+            windows_panic()
+        }
+        WindowsRows::new(self)
+    }
+
+    /// Returns an iterator over the columns in this matrix.
+    #[inline]
+    pub fn iter_columns(&self) -> IterColumns<'_, T, M, N> {
+        IterColumns::new(self)
+    }
+
+    /// Returns a mutable iterator over the columns in this matrix.
+    #[inline]
+    pub fn iter_columns_mut(&mut self) -> IterColumnsMut<'_, T, M, N> {
+        IterColumnsMut::new(self)
+    }
+
+    /// Returns a matrix of the same size as self, with function `f` applied to
+    /// each element in column-major order.
+    #[inline]
+    pub fn map<F, U>(self, f: F) -> Matrix<U, M, N>
+    where
+        F: FnMut(T) -> U,
+    {
+        // SAFETY: the iterator has the exact number of elements required.
+        unsafe { new::collect_unchecked(self.into_iter().map(f)) }
+    }
+
+    /// Applies function `f` to each element in this matrix in place, in
+    /// column-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let mut m = matrix![1, 2; 3, 4];
+    /// m.apply(|x| *x *= 10);
+    /// assert_eq!(m, matrix![10, 20; 30, 40]);
+    /// ```
+    #[inline]
+    pub fn apply<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut T),
+    {
+        self.iter_mut().for_each(f);
+    }
+
+    /// Applies function `f` to each element in this matrix in place, in
+    /// column-major order, passing the row index and column index along with
+    /// the element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let mut m = matrix![1, 2; 3, 4];
+    /// m.apply_with(|i, j, x| {
+    ///     if i == j {
+    ///         *x = 0;
+    ///     }
+    /// });
+    /// assert_eq!(m, matrix![0, 2; 3, 0]);
+    /// ```
+    #[inline]
+    pub fn apply_with<F>(&mut self, mut f: F)
+    where
+        F: FnMut(usize, usize, &mut T),
+    {
+        for (j, column) in self.data.iter_mut().enumerate() {
+            for (i, value) in column.iter_mut().enumerate() {
+                f(i, j, value);
+            }
+        }
+    }
+
+    /// Returns a matrix of the same size as self, with function `f` applied to
+    /// the row index, column index and value of each element in column-major
+    /// order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{matrix, Matrix};
+    /// #
+    /// let m = Matrix::<i32, 2, 3>::zero().map_with_location(|i, j, _| i as i32 - j as i32);
+    /// assert_eq!(m, matrix![0, -1, -2; 1, 0, -1]);
+    /// ```
+    #[inline]
+    pub fn map_with_location<F, U>(self, mut f: F) -> Matrix<U, M, N>
+    where
+        F: FnMut(usize, usize, T) -> U,
+    {
+        let iter = self
+            .into_iter()
+            .enumerate()
+            .map(|(k, value)| f(k % M, k / M, value));
+        // SAFETY: the iterator has the exact number of elements required.
+        unsafe { new::collect_unchecked(iter) }
+    }
+
+    /// Returns a matrix of the same size as self, with function `f` applied to
+    /// each pair of corresponding elements in this matrix and `other`, in
+    /// column-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let a = matrix![1, 2; 3, 4];
+    /// let b = matrix![5, 1; 2, 8];
+    /// assert_eq!(a.zip_map(b, i32::max), matrix![5, 2; 3, 8]);
+    /// ```
+    #[inline]
+    pub fn zip_map<U, V, F>(self, other: Matrix<U, M, N>, mut f: F) -> Matrix<V, M, N>
+    where
+        F: FnMut(T, U) -> V,
+    {
+        let iter = self.into_iter().zip(other).map(|(a, b)| f(a, b));
+        // SAFETY: the iterator has the exact number of elements required.
+        unsafe { new::collect_unchecked(iter) }
+    }
+
+    /// Returns a matrix of the same size as self, with function `f` applied to
+    /// references to each pair of corresponding elements in this matrix and
+    /// `other`, in column-major order.
+    ///
+    /// This is the same as [`zip_map()`][Matrix::zip_map] except the matrices
+    /// are borrowed instead of consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let a = matrix![1.0, 2.0; 3.0, 4.0];
+    /// let b = matrix![2.0, 2.0; 2.0, 2.0];
+    /// assert_eq!(a.zip_with(&b, |x, y| x / y), matrix![0.5, 1.0; 1.5, 2.0]);
+    /// ```
+    #[inline]
+    pub fn zip_with<U, V, F>(&self, other: &Matrix<U, M, N>, mut f: F) -> Matrix<V, M, N>
+    where
+        F: FnMut(&T, &U) -> V,
+    {
+        let iter = self.iter().zip(other.iter()).map(|(a, b)| f(a, b));
+        // SAFETY: the iterator has the exact number of elements required.
+        unsafe { new::collect_unchecked(iter) }
+    }
+
+    /// Folds every element in this matrix into an accumulator by applying an
+    /// operation, in column-major order, returning the final result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let m = matrix![1, -2; 3, -4];
+    /// let negatives = m.fold(0, |acc, &x| acc + (x < 0) as usize);
+    /// assert_eq!(negatives, 2);
+    /// ```
+    #[inline]
+    pub fn fold<B, F>(&self, init: B, f: F) -> B
+    where
+        F: FnMut(B, &T) -> B,
+    {
+        self.iter().fold(init, f)
+    }
+
+    /// Returns the sum of all the elements in this matrix.
+    ///
+    /// The elements are added in column-major order. The sum of an empty
+    /// matrix is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let m = matrix![1, 2, 3; 4, 5, 6];
+    /// assert_eq!(m.sum(), 21);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn sum(&self) -> T
+    where
+        T: Copy + Zero + Add<Output = T>,
+    {
+        self.fold(T::zero(), |acc, &x| acc + x)
+    }
+
+    /// Returns the product of all the elements in this matrix.
+    ///
+    /// The elements are multiplied in column-major order. The product of an
+    /// empty matrix is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let m = matrix![1, 2, 3; 4, 5, 6];
+    /// assert_eq!(m.product(), 720);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn product(&self) -> T
+    where
+        T: Copy + One + Mul<Output = T>,
+    {
+        self.fold(T::one(), |acc, &x| acc * x)
+    }
+
+    /// Returns a column vector with the sum of each row in this matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{matrix, vector};
+    /// #
+    /// let m = matrix![1, 2, 3; 4, 5, 6];
+    /// assert_eq!(m.sum_rows(), vector![6, 15]);
+    /// ```
+    #[must_use]
+    pub fn sum_rows(&self) -> Vector<T, M>
+    where
+        T: Copy + Zero + Add<Output = T>,
+    {
+        let mut vector = Vector::<T, M>::zero();
+        for column in &self.data {
+            for (acc, &x) in vector.iter_mut().zip(column) {
+                *acc = *acc + x;
+            }
+        }
+        vector
+    }
+
+    /// Returns a row vector with the sum of each column in this matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{matrix, row_vector};
+    /// #
+    /// let m = matrix![1, 2, 3; 4, 5, 6];
+    /// assert_eq!(m.sum_columns(), row_vector![5, 7, 9]);
+    /// ```
+    #[must_use]
+    pub fn sum_columns(&self) -> RowVector<T, N>
+    where
+        T: Copy + Zero + Add<Output = T>,
+    {
+        Matrix {
+            data: self
+                .data
+                .map(|column| [column.iter().fold(T::zero(), |acc, &x| acc + x)]),
+        }
+    }
+
+    /// Returns a column vector with the mean of each row in this matrix.
+    ///
+    /// If the matrix has no columns the means are `NaN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{matrix, vector};
+    /// #
+    /// let m = matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0];
+    /// assert_eq!(m.mean_rows(), vector![2.0, 5.0]);
+    /// ```
+    #[must_use]
+    pub fn mean_rows(&self) -> Vector<T, M>
+    where
+        T: Float,
+    {
+        self.sum_rows() / linalg::from_usize::<T>(N)
+    }
+
+    /// Returns a row vector with the mean of each column in this matrix.
+    ///
+    /// If the matrix has no rows the means are `NaN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{matrix, row_vector};
+    /// #
+    /// let m = matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0];
+    /// assert_eq!(m.mean_columns(), row_vector![2.5, 3.5, 4.5]);
+    /// ```
+    #[must_use]
+    pub fn mean_columns(&self) -> RowVector<T, N>
+    where
+        T: Float,
+    {
+        self.sum_columns() / linalg::from_usize::<T>(M)
+    }
+
+    /// Returns the smallest element in this matrix.
+    ///
+    /// This is not called `min()` because an inherent method with that name
+    /// would hide [`Ord::min()`]. See [`argmin()`][Matrix::argmin] for how ties
+    /// and incomparable elements are handled. Returns `None` if the matrix is
+    /// empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// assert_eq!(matrix![3, -1; 2, 7].min_element(), Some(-1));
+    /// ```
+    #[must_use]
+    pub fn min_element(&self) -> Option<T>
+    where
+        T: Copy + PartialOrd,
+    {
+        self.argmin().map(|i| self[i])
+    }
+
+    /// Returns the largest element in this matrix.
+    ///
+    /// This is not called `max()` because an inherent method with that name
+    /// would hide [`Ord::max()`]. See [`argmax()`][Matrix::argmax] for how ties
+    /// and incomparable elements are handled. Returns `None` if the matrix is
+    /// empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// assert_eq!(matrix![3, -1; 2, 7].max_element(), Some(7));
+    /// ```
+    #[must_use]
+    pub fn max_element(&self) -> Option<T>
+    where
+        T: Copy + PartialOrd,
+    {
+        self.argmax().map(|i| self[i])
+    }
+
+    /// Returns the `(row, column)` position of the smallest element in this
+    /// matrix.
+    ///
+    /// If there are multiple smallest elements the first one in column-major
+    /// order is returned. Elements which are incomparable with the current
+    /// smallest, for example `NaN`, are skipped unless they are first, see
+    /// [`argmin_total()`][Matrix::argmin_total] for a variant for floats
+    /// which orders `NaN`. Returns `None` if the matrix is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// assert_eq!(matrix![3, -1; 2, 7].argmin(), Some((0, 1)));
+    /// ```
+    #[must_use]
+    pub fn argmin(&self) -> Option<(usize, usize)>
+    where
+        T: PartialOrd,
+    {
+        self.position_by(|x, best| x < best)
+    }
+
+    /// Returns the `(row, column)` position of the largest element in this
+    /// matrix.
+    ///
+    /// If there are multiple largest elements the first one in column-major
+    /// order is returned. Elements which are incomparable with the current
+    /// largest, for example `NaN`, are skipped unless they are first, see
+    /// [`argmax_total()`][Matrix::argmax_total] for a variant for floats
+    /// which orders `NaN`. Returns `None` if the matrix is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let response = matrix![0.1, 0.4, 0.2; 0.3, 0.9, 0.5];
+    /// assert_eq!(response.argmax(), Some((1, 1)));
+    /// ```
+    #[must_use]
+    pub fn argmax(&self) -> Option<(usize, usize)>
+    where
+        T: PartialOrd,
+    {
+        self.position_by(|x, best| x > best)
+    }
+
+    /// Returns the smallest element in this matrix using the total ordering
+    /// of floats.
+    ///
+    /// See [`argmin_total()`][Matrix::argmin_total].
+    #[must_use]
+    pub fn min_element_total(&self) -> Option<T>
+    where
+        T: Float,
+    {
+        self.argmin_total().map(|i| self[i])
+    }
+
+    /// Returns the largest element in this matrix using the total ordering of
+    /// floats.
+    ///
+    /// See [`argmax_total()`][Matrix::argmax_total].
+    #[must_use]
+    pub fn max_element_total(&self) -> Option<T>
+    where
+        T: Float,
+    {
+        self.argmax_total().map(|i| self[i])
+    }
+
+    /// Returns the `(row, column)` position of the smallest element in this
+    /// matrix using the total ordering of floats.
+    ///
+    /// Unlike [`argmin()`][Matrix::argmin] every element is comparable, `-0.0`
+    /// is smaller than `0.0` and `NaN` with a negative sign is smaller than
+    /// every other element. If there are multiple smallest elements the first
+    /// one in column-major order is returned. Returns `None` if the matrix is
+    /// empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let m = matrix![f64::NAN, 1.0; 0.0, -0.0];
+    /// assert_eq!(m.argmin_total(), Some((1, 1)));
+    /// ```
+    #[must_use]
+    pub fn argmin_total(&self) -> Option<(usize, usize)>
+    where
+        T: Float,
+    {
+        self.position_by(|x, best| x.total_cmp(best) == Ordering::Less)
+    }
+
+    /// Returns the `(row, column)` position of the largest element in this
+    /// matrix using the total ordering of floats.
+    ///
+    /// Unlike [`argmax()`][Matrix::argmax] every element is comparable, `0.0`
+    /// is larger than `-0.0` and `NaN` with a positive sign is larger than
+    /// every other element. If there are multiple largest elements the first
+    /// one in column-major order is returned. Returns `None` if the matrix is
+    /// empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let m = matrix![1.0, f64::NAN; 3.0, 2.0];
+    /// assert_eq!(m.argmax_total(), Some((0, 1)));
+    /// ```
+    #[must_use]
+    pub fn argmax_total(&self) -> Option<(usize, usize)>
+    where
+        T: Float,
+    {
+        self.position_by(|x, best| x.total_cmp(best) == Ordering::Greater)
+    }
+
+    /// Returns the position of the first element for which `better` returns
+    /// `true` when compared to the best element so far.
+    fn position_by<F>(&self, mut better: F) -> Option<(usize, usize)>
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        let mut iter = self.iter_indexed();
+        let first = iter.next()?;
+        let (index, _) = iter.fold(
+            first,
+            |best, (index, x)| {
+                if better(x, best.1) {
+                    (index, x)
+                } else {
+                    best
+                }
+            },
+        );
+        Some(index)
+    }
+
+    /// Folds every pair of corresponding elements in this matrix and `other`
+    /// into an accumulator, in column-major order.
+    ///
+    /// Both matrices are iterated together in a single pass without creating
+    /// any intermediate matrix, which makes this a useful building block for
+    /// fused reductions and comparisons.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let a = matrix![1, 2; 3, 4];
+    /// let b = matrix![1, 5; 3, 2];
+    /// let diffs = a.zip_fold(&b, 0, |acc, x, y| acc + (x != y) as usize);
+    /// assert_eq!(diffs, 2);
+    /// ```
+    #[inline]
+    pub fn zip_fold<U, B, F>(&self, other: &Matrix<U, M, N>, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, &T, &U) -> B,
+    {
+        self.iter()
+            .zip(other.iter())
+            .fold(init, |acc, (a, b)| f(acc, a, b))
+    }
+
+    /// Returns the diagonal of the matrix.
+    ///
+    /// This method fails to compile if the matrix is not square.
+    pub fn diagonal(&self) -> Vector<T, M>
+    where
+        T: Copy + Zero,
+    {
+        let () = AssertSquare::<M, N>::OK;
+        let mut vector = Vector::zero();
+        for i in 0..M {
+            vector[i] = self[(i, i)];
+        }
+        vector
+    }
+
+    /// Returns a mutable strided view of the diagonal of the matrix.
+    ///
+    /// The stride `S` must be equal to `M + 1`. It can't be computed from `M`
+    /// on stable Rust, so in practice it must be given explicitly, for example
+    /// `diagonal_mut::<3>()` for a `2 × 2` matrix, unless the type of the
+    /// result is already known.
+    ///
+    /// This method fails to compile if the matrix is not square or if the
+    /// stride is not equal to `M + 1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{matrix, Matrix};
+    /// #
+    /// let mut m = matrix![1.0, 2.0; 3.0, 4.0];
+    /// for d in m.diagonal_mut::<3>().iter_mut() {
+    ///     *d += 0.5;
+    /// }
+    /// assert_eq!(m, matrix![1.5, 2.0; 3.0, 4.5]);
+    /// ```
+    #[inline]
+    pub fn diagonal_mut<const S: usize>(&mut self) -> &mut Stride<T, S> {
+        let () = AssertSquare::<M, N>::OK;
+        let () = AssertDiagonalStride::<S, M>::OK;
+        Stride::new_mut(self.as_mut_slice())
+    }
+
+    /// Sets the diagonal of the matrix to the given vector.
+    ///
+    /// This method fails to compile if the matrix is not square.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{matrix, vector};
+    /// #
+    /// let mut m = matrix![1, 2; 3, 4];
+    /// m.set_diagonal(&vector![5, 6]);
+    /// assert_eq!(m, matrix![5, 2; 3, 6]);
+    /// ```
+    pub fn set_diagonal(&mut self, diagonal: &Vector<T, M>)
+    where
+        T: Copy,
+    {
+        let () = AssertSquare::<M, N>::OK;
+        for i in 0..M {
+            self[(i, i)] = diagonal[i];
+        }
+    }
+
+    /// Sets every element on the diagonal of the matrix to the given value.
+    ///
+    /// This method fails to compile if the matrix is not square.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let mut m = matrix![1, 2; 3, 4];
+    /// m.fill_diagonal(0);
+    /// assert_eq!(m, matrix![0, 2; 3, 0]);
+    /// ```
+    pub fn fill_diagonal(&mut self, value: T)
+    where
+        T: Copy,
+    {
+        let () = AssertSquare::<M, N>::OK;
+        for i in 0..M {
+            self[(i, i)] = value;
+        }
+    }
+
+    /// Returns the matrix with each element restricted to `[min, max]`.
+    ///
+    /// Elements less than `min` are replaced with `min` and elements greater
+    /// than `max` are replaced with `max`. Incomparable elements, for example
+    /// `NaN`, are unchanged. This is not named `clamp` because that would hide
+    /// [`Ord::clamp()`] which clamps the whole matrix lexicographically.
+    ///
+    /// # Panics
+    ///
+    /// If `min > max` or if either bound is incomparable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let m = matrix![-2.0, 0.5; 3.0, 1.0];
+    /// assert_eq!(m.clamp_elements(0.0, 1.0), matrix![0.0, 0.5; 1.0, 1.0]);
+    /// ```
+    #[must_use]
+    #[track_caller]
+    #[inline]
+    pub fn clamp_elements(mut self, min: T, max: T) -> Self
+    where
+        T: Copy + PartialOrd,
+    {
+        assert!(min <= max, "`min` must not be greater than `max`");
+        self.apply(|x| {
+            if *x < min {
+                *x = min;
+            } else if *x > max {
+                *x = max;
+            }
+        });
+        self
+    }
+
+    /// Returns the matrix with the absolute value of each element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let m = matrix![-2, 0; 3, -1];
+    /// assert_eq!(m.abs(), matrix![2, 0; 3, 1]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn abs(self) -> Self
+    where
+        T: Abs,
+    {
+        self.map(Abs::abs)
+    }
+
+    /// Returns the matrix with the sign of each element.
+    ///
+    /// Each element is replaced with `-1` if it is negative, `1` if it is
+    /// positive and `0` if it is zero. Unlike [`f64::signum()`] zero is
+    /// mapped to zero, regardless of its sign. Incomparable elements, for
+    /// example `NaN`, are unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let m = matrix![-2.5, 0.0; 3.0, -0.0];
+    /// assert_eq!(m.signum(), matrix![-1.0, 0.0; 1.0, 0.0]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn signum(mut self) -> Self
+    where
+        T: Copy + PartialOrd + Zero + One + Neg<Output = T>,
+    {
+        self.apply(|x| {
+            if *x > T::zero() {
+                *x = T::one();
+            } else if *x < T::zero() {
+                *x = -T::one();
+            } else if *x == T::zero() {
+                *x = T::zero();
+            }
+        });
+        self
+    }
+
+    /// Returns the matrix with the square root of each element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let m = matrix![4.0, 9.0; 0.25, 0.0];
+    /// assert_eq!(m.sqrt(), matrix![2.0, 3.0; 0.5, 0.0]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn sqrt(self) -> Self
+    where
+        T: Float,
+    {
+        self.map(Float::sqrt)
+    }
+
+    /// Returns the matrix with `e` raised to the power of each element.
+    ///
+    /// This is not the matrix exponential, see [`exp()`][Matrix::exp] for
+    /// that.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let m = matrix![0.0, 1.0; 0.0, 0.0].exp_elementwise();
+    /// assert_eq!(m[(0, 0)], 1.0);
+    /// assert!((m[(0, 1)] - std::f64::consts::E).abs() < 1e-15);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn exp_elementwise(self) -> Self
+    where
+        T: Float,
+    {
+        self.map(Float::exp)
+    }
+
+    /// Returns the matrix with the natural logarithm of each element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let m = matrix![1.0, std::f64::consts::E];
+    /// assert_eq!(m.ln(), matrix![0.0, 1.0]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn ln(self) -> Self
+    where
+        T: Float,
+    {
+        self.map(Float::ln)
+    }
+
+    /// Returns the matrix with each element raised to the power `n`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let m = matrix![4.0, 9.0; 2.0, 0.5];
+    /// assert_eq!(m.powf(2.0), matrix![16.0, 81.0; 4.0, 0.25]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn powf(self, n: T) -> Self
+    where
+        T: Float,
+    {
+        self.map(|x| x.powf(n))
+    }
+
+    /// Returns the matrix with the reciprocal of each element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let m = matrix![4.0, -0.5; 1.0, 8.0];
+    /// assert_eq!(m.recip(), matrix![0.25, -2.0; 1.0, 0.125]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn recip(self) -> Self
+    where
+        T: Float,
+    {
+        self.map(|x| T::one() / x)
+    }
+
+    /// Returns the linear interpolation between this matrix and `other`.
+    ///
+    /// Each element is computed as `self * (1 - t) + other * t`, so `t = 0`
+    /// returns `self` and `t = 1` returns `other`. The value of `t` is not
+    /// clamped so values outside of `[0, 1]` will extrapolate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::vector;
+    /// #
+    /// let a = vector![0.0, 10.0];
+    /// let b = vector![4.0, 20.0];
+    /// assert_eq!(a.lerp(b, 0.25), vector![1.0, 12.5]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn lerp(mut self, other: Self, t: T) -> Self
+    where
+        T: Copy + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+    {
+        let s = T::one() - t;
+        for i in 0..(M * N) {
+            self[i] = self[i] * s + other[i] * t;
+        }
+        self
+    }
+
+    /// Returns the smoothstep interpolation between this matrix and `other`.
+    ///
+    /// This is the same as [`lerp()`][Self::lerp] except `t` is first clamped
+    /// to `[0, 1]` and then eased using `3t² - 2t³`, so the interpolation
+    /// starts and ends with zero velocity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::vector;
+    /// #
+    /// let a = vector![0.0, 10.0];
+    /// let b = vector![4.0, 20.0];
+    /// assert_eq!(a.smoothstep(b, 0.5), vector![2.0, 15.0]);
+    /// assert_eq!(a.smoothstep(b, 0.25), vector![0.625, 11.5625]);
+    /// assert_eq!(a.smoothstep(b, 2.0), b);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn smoothstep(self, other: Self, t: T) -> Self
+    where
+        T: Float,
+    {
+        let t = min(max(t, T::zero()), T::one());
+        let two = T::one() + T::one();
+        self.lerp(other, t * t * (T::one() + two - two * t))
+    }
+
+    /// Moves this matrix towards `target` by at most `max_delta`.
+    ///
+    /// The distance is measured using the
+    /// [`.frobenius_norm()`][Self::frobenius_norm], which is the Euclidean
+    /// distance for vectors. If `target` is within `max_delta` then `target`
+    /// is returned, so repeated calls will reach it exactly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::vector;
+    /// #
+    /// let a = vector![0.0, 0.0];
+    /// let b = vector![3.0, 4.0];
+    /// assert_eq!(a.move_towards(&b, 2.5), vector![1.5, 2.0]);
+    /// assert_eq!(a.move_towards(&b, 10.0), b);
+    /// ```
+    #[must_use]
+    pub fn move_towards(self, target: &Self, max_delta: T) -> Self
+    where
+        T: Float,
+    {
+        let delta = *target - self;
+        let distance = delta.frobenius_norm();
+        if distance <= max_delta || distance == T::zero() {
+            *target
+        } else {
+            self + delta * (max_delta / distance)
+        }
+    }
+
+    /// Returns the L1 norm of the matrix.
+    ///
+    /// Also known as *Manhattan Distance* or *Taxicab norm*. L1 Norm is the sum
+    /// of the magnitudes of the vectors in a space.
+    ///
+    /// # Note
+    ///
+    /// If the matrix is a *row vector* this method might not do what you what
+    /// you expect. For example:
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let row_vector = matrix![1, 2, 3];
+    /// assert_eq!(row_vector.l1_norm(), 3);
+    ///
+    /// let column_vector = matrix![1; 2; 3];
+    /// assert_eq!(column_vector.l1_norm(), 6);
+    /// ```
+    pub fn l1_norm(&self) -> T
+    where
+        T: Copy + PartialOrd + Abs + Zero + Sum<T>,
+    {
+        (0..N)
+            .map(|i| self.data[i].iter().copied().map(Abs::abs).sum())
+            .fold(T::zero(), max)
+    }
+
+    /// Returns the L∞ norm of the matrix.
+    ///
+    /// This is the maximum absolute row sum of the matrix. For a *column
+    /// vector* this is the largest magnitude of its elements.
+    ///
+    /// # Note
+    ///
+    /// Like [`.l1_norm()`][Matrix::l1_norm], if the matrix is a *row vector*
+    /// this might not do what you expect. For example:
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let row_vector = matrix![1, -2, 3];
+    /// assert_eq!(row_vector.linf_norm(), 6);
+    ///
+    /// let column_vector = matrix![1; -2; 3];
+    /// assert_eq!(column_vector.linf_norm(), 3);
+    /// ```
+    pub fn linf_norm(&self) -> T
+    where
+        T: Copy + PartialOrd + Abs + Zero + Sum<T>,
+    {
+        (0..M)
+            .map(|i| self.row(i).iter().copied().map(Abs::abs).sum())
+            .fold(T::zero(), max)
+    }
+
+    /// Returns the L2 norm of the matrix.
+    ///
+    /// This is the largest singular value of the matrix, also known as the
+    /// *spectral norm*. For both row and column vectors this is the Euclidean
+    /// length of the vector and is the same as the
+    /// [`.frobenius_norm()`][Matrix::frobenius_norm].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let v = matrix![3.0; 4.0];
+    /// assert_eq!(v.l2_norm(), 5.0);
+    ///
+    /// let m = matrix![
+    ///     3.0, 0.0;
+    ///     4.0, 5.0;
+    /// ];
+    /// assert!((m.l2_norm() - 45f64.sqrt()).abs() < 1e-12);
+    /// ```
+    pub fn l2_norm(&self) -> T
+    where
+        T: Float,
+    {
+        if M == 1 || N == 1 {
+            return self.frobenius_norm();
+        }
+        let sigma = linalg::svd(*self).1;
+        sigma.iter().copied().fold(T::zero(), max)
+    }
+
+    /// Returns the Frobenius norm of the matrix.
+    ///
+    /// This is the square root of the sum of the squares of all the elements
+    /// in the matrix. For both row and column vectors this is the Euclidean
+    /// length of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let m = matrix![
+    ///     1.0, -1.0;
+    ///     3.0, -5.0;
+    /// ];
+    /// assert_eq!(m.frobenius_norm(), 6.0);
+    /// ```
+    pub fn frobenius_norm(&self) -> T
+    where
+        T: Float,
+    {
+        self.iter().fold(T::zero(), |acc, &d| acc + d * d).sqrt()
+    }
+
+    /// Returns the sum of the squared differences between the elements of
+    /// this matrix and `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let a = matrix![1, 2; 3, 4];
+    /// let b = matrix![2, 2; 0, 4];
+    /// assert_eq!(a.sum_squared_diff(&b), 10);
+    /// ```
+    pub fn sum_squared_diff(&self, other: &Self) -> T
+    where
+        T: Copy + PartialOrd + Zero + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+    {
+        self.zip_fold(other, T::zero(), |acc, &a, &b| {
+            // Written so that unsigned integers do not underflow.
+            let d = if a > b { a - b } else { b - a };
+            acc + d * d
+        })
+    }
+
+    /// Returns the mean of the squared differences between the elements of
+    /// this matrix and `other`.
+    ///
+    /// For empty matrices this is `NaN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let a = matrix![1.0, 2.0; 3.0, 4.0];
+    /// let b = matrix![2.0, 2.0; 0.0, 4.0];
+    /// assert_eq!(a.mse(&b), 2.5);
+    /// ```
+    pub fn mse(&self, other: &Self) -> T
+    where
+        T: Float,
+    {
+        self.sum_squared_diff(other) / linalg::from_usize(M * N)
+    }
+
+    /// Returns the largest absolute difference between the elements of this
+    /// matrix and `other`.
+    ///
+    /// For empty matrices this is zero. If any difference is incomparable, for
+    /// example `NaN`, then the first such difference is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let a = matrix![1, 2; 3, 4];
+    /// let b = matrix![2, 2; 0, 4];
+    /// assert_eq!(a.max_abs_diff(&b), 3);
+    /// assert!(matrix![f64::NAN].max_abs_diff(&matrix![1.0]).is_nan());
+    /// ```
+    pub fn max_abs_diff(&self, other: &Self) -> T
+    where
+        T: Copy + PartialOrd + Zero + Sub<Output = T>,
+    {
+        self.zip_fold(other, T::zero(), |acc, &a, &b| {
+            let d = if a > b { a - b } else { b - a };
+            match acc.partial_cmp(&d) {
+                Some(Ordering::Less) => d,
+                Some(_) => acc,
+                None if acc.partial_cmp(&acc).is_none() => acc,
+                None => d,
+            }
+        })
+    }
+}
+
+#[track_caller]
+#[inline]
+fn row_assert<const M: usize>(i: usize) {
+    if i >= M {
+        row_panic::<M>(i)
+    }
+}
+
+#[track_caller]
+#[inline]
+fn column_assert<const N: usize>(j: usize) {
+    if j >= N {
+        column_panic::<N>(j)
+    }
+}
+
+#[cold]
+#[track_caller]
+fn block_panic<const M: usize, const N: usize, const P: usize, const Q: usize>(
+    row: usize,
+    col: usize,
+) -> ! {
+    panic!(
+        "block of size {}x{} at ({}, {}) out of range for matrix with {} rows and {} columns",
+        P, Q, row, col, M, N
+    );
+}
+
+#[cold]
+#[track_caller]
+fn windows_panic() -> ! {
+    panic!("window size must be non-zero");
+}
+
+#[cold]
+#[track_caller]
+fn row_panic<const M: usize>(i: usize) -> ! {
+    panic!("row index {} out of range for matrix with {} rows", i, M);
+}
+
+#[cold]
+#[track_caller]
+fn column_panic<const N: usize>(j: usize) -> ! {
+    panic!(
+        "column index {} out of range for matrix with {} columns",
+        j, N
+    );
+}
+
+/// Returns the sum of the products of a dot product.
+///
+/// The products are accumulated strictly from left to right starting from the
+/// first product, so the result does not depend on the [`Sum`]
+/// implementation's initial value. Every dot product in this crate goes
+/// through this function.
+#[inline]
+pub(crate) fn sum_products<T, I>(mut products: I) -> T
+where
+    T: Add<Output = T> + Sum,
+    I: Iterator<Item = T>,
+{
+    match products.next() {
+        Some(first) => products.fold(first, |acc, p| acc + p),
+        None => products.sum(),
+    }
+}
+
+/// Returns the larger of two partially ordered values.
+#[inline]
+pub(crate) fn max<T: PartialOrd>(a: T, b: T) -> T {
+    if b > a {
+        b
+    } else {
+        a
+    }
+}
+
+/// Returns the smaller of two partially ordered values.
+#[inline]
+pub(crate) fn min<T: PartialOrd>(a: T, b: T) -> T {
+    if b < a {
+        b
+    } else {
+        a
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
 // Matrix<T, N, N> methods
 ////////////////////////////////////////////////////////////////////////////////
 
+impl<T: Copy + ConstZero + ConstOne, const N: usize> Matrix<T, N, N> {
+    /// An identity matrix.
+    ///
+    /// Unlike [`Matrix::identity()`] this is usable in `const` and `static`
+    /// contexts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{matrix, Matrix};
+    /// #
+    /// const I: Matrix<i64, 2, 2> = Matrix::IDENTITY;
+    /// assert_eq!(I, matrix![1, 0; 0, 1]);
+    /// ```
+    pub const IDENTITY: Self = {
+        let mut data = [[T::ZERO; N]; N];
+        let mut i = 0;
+        while i < N {
+            data[i][i] = T::ONE;
+            i += 1;
+        }
+        Self { data }
+    };
+}
+
 impl<T, const N: usize> Matrix<T, N, N> {
     /// Returns an identity matrix.
     #[must_use]
@@ -568,15 +2683,57 @@ impl<T, const N: usize> Matrix<T, N, N> {
         matrix
     }
 
-    /// Returns the diagonal of the matrix.
-    pub fn diagonal(&self) -> Vector<T, N>
+    /// Returns a diagonal matrix with the given elements on the main diagonal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{matrix, vector, Matrix};
+    /// #
+    /// let m = Matrix::from_diagonal(&vector![1, 2, 3]);
+    /// assert_eq!(
+    ///     m,
+    ///     matrix![
+    ///         1, 0, 0;
+    ///         0, 2, 0;
+    ///         0, 0, 3;
+    ///     ]
+    /// );
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn from_diagonal(diagonal: &Vector<T, N>) -> Self
     where
         T: Copy + Zero,
     {
-        let mut vector = Vector::zero();
+        let mut matrix = Self::zero();
         for i in 0..N {
-            vector[i] = self[(i, i)];
+            matrix[(i, i)] = diagonal[i];
         }
-        vector
+        matrix
+    }
+
+    /// Returns a diagonal matrix with the given element repeated along the
+    /// main diagonal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{matrix, Matrix};
+    /// #
+    /// let m = Matrix::from_diagonal_element(5);
+    /// assert_eq!(m, matrix![5, 0; 0, 5]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn from_diagonal_element(element: T) -> Self
+    where
+        T: Copy + Zero,
+    {
+        let mut matrix = Self::zero();
+        for i in 0..N {
+            matrix[(i, i)] = element;
+        }
+        matrix
     }
 }
@@ -0,0 +1,681 @@
+//! Numerical linear algebra routines.
+
+use core::iter::Sum;
+use core::ops::{Add, Mul, Sub};
+
+use crate::{Float, Matrix, One, Transpose, Vector, Zero};
+
+/// The maximum number of sweeps performed by the Jacobi SVD algorithm.
+///
+/// The algorithm converges quadratically so in practice only a handful of
+/// sweeps are ever required.
+const MAX_SWEEPS: usize = 64;
+
+/// Computes the singular value decomposition of a matrix.
+///
+/// This uses the one-sided Jacobi algorithm which orthogonalizes the columns
+/// of the matrix using plane rotations. The singular values are returned
+/// unordered along with the matrix `A * V` whose columns are the left singular
+/// vectors scaled by the singular values, and `V` whose columns are the right
+/// singular vectors.
+pub(crate) fn svd<T, const M: usize, const N: usize>(
+    mut a: Matrix<T, M, N>,
+) -> (Matrix<T, M, N>, Vector<T, N>, Matrix<T, N, N>)
+where
+    T: Float,
+{
+    let mut v = Matrix::identity();
+
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated = false;
+
+        for p in 0..N {
+            for q in (p + 1)..N {
+                let (mut alpha, mut beta, mut gamma) = (T::zero(), T::zero(), T::zero());
+                for i in 0..M {
+                    alpha = alpha + a[(i, p)] * a[(i, p)];
+                    beta = beta + a[(i, q)] * a[(i, q)];
+                    gamma = gamma + a[(i, p)] * a[(i, q)];
+                }
+                if gamma.abs() <= T::epsilon() * (alpha * beta).sqrt() {
+                    continue;
+                }
+                rotated = true;
+
+                // Compute the rotation that makes columns `p` and `q`
+                // orthogonal.
+                let two = T::one() + T::one();
+                let zeta = (beta - alpha) / (two * gamma);
+                let t = T::one() / (zeta.abs() + (zeta * zeta + T::one()).sqrt());
+                let t = if zeta < T::zero() { -t } else { t };
+                let c = T::one() / (t * t + T::one()).sqrt();
+                let s = t * c;
+
+                for i in 0..M {
+                    let (aip, aiq) = (a[(i, p)], a[(i, q)]);
+                    a[(i, p)] = c * aip - s * aiq;
+                    a[(i, q)] = s * aip + c * aiq;
+                }
+                for i in 0..N {
+                    let (vip, viq) = (v[(i, p)], v[(i, q)]);
+                    v[(i, p)] = c * vip - s * viq;
+                    v[(i, q)] = s * vip + c * viq;
+                }
+            }
+        }
+
+        if !rotated {
+            break;
+        }
+    }
+
+    let sigma = (0..N).map(|j| column_norm(&a, j)).collect();
+    (a, sigma, v)
+}
+
+/// Solves the linear system `A * X = B` for `X`.
+///
+/// This uses Gaussian elimination with partial pivoting. Returns `None` if the
+/// matrix `A` is singular.
+pub(crate) fn solve<T, const N: usize, const P: usize>(
+    mut a: Matrix<T, N, N>,
+    mut b: Matrix<T, N, P>,
+) -> Option<Matrix<T, N, P>>
+where
+    T: Float,
+{
+    for k in 0..N {
+        // Find the row with the largest pivot and swap it into place.
+        let pivot = (k..N).fold(k, |p, i| {
+            if a[(i, k)].abs() > a[(p, k)].abs() {
+                i
+            } else {
+                p
+            }
+        });
+        if a[(pivot, k)] == T::zero() {
+            return None;
+        }
+        if pivot != k {
+            for j in 0..N {
+                a.as_mut_slice().swap(j * N + k, j * N + pivot);
+            }
+            for j in 0..P {
+                b.as_mut_slice().swap(j * N + k, j * N + pivot);
+            }
+        }
+
+        // Eliminate the column below the pivot.
+        for i in (k + 1)..N {
+            let f = a[(i, k)] / a[(k, k)];
+            for j in k..N {
+                a[(i, j)] = a[(i, j)] - f * a[(k, j)];
+            }
+            for j in 0..P {
+                b[(i, j)] = b[(i, j)] - f * b[(k, j)];
+            }
+        }
+    }
+
+    // Back substitution.
+    for j in 0..P {
+        for i in (0..N).rev() {
+            let sum = ((i + 1)..N).fold(b[(i, j)], |acc, k| acc - a[(i, k)] * b[(k, j)]);
+            b[(i, j)] = sum / a[(i, i)];
+        }
+    }
+
+    Some(b)
+}
+
+/// Computes the determinant of a square matrix.
+///
+/// This uses the Samuelson–Berkowitz algorithm which builds up the
+/// characteristic polynomial of each leading principal submatrix in turn. It
+/// only requires addition, subtraction and multiplication so it is exact for
+/// integers and valid for matrices over any commutative ring.
+pub(crate) fn determinant<T, const N: usize>(a: &Matrix<T, N, N>) -> T
+where
+    T: Copy + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    // The coefficients of the characteristic polynomial of the leading `r × r`
+    // submatrix in order of decreasing degree, excluding the leading one.
+    let mut c = [T::zero(); N];
+    // The first column of the Toeplitz matrix that maps the coefficients for
+    // the `r × r` submatrix to those for the `(r + 1) × (r + 1)` submatrix,
+    // again excluding the leading one.
+    let mut q = [T::zero(); N];
+    let mut v = [T::zero(); N];
+    let mut w = [T::zero(); N];
+
+    for r in 0..N {
+        q[0] = T::zero() - a[(r, r)];
+        for i in 0..r {
+            v[i] = a[(i, r)];
+        }
+        for (k, d) in q.iter_mut().enumerate().take(r + 1).skip(1) {
+            let dot = (0..r).fold(T::zero(), |acc, j| acc + a[(r, j)] * v[j]);
+            *d = T::zero() - dot;
+            if k < r {
+                for (i, d) in w.iter_mut().enumerate().take(r) {
+                    *d = (0..r).fold(T::zero(), |acc, j| acc + a[(i, j)] * v[j]);
+                }
+                v[..r].copy_from_slice(&w[..r]);
+            }
+        }
+        for i in (1..=r + 1).rev() {
+            let mut sum = q[i - 1];
+            for k in 1..=i.min(r) {
+                let t = if k == i { T::one() } else { q[i - k - 1] };
+                sum = sum + t * c[k - 1];
+            }
+            c[i - 1] = sum;
+        }
+    }
+
+    match N {
+        0 => T::one(),
+        n if n & 1 == 0 => c[n - 1],
+        n => T::zero() - c[n - 1],
+    }
+}
+
+/// Returns the tolerance below which singular values are considered zero.
+fn rank_tolerance<T, const M: usize, const N: usize>(sigma: &Vector<T, N>) -> T
+where
+    T: Float,
+{
+    let max = sigma.iter().copied().fold(T::zero(), crate::max);
+    from_usize::<T>(M.max(N)) * T::epsilon() * max
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Null space and pseudoinverse
+////////////////////////////////////////////////////////////////////////////////
+
+impl<T, const M: usize, const N: usize> Matrix<T, M, N> {
+    /// Returns an orthonormal basis for the null space of this matrix.
+    ///
+    /// The null space, also known as the *kernel*, is the set of vectors `x`
+    /// such that `A * x` is zero. The basis is returned as the columns of a
+    /// matrix along with the dimension of the null space, also known as the
+    /// *nullity*. Only the first `nullity` columns of the matrix are valid, the
+    /// remaining columns are zero.
+    ///
+    /// The basis is computed using the singular value decomposition of the
+    /// matrix, singular values that are negligible relative to the largest
+    /// singular value are treated as zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let m = matrix![
+    ///     1.0, 1.0, 0.0;
+    ///     0.0, 0.0, 1.0;
+    /// ];
+    /// let (basis, nullity) = m.null_space();
+    /// assert_eq!(nullity, 1);
+    /// assert!((m * basis).frobenius_norm() < 1e-12);
+    /// ```
+    pub fn null_space(&self) -> (Matrix<T, N, N>, usize)
+    where
+        T: Float,
+    {
+        let (_, sigma, v) = svd(*self);
+        let tol = rank_tolerance::<T, M, N>(&sigma);
+
+        let mut basis = Matrix::zero();
+        let mut nullity = 0;
+        for j in 0..N {
+            if sigma[j] <= tol {
+                for i in 0..N {
+                    basis[(i, nullity)] = v[(i, j)];
+                }
+                nullity += 1;
+            }
+        }
+        (basis, nullity)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Orthonormalization
+////////////////////////////////////////////////////////////////////////////////
+
+impl<T, const M: usize, const N: usize> Matrix<T, M, N> {
+    /// Returns a matrix whose columns are an orthonormal basis for the columns
+    /// of this matrix.
+    ///
+    /// This uses the modified Gram–Schmidt process over each column in order.
+    /// If a column is linearly dependent on the previous columns then the
+    /// corresponding column in the result is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{matrix, Matrix};
+    /// #
+    /// let m = matrix![
+    ///     2.0, 1.0;
+    ///     0.0, 3.0;
+    /// ];
+    /// assert_eq!(m.orthonormalize(), Matrix::identity());
+    /// ```
+    #[must_use]
+    pub fn orthonormalize(self) -> Self
+    where
+        T: Float,
+    {
+        self.orthonormalize_with_r().0
+    }
+
+    /// Returns a matrix whose columns are an orthonormal basis for the columns
+    /// of this matrix along with the upper triangular `R` factor.
+    ///
+    /// This is the same as [`.orthonormalize()`][Matrix::orthonormalize] except
+    /// that the coefficients are also returned such that `Q * R` is equal to
+    /// the original matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let m = matrix![
+    ///     3.0, 1.0;
+    ///     4.0, 2.0;
+    /// ];
+    /// let (q, r) = m.orthonormalize_with_r();
+    /// assert_eq!(r[(1, 0)], 0.0);
+    /// assert!((q * r - m).frobenius_norm() < 1e-12);
+    /// ```
+    #[must_use]
+    pub fn orthonormalize_with_r(mut self) -> (Self, Matrix<T, N, N>)
+    where
+        T: Float,
+    {
+        // The tolerance below which a column is considered linearly dependent
+        // is relative to the size of the original column.
+        let tol = T::epsilon() * from_usize(M);
+        let scales: Vector<T, N> = (0..N).map(|j| column_norm(&self, j)).collect();
+
+        let mut r = Matrix::zero();
+        for j in 0..N {
+            let norm = column_norm(&self, j);
+            if norm <= tol * scales[j] {
+                for i in 0..M {
+                    self[(i, j)] = T::zero();
+                }
+                continue;
+            }
+            r[(j, j)] = norm;
+            for i in 0..M {
+                self[(i, j)] = self[(i, j)] / norm;
+            }
+            for k in (j + 1)..N {
+                let dot = (0..M).fold(T::zero(), |acc, i| acc + self[(i, j)] * self[(i, k)]);
+                r[(j, k)] = dot;
+                for i in 0..M {
+                    self[(i, k)] = self[(i, k)] - dot * self[(i, j)];
+                }
+            }
+        }
+        (self, r)
+    }
+
+    /// Returns the Moore–Penrose pseudoinverse of this matrix.
+    ///
+    /// The pseudoinverse is computed using the singular value decomposition of
+    /// the matrix, singular values that are negligible relative to the largest
+    /// singular value are treated as zero. For a matrix with full column rank
+    /// this gives the least squares solution to an over-determined system.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{matrix, vector};
+    /// #
+    /// // Fit a line `y = a + b * x` to some points.
+    /// let m = matrix![
+    ///     1.0, 0.0;
+    ///     1.0, 1.0;
+    ///     1.0, 2.0;
+    /// ];
+    /// let y = vector![1.0, 3.0, 5.0];
+    /// let x = m.pseudo_inverse() * y;
+    /// assert!((x - vector![1.0, 2.0]).frobenius_norm() < 1e-12);
+    /// ```
+    pub fn pseudo_inverse(&self) -> Matrix<T, N, M>
+    where
+        T: Float,
+    {
+        let (w, sigma, v) = svd(*self);
+        let tol = rank_tolerance::<T, M, N>(&sigma);
+
+        // Since the columns of `w` are the left singular vectors scaled by the
+        // singular values the pseudoinverse is the sum of `v * wᵀ / σ²` for
+        // each non-negligible singular value σ.
+        let mut matrix = Matrix::zero();
+        for k in 0..N {
+            if sigma[k] <= tol {
+                continue;
+            }
+            let s = T::one() / (sigma[k] * sigma[k]);
+            for j in 0..M {
+                for i in 0..N {
+                    matrix[(i, j)] = matrix[(i, j)] + v[(i, k)] * w[(j, k)] * s;
+                }
+            }
+        }
+        matrix
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Matrix exponential
+////////////////////////////////////////////////////////////////////////////////
+
+/// The degree of the Padé approximant used for the matrix exponential.
+const EXP_PADE_DEGREE: usize = 6;
+
+/// The maximum number of times a matrix is halved before computing the
+/// exponential. This only matters for matrices with non-finite elements.
+const EXP_MAX_SCALING: usize = 1100;
+
+impl<T, const N: usize> Matrix<T, N, N> {
+    /// Returns the matrix exponential of this matrix.
+    ///
+    /// This is computed using the scaling and squaring method with a diagonal
+    /// Padé approximant. The matrix is scaled by a power of two so that its
+    /// norm is small, the Padé approximant of the scaled matrix is computed,
+    /// and then the result is squared repeatedly to undo the scaling.
+    ///
+    /// This is not the element-wise exponential, see
+    /// [`exp_elementwise()`][Matrix::exp_elementwise] for that.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{matrix, Matrix};
+    /// #
+    /// let a = matrix![
+    ///     0.0, 1.0;
+    ///     0.0, 0.0;
+    /// ];
+    /// let exp = matrix![
+    ///     1.0, 1.0;
+    ///     0.0, 1.0;
+    /// ];
+    /// assert!((a.exp() - exp).frobenius_norm() < 1e-12);
+    /// ```
+    #[must_use]
+    pub fn exp(self) -> Self
+    where
+        T: Float + Sum,
+    {
+        let one = T::one();
+        let half = one / (one + one);
+
+        // Scale the matrix so that its norm is at most a half.
+        let mut a = self;
+        let mut norm = a.linf_norm();
+        let mut s = 0;
+        while norm > half && s < EXP_MAX_SCALING {
+            norm = norm * half;
+            s += 1;
+        }
+        for _ in 0..s {
+            a = a * half;
+        }
+
+        // Evaluate the numerator and denominator of the Padé approximant.
+        let q = EXP_PADE_DEGREE;
+        let identity = Self::identity();
+        let mut c = half;
+        let mut x = a;
+        let mut numer = identity + a * c;
+        let mut denom = identity - a * c;
+        for k in 2..=q {
+            c = c * from_usize::<T>(q - k + 1) / from_usize::<T>(k * (2 * q - k + 1));
+            x = a * x;
+            let cx = x * c;
+            numer = numer + cx;
+            if k % 2 == 0 {
+                denom = denom + cx;
+            } else {
+                denom = denom - cx;
+            }
+        }
+        let mut e = solve(denom, numer).expect("Padé denominator should be invertible");
+
+        // Undo the scaling by repeated squaring.
+        for _ in 0..s {
+            e = e * e;
+        }
+        e
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Numerical differentiation
+////////////////////////////////////////////////////////////////////////////////
+
+impl<T: Float, const M: usize, const N: usize> Matrix<T, M, N> {
+    /// Returns the Jacobian of `f` at `x` computed using central differences.
+    ///
+    /// The `j`-th column is `(f(x + eps * eⱼ) - f(x - eps * eⱼ)) / (2 * eps)`
+    /// where `eⱼ` is the `j`-th standard basis vector, so `f` is evaluated
+    /// `2 * N` times. The error is proportional to `eps²` for smooth
+    /// functions, but a step that is too small loses precision to rounding,
+    /// for `f64` something around `1e-6` relative to the scale of `x` is
+    /// usually a good choice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{matrix, vector, Matrix, Vector};
+    /// #
+    /// let f = |v: Vector<f64, 2>| vector![v.x * v.y, v.x + 3.0 * v.y, v.y * v.y];
+    /// let j = Matrix::jacobian(f, &vector![2.0, 5.0], 1e-6);
+    /// let exp = matrix![
+    ///     5.0, 2.0;
+    ///     1.0, 3.0;
+    ///     0.0, 10.0;
+    /// ];
+    /// assert!((j - exp).frobenius_norm() < 1e-8);
+    /// ```
+    #[must_use]
+    pub fn jacobian<F>(mut f: F, x: &Vector<T, N>, eps: T) -> Self
+    where
+        F: FnMut(Vector<T, N>) -> Vector<T, M>,
+    {
+        let two_eps = eps + eps;
+        let mut matrix = Self::zero();
+        for j in 0..N {
+            let (mut forward, mut backward) = (*x, *x);
+            forward[j] = x[j] + eps;
+            backward[j] = x[j] - eps;
+            let column = (f(forward) - f(backward)) / two_eps;
+            matrix.data[j] = column.data[0];
+        }
+        matrix
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Nonlinear systems
+////////////////////////////////////////////////////////////////////////////////
+
+impl<T: Float, const N: usize> Vector<T, N> {
+    /// Solves the nonlinear system `f(x) = 0` using Newton's method.
+    ///
+    /// Starting at `x0`, each iteration solves `J(x) * dx = -f(x)` where `J`
+    /// is given by `jacobian`, and updates `x` by `dx`. The solution is
+    /// returned once the Euclidean norm of `f(x)` is at most `tol`. The
+    /// Jacobian can be computed numerically using
+    /// [`Matrix::jacobian()`].
+    ///
+    /// Returns `None` if the Jacobian is singular at any iterate or if the
+    /// method does not converge within `max_iter` iterations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{matrix, vector, Vector};
+    /// #
+    /// // the intersection of the unit circle and the line y = x
+    /// let f = |v: Vector<f64, 2>| vector![v.x * v.x + v.y * v.y - 1.0, v.y - v.x];
+    /// let jacobian = |v: Vector<f64, 2>| matrix![2.0 * v.x, 2.0 * v.y; -1.0, 1.0];
+    /// let x = Vector::solve_newton(f, jacobian, &vector![1.0, 0.0], 1e-12, 20).unwrap();
+    /// let s = 0.5f64.sqrt();
+    /// assert!((x - vector![s, s]).l2_norm() < 1e-12);
+    /// ```
+    pub fn solve_newton<F, J>(
+        mut f: F,
+        mut jacobian: J,
+        x0: &Self,
+        tol: T,
+        max_iter: usize,
+    ) -> Option<Self>
+    where
+        F: FnMut(Self) -> Self,
+        J: FnMut(Self) -> Matrix<T, N, N>,
+    {
+        let mut x = *x0;
+        let mut fx = f(x);
+        for _ in 0..max_iter {
+            if fx.l2_norm() <= tol {
+                return Some(x);
+            }
+            let dx = solve(jacobian(x), -fx)?;
+            x = x + dx;
+            fx = f(x);
+        }
+        if fx.l2_norm() <= tol {
+            Some(x)
+        } else {
+            None
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Least squares steps
+////////////////////////////////////////////////////////////////////////////////
+
+impl<T: Float + Sum, const N: usize> Vector<T, N> {
+    /// Returns the gradient descent update for the least squares problem
+    /// `min ½‖r(x)‖²`.
+    ///
+    /// Given the `residuals` `r` and their `jacobian` `J` at the current
+    /// parameters, the gradient of the objective is `Jᵀ * r`, so the update
+    /// is `-rate * Jᵀ * r`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{matrix, vector, Vector};
+    /// #
+    /// let r = vector![1.0, 2.0];
+    /// let j = matrix![1.0, 0.0; 0.0, 1.0];
+    /// assert_eq!(Vector::gradient_descent_step(&r, &j, 0.5), vector![-0.5, -1.0]);
+    /// ```
+    #[must_use]
+    pub fn gradient_descent_step<const M: usize>(
+        residuals: &Vector<T, M>,
+        jacobian: &Matrix<T, M, N>,
+        rate: T,
+    ) -> Self {
+        -(jacobian.transpose() * *residuals) * rate
+    }
+
+    /// Returns the Gauss–Newton update for the least squares problem
+    /// `min ½‖r(x)‖²`.
+    ///
+    /// Given the `residuals` `r` and their `jacobian` `J` at the current
+    /// parameters, this returns the `dx` minimizing `‖J * dx + r‖`. It is
+    /// computed from the QR decomposition of `J` rather than the normal
+    /// equations, so the conditioning of `J` is not squared.
+    ///
+    /// Returns `None` if `J` does not have full column rank, use
+    /// [`Vector::levenberg_marquardt_step()`] in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{matrix, vector, Vector};
+    /// #
+    /// // Fit a line `y = a + b * t` to some points, starting at `a = b = 0`.
+    /// let j = matrix![
+    ///     1.0, 0.0;
+    ///     1.0, 1.0;
+    ///     1.0, 2.0;
+    /// ];
+    /// let r = -vector![1.0, 3.0, 5.0];
+    /// let dx = Vector::gauss_newton_step(&r, &j).unwrap();
+    /// assert!((dx - vector![1.0, 2.0]).l2_norm() < 1e-12);
+    /// ```
+    #[must_use]
+    pub fn gauss_newton_step<const M: usize>(
+        residuals: &Vector<T, M>,
+        jacobian: &Matrix<T, M, N>,
+    ) -> Option<Self> {
+        let (q, r) = jacobian.orthonormalize_with_r();
+        solve(r, -(q.transpose() * *residuals))
+    }
+
+    /// Returns the damped Levenberg–Marquardt update for the least squares
+    /// problem `min ½‖r(x)‖²`.
+    ///
+    /// Given the `residuals` `r` and their `jacobian` `J` at the current
+    /// parameters, this solves `(Jᵀ * J + lambda * I) * dx = -Jᵀ * r`. A small
+    /// `lambda` gives a step close to the Gauss–Newton step and a large
+    /// `lambda` gives a short step in the direction of steepest descent.
+    ///
+    /// Returns `None` if the damped system is singular, which can only happen
+    /// if `lambda` is not positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{matrix, vector, Vector};
+    /// #
+    /// // The Jacobian is rank deficient, but the damped step is still defined.
+    /// let j = matrix![1.0, 1.0; 1.0, 1.0];
+    /// let r = vector![2.0, 2.0];
+    /// let dx = Vector::levenberg_marquardt_step(&r, &j, 1.0).unwrap();
+    /// assert!((dx - vector![-0.8, -0.8]).l2_norm() < 1e-12);
+    /// ```
+    #[must_use]
+    pub fn levenberg_marquardt_step<const M: usize>(
+        residuals: &Vector<T, M>,
+        jacobian: &Matrix<T, M, N>,
+        lambda: T,
+    ) -> Option<Self> {
+        let jt = jacobian.transpose();
+        let mut a = jt * *jacobian;
+        for i in 0..N {
+            a[(i, i)] = a[(i, i)] + lambda;
+        }
+        solve(a, -(jt * *residuals))
+    }
+}
+
+/// Returns the Euclidean norm of the `j`-th column of the matrix.
+fn column_norm<T, const M: usize, const N: usize>(matrix: &Matrix<T, M, N>, j: usize) -> T
+where
+    T: Float,
+{
+    matrix
+        .column(j)
+        .iter()
+        .fold(T::zero(), |acc, &d| acc + d * d)
+        .sqrt()
+}
+
+/// Converts a `usize` to a float by repeated addition.
+pub(crate) fn from_usize<T: Float>(n: usize) -> T {
+    (0..n).fold(T::zero(), |acc, _| acc + T::one())
+}
@@ -0,0 +1,192 @@
+//! Element-wise comparisons and boolean masks.
+
+use crate::{new, Matrix};
+
+////////////////////////////////////////////////////////////////////////////////
+// Comparisons
+////////////////////////////////////////////////////////////////////////////////
+
+/// Returns the mask of the predicate applied to each pair of elements.
+fn compare<T, F, const M: usize, const N: usize>(
+    a: &Matrix<T, M, N>,
+    b: &Matrix<T, M, N>,
+    mut f: F,
+) -> Matrix<bool, M, N>
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    let iter = a.iter().zip(b.iter()).map(|(a, b)| f(a, b));
+    // SAFETY: the iterator has the exact number of elements required.
+    unsafe { new::collect_unchecked(iter) }
+}
+
+impl<T: PartialOrd, const M: usize, const N: usize> Matrix<T, M, N> {
+    /// Returns a mask that is `true` where the element in this matrix is less
+    /// than the corresponding element in `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let a = matrix![1, 5; 3, 4];
+    /// let b = matrix![2, 5; 1, 6];
+    /// assert_eq!(a.lt_mask(&b), matrix![true, false; false, true]);
+    /// ```
+    #[must_use]
+    pub fn lt_mask(&self, other: &Self) -> Matrix<bool, M, N> {
+        compare(self, other, |a, b| a < b)
+    }
+
+    /// Returns a mask that is `true` where the element in this matrix is less
+    /// than or equal to the corresponding element in `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let a = matrix![1, 5; 3, 4];
+    /// let b = matrix![2, 5; 1, 6];
+    /// assert_eq!(a.le_mask(&b), matrix![true, true; false, true]);
+    /// ```
+    #[must_use]
+    pub fn le_mask(&self, other: &Self) -> Matrix<bool, M, N> {
+        compare(self, other, |a, b| a <= b)
+    }
+
+    /// Returns a mask that is `true` where the element in this matrix is
+    /// greater than the corresponding element in `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let a = matrix![1, 5; 3, 4];
+    /// let b = matrix![2, 5; 1, 6];
+    /// assert_eq!(a.gt_mask(&b), matrix![false, false; true, false]);
+    /// ```
+    #[must_use]
+    pub fn gt_mask(&self, other: &Self) -> Matrix<bool, M, N> {
+        compare(self, other, |a, b| a > b)
+    }
+
+    /// Returns a mask that is `true` where the element in this matrix is
+    /// greater than or equal to the corresponding element in `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let a = matrix![1, 5; 3, 4];
+    /// let b = matrix![2, 5; 1, 6];
+    /// assert_eq!(a.ge_mask(&b), matrix![false, true; true, false]);
+    /// ```
+    #[must_use]
+    pub fn ge_mask(&self, other: &Self) -> Matrix<bool, M, N> {
+        compare(self, other, |a, b| a >= b)
+    }
+}
+
+impl<T: PartialEq, const M: usize, const N: usize> Matrix<T, M, N> {
+    /// Returns a mask that is `true` where the element in this matrix is
+    /// equal to the corresponding element in `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let a = matrix![1, 5; 3, 4];
+    /// let b = matrix![2, 5; 1, 4];
+    /// assert_eq!(a.eq_mask(&b), matrix![false, true; false, true]);
+    /// ```
+    #[must_use]
+    pub fn eq_mask(&self, other: &Self) -> Matrix<bool, M, N> {
+        compare(self, other, |a, b| a == b)
+    }
+}
+
+impl<T: Copy, const M: usize, const N: usize> Matrix<T, M, N> {
+    /// Returns a matrix with the element from `a` where the mask is `true` and
+    /// the element from `b` where it is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{matrix, Matrix};
+    /// #
+    /// let x = matrix![-1.0, 2.0; 3.0, -4.0];
+    /// let zero = Matrix::zero();
+    /// let relu = Matrix::select(&x.gt_mask(&zero), &x, &zero);
+    /// assert_eq!(relu, matrix![0.0, 2.0; 3.0, 0.0]);
+    /// ```
+    #[must_use]
+    pub fn select(mask: &Matrix<bool, M, N>, a: &Self, b: &Self) -> Self {
+        let iter = mask
+            .iter()
+            .zip(a.iter().zip(b.iter()))
+            .map(|(&m, (&a, &b))| if m { a } else { b });
+        // SAFETY: the iterator has the exact number of elements required.
+        unsafe { new::collect_unchecked(iter) }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Matrix<bool, M, N> methods
+////////////////////////////////////////////////////////////////////////////////
+
+impl<const M: usize, const N: usize> Matrix<bool, M, N> {
+    /// Returns `true` if any element of the mask is `true`.
+    ///
+    /// An empty mask returns `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// assert!(matrix![false, true; false, false].any());
+    /// assert!(!matrix![false, false].any());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn any(&self) -> bool {
+        self.iter().any(|&b| b)
+    }
+
+    /// Returns `true` if every element of the mask is `true`.
+    ///
+    /// An empty mask returns `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// assert!(matrix![true, true; true, true].all());
+    /// assert!(!matrix![true, false].all());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn all(&self) -> bool {
+        self.iter().all(|&b| b)
+    }
+
+    /// Returns the number of elements of the mask that are `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// assert_eq!(matrix![true, false; true, true].count_true(), 3);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn count_true(&self) -> usize {
+        self.iter().filter(|&&b| b).count()
+    }
+}
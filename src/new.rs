@@ -5,7 +5,10 @@ use core::mem;
 use core::mem::MaybeUninit;
 use core::ptr;
 
-use crate::Matrix;
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+use crate::{CollectError, Error, Matrix, RowVector, Vector};
 
 /// A macro for composing matrices.
 ///
@@ -37,14 +40,143 @@ use crate::Matrix;
 /// ```text
 /// Matrix { data: [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]] }
 /// ```
+///
+/// Rows can be conditionally included using `#[cfg(..)]` attributes, and
+/// trailing commas and semicolons are allowed.
+///
+/// ```rust
+/// # use vectrix::matrix;
+/// #
+/// let m = matrix![
+///     1, 2,;
+///     #[cfg(any())]
+///     3, 4,;
+/// ];
+/// assert_eq!(m, matrix![1, 2]);
+/// ```
+///
+/// An existing array of arrays in row-major order, where each inner array is a
+/// row, can be converted using the `@rows` form. This accepts any expression
+/// including nested array literals and constants, and like
+/// [`Matrix::from_column_major_order()`] it can be used in `const` contexts as
+/// long as the elements are `Copy`.
+///
+/// ```rust
+/// # use vectrix::{matrix, Matrix};
+/// #
+/// const DATA: [[i64; 2]; 3] = [[1, 4], [2, 5], [3, 6]];
+/// const M: Matrix<i64, 3, 2> = matrix![@rows DATA];
+/// assert_eq!(M, matrix![1, 4; 2, 5; 3, 6]);
+/// assert_eq!(matrix![@rows [[1, 2], [3, 4]]], matrix![1, 2; 3, 4]);
+/// ```
+///
+/// Without `@rows` array literals are just elements, so `matrix![[1, 2], [3,
+/// 4]]` is a row vector of arrays.
+///
+/// A matrix filled with a single element can be created by giving the element
+/// followed by the number of rows and columns separated by a comma, i.e.
+/// `matrix![elem; M, N]`. The dimensions are not separated by a semicolon
+/// because `matrix![elem; M; N]` already denotes a column vector with three
+/// elements. Like an array repeat expression the element must be `Copy` or a
+/// constant, and the dimensions can be any constant expression.
+///
+/// ```rust
+/// # use vectrix::{matrix, Matrix};
+/// #
+/// const ROWS: usize = 2;
+/// let m = matrix![7; ROWS, 3];
+/// assert_eq!(m, Matrix::<i64, 2, 3>::repeat(7));
+/// ```
 #[cfg(feature = "macro")]
 #[macro_export]
 macro_rules! matrix {
+    (@rows $data:expr) => {
+        $crate::Matrix::__from_rows($data)
+    };
+    ($repeat:expr; $m:expr, $n:expr) => {
+        $crate::Matrix::from_column_major_order([[$repeat; $m]; $n])
+    };
     ($($data:tt)*) => {
         $crate::Matrix::from_column_major_order($crate::proc_macro::matrix!($($data)*))
     };
 }
 
+////////////////////////////////////////////////////////////////////////////////
+// Row-major order
+////////////////////////////////////////////////////////////////////////////////
+
+impl<T, const M: usize, const N: usize> Matrix<T, M, N> {
+    /// Create a new matrix from an array of arrays in row-major order.
+    ///
+    /// Each inner array is a row of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{matrix, Matrix};
+    /// #
+    /// let m = Matrix::from_row_major_order([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(m, matrix![1, 2, 3; 4, 5, 6]);
+    /// ```
+    #[must_use]
+    pub fn from_row_major_order(data: [[T; N]; M]) -> Self {
+        let mut rows = data.map(IntoIterator::into_iter);
+        // SAFETY: each row iterator yields exactly `N` elements and is advanced
+        // once per column, so the iterator yields exactly `M * N` elements.
+        unsafe { collect_unchecked((0..(M * N)).filter_map(|k| rows[k % M].next())) }
+    }
+
+    /// Converts this matrix into an array of arrays in row-major order.
+    ///
+    /// Each inner array is a row of the matrix. This is the inverse of
+    /// [`from_row_major_order()`][Matrix::from_row_major_order].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let m = matrix![1, 2, 3; 4, 5, 6];
+    /// assert_eq!(m.into_row_major_order(), [[1, 2, 3], [4, 5, 6]]);
+    /// ```
+    pub fn into_row_major_order(self) -> [[T; N]; M] {
+        let mut columns = self.data.map(IntoIterator::into_iter);
+        // SAFETY: each column iterator yields exactly `M` elements and is
+        // advanced once per row, so the iterator yields exactly `M * N`
+        // elements.
+        let matrix: Matrix<T, N, M> =
+            unsafe { collect_unchecked((0..(M * N)).filter_map(|k| columns[k % N].next())) };
+        matrix.data
+    }
+}
+
+impl<T: Copy, const M: usize, const N: usize> Matrix<T, M, N> {
+    /// Create a new matrix from an array of arrays in row-major order in a
+    /// `const` context.
+    ///
+    /// This is used by the `matrix![@rows ..]` form, it is the same as
+    /// [`from_row_major_order()`][Matrix::from_row_major_order] except that
+    /// the elements must be `Copy`.
+    #[doc(hidden)]
+    #[must_use]
+    pub const fn __from_rows(data: [[T; N]; M]) -> Self {
+        let mut columns = [[MaybeUninit::<T>::uninit(); M]; N];
+        let mut i = 0;
+        while i < M {
+            let mut j = 0;
+            while j < N {
+                columns[j][i] = MaybeUninit::new(data[i][j]);
+                j += 1;
+            }
+            i += 1;
+        }
+        // SAFETY: every element has been initialized above, and
+        // `MaybeUninit<T>` has the same layout as `T`.
+        let data = unsafe { ptr::read(ptr::addr_of!(columns).cast::<[[T; M]; N]>()) };
+        Self { data }
+    }
+}
+
 impl<T: Default, const M: usize, const N: usize> Default for Matrix<T, M, N> {
     /// Create a new matrix using `T::default()` as an initializer.
     ///
@@ -183,6 +315,43 @@ where
     }
 }
 
+impl<T, const M: usize, const N: usize> Matrix<T, M, N> {
+    /// Create a new matrix from an iterator, returning an error if the
+    /// iterator doesn't yield enough elements.
+    ///
+    /// Elements will be filled in column-major order. Any elements beyond the
+    /// first `M * N` are not consumed. This is the non-panicking version of
+    /// [`FromIterator::from_iter()`], if an error is returned all the elements
+    /// already yielded by the iterator are dropped.
+    ///
+    /// # Errors
+    ///
+    /// If the iterator yields fewer than `M * N` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{matrix, Matrix};
+    /// #
+    /// let m = Matrix::<i64, 2, 2>::try_from_iter(1..);
+    /// assert_eq!(m, Ok(matrix![1, 3; 2, 4]));
+    ///
+    /// let err = Matrix::<i64, 2, 2>::try_from_iter(1..4).unwrap_err();
+    /// assert_eq!(err.yielded(), 3);
+    /// assert_eq!(err.expected(), 4);
+    /// ```
+    #[inline]
+    pub fn try_from_iter<I>(iter: I) -> Result<Self, CollectError>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        collect(iter.into_iter()).map_err(|len| CollectError {
+            len,
+            expected: M * N,
+        })
+    }
+}
+
 impl<T, const M: usize, const N: usize> FromIterator<T> for Matrix<T, M, N> {
     /// Create a new matrix from an iterator.
     ///
@@ -200,6 +369,217 @@ impl<T, const M: usize, const N: usize> FromIterator<T> for Matrix<T, M, N> {
     }
 }
 
+impl<T, const M: usize, const N: usize> Matrix<T, M, N> {
+    /// Create a new matrix from an iterator of rows.
+    ///
+    /// # Panics
+    ///
+    /// If the iterator doesn't yield enough rows to fill the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{matrix, row_vector, Matrix};
+    /// #
+    /// let m = Matrix::<_, 2, 3>::from_rows((1..=2).map(|i| row_vector![i, 2 * i, 3 * i]));
+    /// assert_eq!(m, matrix![1, 2, 3; 2, 4, 6]);
+    /// ```
+    pub fn from_rows<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = RowVector<T, N>>,
+    {
+        let [rows]: [[RowVector<T, N>; M]; 1] = match collect(iter.into_iter()) {
+            Ok(rows) => rows.data,
+            Err(len) => collect_vectors_panic::<M, N>("rows", len),
+        };
+        Self::from_row_major_order(rows.map(|row| row.data.map(|[x]| x)))
+    }
+
+    /// Create a new matrix from an iterator of columns.
+    ///
+    /// # Panics
+    ///
+    /// If the iterator doesn't yield enough columns to fill the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{matrix, vector, Matrix};
+    /// #
+    /// let m = Matrix::<_, 3, 2>::from_columns((1..=2).map(|i| vector![i, 2 * i, 3 * i]));
+    /// assert_eq!(m, matrix![1, 2; 2, 4; 3, 6]);
+    /// ```
+    pub fn from_columns<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = Vector<T, M>>,
+    {
+        let [columns]: [[Vector<T, M>; N]; 1] = match collect(iter.into_iter()) {
+            Ok(columns) => columns.data,
+            Err(len) => collect_vectors_panic::<M, N>("columns", len),
+        };
+        Self {
+            data: columns.map(|column| {
+                let [column] = column.data;
+                column
+            }),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Slices
+////////////////////////////////////////////////////////////////////////////////
+
+impl<T: Copy, const M: usize, const N: usize> Matrix<T, M, N> {
+    /// Create a new matrix from a slice in column-major order.
+    ///
+    /// # Errors
+    ///
+    /// If the length of the slice is not equal to `M * N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{matrix, Error, Matrix};
+    /// #
+    /// let m = Matrix::<_, 2, 3>::from_column_slice(&[1, 4, 2, 5, 3, 6]);
+    /// assert_eq!(m, Ok(matrix![1, 2, 3; 4, 5, 6]));
+    ///
+    /// let m = Matrix::<_, 2, 3>::from_column_slice(&[1, 4, 2, 5]);
+    /// assert_eq!(m, Err(Error::LengthMismatch));
+    /// ```
+    pub fn from_column_slice(slice: &[T]) -> Result<Self, Error> {
+        if slice.len() != M * N {
+            return Err(Error::LengthMismatch);
+        }
+        // SAFETY: the slice has exactly `M * N` elements.
+        Ok(unsafe { collect_unchecked(slice.iter().copied()) })
+    }
+
+    /// Create a new matrix from a slice in row-major order.
+    ///
+    /// # Errors
+    ///
+    /// If the length of the slice is not equal to `M * N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{matrix, Matrix};
+    /// #
+    /// let m = Matrix::<_, 2, 3>::from_row_slice(&[1, 2, 3, 4, 5, 6]);
+    /// assert_eq!(m, Ok(matrix![1, 2, 3; 4, 5, 6]));
+    /// ```
+    pub fn from_row_slice(slice: &[T]) -> Result<Self, Error> {
+        if slice.len() != M * N {
+            return Err(Error::LengthMismatch);
+        }
+        let iter = (0..N).flat_map(|j| (0..M).map(move |i| slice[i * N + j]));
+        // SAFETY: the iterator yields exactly `M * N` elements.
+        Ok(unsafe { collect_unchecked(iter) })
+    }
+}
+
+impl<T: Copy, const M: usize, const N: usize> TryFrom<&[T]> for Matrix<T, M, N> {
+    type Error = Error;
+
+    /// Create a new matrix from a slice in column-major order.
+    ///
+    /// See [`Matrix::from_column_slice()`].
+    #[inline]
+    fn try_from(slice: &[T]) -> Result<Self, Error> {
+        Self::from_column_slice(slice)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Vec
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(feature = "std")]
+impl<T: Clone, const M: usize, const N: usize> Matrix<T, M, N> {
+    /// Returns a vector of the elements in column-major order.
+    ///
+    /// *This method is only available when the `std` feature is enabled.*
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let m = matrix![1, 2, 3; 4, 5, 6];
+    /// assert_eq!(m.to_vec(), vec![1, 4, 2, 5, 3, 6]);
+    /// ```
+    #[must_use]
+    pub fn to_vec(&self) -> Vec<T> {
+        self.as_slice().to_vec()
+    }
+
+    /// Returns a vector of the elements in row-major order.
+    ///
+    /// *This method is only available when the `std` feature is enabled.*
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let m = matrix![1, 2, 3; 4, 5, 6];
+    /// assert_eq!(m.to_row_major_vec(), vec![1, 2, 3, 4, 5, 6]);
+    /// ```
+    #[must_use]
+    pub fn to_row_major_vec(&self) -> Vec<T> {
+        self.iter_row_major().cloned().collect()
+    }
+
+    /// Returns a vector of rows, each row is a vector of elements.
+    ///
+    /// *This method is only available when the `std` feature is enabled.*
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let m = matrix![1, 2, 3; 4, 5, 6];
+    /// assert_eq!(m.to_nested_vec(), vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    /// ```
+    #[must_use]
+    pub fn to_nested_vec(&self) -> Vec<Vec<T>> {
+        self.iter_rows()
+            .map(|row| row.iter().cloned().collect())
+            .collect()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, const M: usize, const N: usize> From<Matrix<T, M, N>> for Vec<T> {
+    /// Converts the matrix into a vector of elements in column-major order.
+    fn from(matrix: Matrix<T, M, N>) -> Self {
+        matrix.into_iter().collect()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, const M: usize, const N: usize> From<Matrix<T, M, N>> for Vec<Vec<T>> {
+    /// Converts the matrix into a vector of rows.
+    fn from(matrix: Matrix<T, M, N>) -> Self {
+        matrix
+            .into_row_major_order()
+            .into_iter()
+            .map(|row| row.into_iter().collect())
+            .collect()
+    }
+}
+
+#[cold]
+fn collect_vectors_panic<const M: usize, const N: usize>(what: &str, len: usize) -> ! {
+    panic!(
+        "collect iterator of {} {} into `Matrix<_, {}, {}>`",
+        len, what, M, N
+    );
+}
+
 #[cold]
 fn collect_panic<const M: usize, const N: usize>(len: usize) -> ! {
     if N == 1 {
@@ -4,7 +4,7 @@
 use core::iter::Sum;
 use core::ops::*;
 
-use crate::{Matrix, MatrixIndex, Zero};
+use crate::{Checked, Matrix, MatrixIndex, Widen, Zero};
 
 ////////////////////////////////////////////////////////////////////////////////
 // Indexing
@@ -239,7 +239,7 @@ macro_rules! impl_op_mul {
     ($lhs:ty, $rhs:ty) => {
         impl<T, const N: usize, const M: usize, const P: usize> Mul<$rhs> for $lhs
         where
-            T: Copy + Zero + Mul<Output = T> + Sum,
+            T: Copy + Zero + Add<Output = T> + Mul<Output = T> + Sum,
         {
             type Output = Matrix<T, M, P>;
 
@@ -261,6 +261,79 @@ impl_op_mul! {  Matrix<T, M, N>, &Matrix<T, N, P> }
 impl_op_mul! { &Matrix<T, M, N>,  Matrix<T, N, P> }
 impl_op_mul! { &Matrix<T, M, N>, &Matrix<T, N, P> }
 
+impl<T, const M: usize, const N: usize> Matrix<T, M, N> {
+    /// Returns the matrix product or `None` if overflow occurred.
+    ///
+    /// The `*` operator uses the element type's own arithmetic, so for integer
+    /// matrices overflow panics when overflow checks are enabled, like in
+    /// debug builds, and silently wraps otherwise. This method always detects
+    /// overflow, which makes it possible to validate products in release
+    /// builds without affecting the performance of the operator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// let a = matrix![1, 2; 3, 4];
+    /// assert_eq!(a.checked_mul(&a), Some(matrix![7, 10; 15, 22]));
+    ///
+    /// let b = matrix![i32::MAX, 0; 0, 1];
+    /// assert_eq!(b.checked_mul(&b), None);
+    /// ```
+    #[must_use]
+    pub fn checked_mul<const P: usize>(&self, rhs: &Matrix<T, N, P>) -> Option<Matrix<T, M, P>>
+    where
+        T: Copy + Zero + Checked,
+    {
+        let mut matrix = Matrix::zero();
+        for i in 0..M {
+            for j in 0..P {
+                matrix[(i, j)] = self.row(i).checked_dot(rhs.column(j))?;
+            }
+        }
+        Some(matrix)
+    }
+
+    /// Returns the matrix product computed in a wider element type.
+    ///
+    /// Each element is converted using [`Widen`] before multiplying, so the
+    /// products and their sums are accumulated in the wider type. For example
+    /// the product of two `i32` matrices is an `i64` matrix.
+    ///
+    /// A single product of two elements never overflows the wider type, but
+    /// the sum of several products still can. For example the dot product of
+    /// `[i32::MIN, i32::MIN]` with itself is `2⁶³` which does not fit in an
+    /// `i64`. Overflow is handled the same way as the wider type's `+`
+    /// operator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{matrix, Matrix};
+    /// #
+    /// let a = matrix![i32::MAX, i32::MAX];
+    /// let b = matrix![2; 2];
+    /// let c: Matrix<i64, 1, 1> = a.widening_mul(&b);
+    /// assert_eq!(c, matrix![4 * i32::MAX as i64]);
+    /// ```
+    #[must_use]
+    pub fn widening_mul<const P: usize>(&self, rhs: &Matrix<T, N, P>) -> Matrix<T::Output, M, P>
+    where
+        T: Copy + Widen,
+        T::Output: Copy + Zero + Add<Output = T::Output> + Mul<Output = T::Output> + Sum,
+    {
+        let mut matrix = Matrix::zero();
+        for i in 0..M {
+            for j in 0..P {
+                matrix[(i, j)] =
+                    crate::sum_products((0..N).map(|k| self[(i, k)].widen() * rhs[(k, j)].widen()));
+            }
+        }
+        matrix
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Matrix += Matrix
 ////////////////////////////////////////////////////////////////////////////////
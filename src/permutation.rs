@@ -0,0 +1,201 @@
+//! A compact representation of permutation matrices.
+
+use crate::{Error, Matrix, One, Zero};
+
+/// Represents a permutation of `N` elements.
+///
+/// This is a compact representation of an `N × N` permutation matrix. It
+/// stores, for each position `i`, the index of the element that is moved to
+/// position `i`. Applying the permutation to the rows of a matrix is
+/// equivalent to multiplying the matrix on the left by the corresponding
+/// permutation matrix.
+///
+/// # Examples
+///
+/// ```
+/// # use vectrix::{matrix, Matrix, Permutation};
+/// #
+/// let p = Permutation::new([2, 0, 1]).unwrap();
+/// let m = matrix![
+///     1, 2;
+///     3, 4;
+///     5, 6;
+/// ];
+/// assert_eq!(m.permute_rows(&p), matrix![5, 6; 1, 2; 3, 4]);
+/// assert_eq!(Matrix::from(p) * m, m.permute_rows(&p));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Permutation<const N: usize> {
+    map: [usize; N],
+}
+
+impl<const N: usize> Default for Permutation<N> {
+    #[inline]
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl<const N: usize> Permutation<N> {
+    /// Returns the identity permutation, which leaves every element in place.
+    #[must_use]
+    #[inline]
+    pub fn identity() -> Self {
+        let mut map = [0; N];
+        for (i, d) in map.iter_mut().enumerate() {
+            *d = i;
+        }
+        Self { map }
+    }
+
+    /// Create a new permutation where `map[i]` is the index of the element
+    /// that is moved to position `i`.
+    ///
+    /// # Errors
+    ///
+    /// If any index is out of bounds or if any index appears more than once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{Error, Permutation};
+    /// #
+    /// assert!(Permutation::new([1, 0, 2]).is_ok());
+    /// assert_eq!(Permutation::new([1, 3, 2]), Err(Error::IndexOutOfBounds));
+    /// assert_eq!(Permutation::new([1, 1, 2]), Err(Error::OverlappingIndices));
+    /// ```
+    pub fn new(map: [usize; N]) -> Result<Self, Error> {
+        let mut seen = [false; N];
+        for &i in &map {
+            match seen.get_mut(i) {
+                None => return Err(Error::IndexOutOfBounds),
+                Some(true) => return Err(Error::OverlappingIndices),
+                Some(s) => *s = true,
+            }
+        }
+        Ok(Self { map })
+    }
+
+    /// Returns the underlying array where element `i` is the index of the
+    /// element that is moved to position `i`.
+    #[inline]
+    pub fn as_array(&self) -> &[usize; N] {
+        &self.map
+    }
+
+    /// Swaps the elements at positions `a` and `b`.
+    ///
+    /// This is the elementary operation used when pivoting during a
+    /// decomposition.
+    ///
+    /// # Panics
+    ///
+    /// If `a` or `b` is out of bounds.
+    #[inline]
+    #[track_caller]
+    pub fn swap(&mut self, a: usize, b: usize) {
+        self.map.swap(a, b);
+    }
+
+    /// Returns the inverse of this permutation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{matrix, Permutation};
+    /// #
+    /// let p = Permutation::new([2, 0, 1]).unwrap();
+    /// let m = matrix![1; 2; 3];
+    /// assert_eq!(m.permute_rows(&p).permute_rows(&p.inverse()), m);
+    /// ```
+    #[must_use]
+    pub fn inverse(&self) -> Self {
+        let mut map = [0; N];
+        for (i, &d) in self.map.iter().enumerate() {
+            map[d] = i;
+        }
+        Self { map }
+    }
+
+    /// Returns `true` if this permutation can be composed from an even number
+    /// of swaps.
+    ///
+    /// The determinant of the corresponding permutation matrix is `1` if the
+    /// permutation is even and `-1` otherwise.
+    pub fn is_even(&self) -> bool {
+        let mut visited = [false; N];
+        let mut cycles = 0;
+        for i in 0..N {
+            if visited[i] {
+                continue;
+            }
+            cycles += 1;
+            let mut j = i;
+            while !visited[j] {
+                visited[j] = true;
+                j = self.map[j];
+            }
+        }
+        (N - cycles) & 1 == 0
+    }
+}
+
+impl<T, const N: usize> From<Permutation<N>> for Matrix<T, N, N>
+where
+    T: Copy + One + Zero,
+{
+    /// Converts the permutation into the corresponding permutation matrix.
+    fn from(p: Permutation<N>) -> Self {
+        let mut matrix = Self::zero();
+        for (i, &j) in p.map.iter().enumerate() {
+            matrix[(i, j)] = T::one();
+        }
+        matrix
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Matrix<T, M, N> methods
+////////////////////////////////////////////////////////////////////////////////
+
+impl<T: Copy, const M: usize, const N: usize> Matrix<T, M, N> {
+    /// Returns a matrix with the rows permuted by `p`.
+    ///
+    /// Row `i` of the result is row `p.as_array()[i]` of this matrix. This is
+    /// equivalent to multiplying this matrix on the left by the permutation
+    /// matrix.
+    #[must_use]
+    pub fn permute_rows(&self, p: &Permutation<M>) -> Self {
+        let mut matrix = *self;
+        for (i, &k) in p.map.iter().enumerate() {
+            for j in 0..N {
+                matrix[(i, j)] = self[(k, j)];
+            }
+        }
+        matrix
+    }
+
+    /// Returns a matrix with the columns permuted by `p`.
+    ///
+    /// Column `j` of the result is column `p.as_array()[j]` of this matrix.
+    /// This is equivalent to multiplying this matrix on the right by the
+    /// permutation matrix of the inverse permutation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{matrix, Permutation};
+    /// #
+    /// let p = Permutation::new([1, 2, 0]).unwrap();
+    /// let m = matrix![1, 2, 3; 4, 5, 6];
+    /// assert_eq!(m.permute_columns(&p), matrix![2, 3, 1; 5, 6, 4]);
+    /// ```
+    #[must_use]
+    pub fn permute_columns(&self, p: &Permutation<N>) -> Self {
+        let mut matrix = *self;
+        for (j, &k) in p.map.iter().enumerate() {
+            matrix.data[j] = self.data[k];
+        }
+        matrix
+    }
+}
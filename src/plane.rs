@@ -0,0 +1,83 @@
+//! Planes in three dimensional space.
+
+use core::iter::Sum;
+
+use crate::{Dot, Float, Vector};
+
+/// A plane in three dimensional space.
+///
+/// The plane is represented by the equation `normal · p + d = 0`, so the
+/// signed distance of a point `p` from the plane is `normal · p + d` when the
+/// normal has unit length. Points on the side the normal points towards have
+/// a positive distance.
+///
+/// # Examples
+///
+/// ```
+/// # use vectrix::{vector, Plane};
+/// #
+/// let plane = Plane::new(vector![0.0, 2.0, 0.0], -4.0).normalize();
+/// assert_eq!(plane.normal(), &vector![0.0, 1.0, 0.0]);
+/// assert_eq!(plane.d(), -2.0);
+/// assert_eq!(plane.signed_distance(&vector![1.0, 5.0, 1.0]), 3.0);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Plane<T> {
+    normal: Vector<T, 3>,
+    d: T,
+}
+
+impl<T> Plane<T> {
+    /// Create a new plane from the normal and the constant `d`.
+    #[inline]
+    pub const fn new(normal: Vector<T, 3>, d: T) -> Self {
+        Self { normal, d }
+    }
+
+    /// Returns a reference to the normal of this plane.
+    #[inline]
+    pub const fn normal(&self) -> &Vector<T, 3> {
+        &self.normal
+    }
+
+    /// Returns the constant `d` of this plane.
+    #[inline]
+    pub fn d(&self) -> T
+    where
+        T: Copy,
+    {
+        self.d
+    }
+
+    /// Create a new plane from the coefficients `[a, b, c, d]` of the plane
+    /// equation `ax + by + cz + d = 0`.
+    #[inline]
+    pub fn from_coefficients(coefficients: Vector<T, 4>) -> Self
+    where
+        T: Copy,
+    {
+        let [[a, b, c, d]] = coefficients.data;
+        Self::new(Vector::from_column_major_order([[a, b, c]]), d)
+    }
+}
+
+impl<T: Float + Sum> Plane<T> {
+    /// Returns the plane with the normal scaled to unit length.
+    ///
+    /// The plane itself is unchanged, only its representation. If the normal
+    /// has zero length the result contains non-finite values.
+    #[must_use]
+    pub fn normalize(&self) -> Self {
+        let norm = self.normal.l2_norm();
+        Self::new(self.normal / norm, self.d / norm)
+    }
+
+    /// Returns the signed distance of the point from this plane.
+    ///
+    /// This is only a true distance if the normal has unit length, see
+    /// [`normalize()`][Plane::normalize].
+    #[must_use]
+    pub fn signed_distance(&self, point: &Vector<T, 3>) -> T {
+        self.normal.dot(point) + self.d
+    }
+}
@@ -0,0 +1,181 @@
+//! Points in affine space.
+
+use core::ops::{Add, AddAssign, Deref, DerefMut, Sub, SubAssign};
+
+use crate::{Matrix, Vector, Zero};
+
+/// A point in `N` dimensional space.
+///
+/// A point is a location whereas a [`Vector`] is a displacement. The usual
+/// affine operations are supported:
+///
+/// - `point - point = vector`
+/// - `point + vector = point`
+/// - `point - vector = point`
+///
+/// Adding two points together is not supported. The coordinates can be
+/// accessed using the same `x`, `y`, `z`, and `w` fields as a vector.
+///
+/// # Examples
+///
+/// ```
+/// # use vectrix::{vector, Point2};
+/// #
+/// let a = Point2::from([1, 2]);
+/// let b = Point2::from([4, 6]);
+/// let v = b - a;
+/// assert_eq!(v, vector![3, 4]);
+/// assert_eq!(a + v, b);
+/// assert_eq!(b.x, 4);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct Point<T, const N: usize> {
+    coords: Vector<T, N>,
+}
+
+/// A point in two dimensional space.
+pub type Point2<T> = Point<T, 2>;
+
+/// A point in three dimensional space.
+pub type Point3<T> = Point<T, 3>;
+
+impl<T, const N: usize> Point<T, N> {
+    /// Create a new point from the coordinate vector.
+    #[inline]
+    pub const fn new(coords: Vector<T, N>) -> Self {
+        Self { coords }
+    }
+
+    /// Returns the origin, the point with all coordinates zero.
+    #[must_use]
+    #[inline]
+    pub fn origin() -> Self
+    where
+        T: Copy + Zero,
+    {
+        Self::new(Vector::zero())
+    }
+
+    /// Returns a reference to the coordinate vector of this point.
+    ///
+    /// This is the displacement of the point from the origin.
+    #[inline]
+    pub const fn coords(&self) -> &Vector<T, N> {
+        &self.coords
+    }
+
+    /// Returns the coordinate vector of this point.
+    #[inline]
+    pub fn into_vector(self) -> Vector<T, N> {
+        self.coords
+    }
+}
+
+impl<T, const N: usize> Deref for Point<T, N>
+where
+    Vector<T, N>: Deref,
+{
+    type Target = <Vector<T, N> as Deref>::Target;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.coords
+    }
+}
+
+impl<T, const N: usize> DerefMut for Point<T, N>
+where
+    Vector<T, N>: DerefMut,
+{
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.coords
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Conversions
+////////////////////////////////////////////////////////////////////////////////
+
+impl<T, const N: usize> From<Vector<T, N>> for Point<T, N> {
+    #[inline]
+    fn from(coords: Vector<T, N>) -> Self {
+        Self::new(coords)
+    }
+}
+
+impl<T, const N: usize> From<Point<T, N>> for Vector<T, N> {
+    #[inline]
+    fn from(point: Point<T, N>) -> Self {
+        point.coords
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for Point<T, N> {
+    #[inline]
+    fn from(arr: [T; N]) -> Self {
+        Self::new(Matrix::from_column_major_order([arr]))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Operations
+////////////////////////////////////////////////////////////////////////////////
+
+impl<T, const N: usize> Sub for Point<T, N>
+where
+    T: Copy + Sub<Output = T>,
+{
+    type Output = Vector<T, N>;
+
+    /// Returns the displacement from `other` to `self`.
+    #[inline]
+    fn sub(self, other: Self) -> Vector<T, N> {
+        self.coords - other.coords
+    }
+}
+
+impl<T, const N: usize> Add<Vector<T, N>> for Point<T, N>
+where
+    T: Copy + Add<Output = T>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn add(self, other: Vector<T, N>) -> Self {
+        Self::new(self.coords + other)
+    }
+}
+
+impl<T, const N: usize> Sub<Vector<T, N>> for Point<T, N>
+where
+    T: Copy + Sub<Output = T>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, other: Vector<T, N>) -> Self {
+        Self::new(self.coords - other)
+    }
+}
+
+impl<T, const N: usize> AddAssign<Vector<T, N>> for Point<T, N>
+where
+    T: Copy + Add<Output = T>,
+{
+    #[inline]
+    fn add_assign(&mut self, other: Vector<T, N>) {
+        self.coords = self.coords + other;
+    }
+}
+
+impl<T, const N: usize> SubAssign<Vector<T, N>> for Point<T, N>
+where
+    T: Copy + Sub<Output = T>,
+{
+    #[inline]
+    fn sub_assign(&mut self, other: Vector<T, N>) {
+        self.coords = self.coords - other;
+    }
+}
@@ -0,0 +1,253 @@
+//! Predicates that query the structure of a matrix.
+
+use core::fmt;
+use core::ops::Sub;
+
+use crate::{Float, Matrix, One, Zero};
+
+/// Returns `true` if every element in the matrix satisfies the predicate.
+///
+/// The predicate is given the row and column index along with the element.
+fn all<T, F, const M: usize, const N: usize>(matrix: &Matrix<T, M, N>, mut f: F) -> bool
+where
+    F: FnMut(usize, usize, &T) -> bool,
+{
+    (0..N).all(|j| (0..M).all(|i| f(i, j, &matrix[(i, j)])))
+}
+
+/// Returns `true` if the absolute difference between `a` and `b` is at most
+/// `epsilon`.
+#[inline]
+fn approx_eq<T>(a: T, b: T, epsilon: T) -> bool
+where
+    T: Copy + PartialOrd + Sub<Output = T>,
+{
+    let diff = if a > b { a - b } else { b - a };
+    diff <= epsilon
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Matrix<T, M, N> predicates
+////////////////////////////////////////////////////////////////////////////////
+
+impl<T, const M: usize, const N: usize> Matrix<T, M, N> {
+    /// Returns `true` if all the elements off the main diagonal are zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// assert!(matrix![1, 0; 0, 2].is_diagonal());
+    /// assert!(!matrix![1, 3; 0, 2].is_diagonal());
+    /// ```
+    pub fn is_diagonal(&self) -> bool
+    where
+        T: PartialEq + Zero,
+    {
+        all(self, |i, j, d| i == j || *d == T::zero())
+    }
+
+    /// Returns `true` if all the elements off the main diagonal are within
+    /// `epsilon` of zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// assert!(matrix![1.0, 1e-9; 0.0, 2.0].is_diagonal_eps(1e-6));
+    /// ```
+    pub fn is_diagonal_eps(&self, epsilon: T) -> bool
+    where
+        T: Copy + PartialOrd + Zero + Sub<Output = T>,
+    {
+        all(self, |i, j, &d| i == j || approx_eq(d, T::zero(), epsilon))
+    }
+
+    /// Returns `true` if all the elements below the main diagonal are zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// assert!(matrix![1, 2; 0, 3].is_upper_triangular());
+    /// assert!(!matrix![1, 2; 4, 3].is_upper_triangular());
+    /// ```
+    pub fn is_upper_triangular(&self) -> bool
+    where
+        T: PartialEq + Zero,
+    {
+        all(self, |i, j, d| i <= j || *d == T::zero())
+    }
+
+    /// Returns `true` if all the elements below the main diagonal are within
+    /// `epsilon` of zero.
+    pub fn is_upper_triangular_eps(&self, epsilon: T) -> bool
+    where
+        T: Copy + PartialOrd + Zero + Sub<Output = T>,
+    {
+        all(self, |i, j, &d| i <= j || approx_eq(d, T::zero(), epsilon))
+    }
+
+    /// Returns `true` if all the elements above the main diagonal are zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// assert!(matrix![1, 0; 2, 3].is_lower_triangular());
+    /// assert!(!matrix![1, 4; 2, 3].is_lower_triangular());
+    /// ```
+    pub fn is_lower_triangular(&self) -> bool
+    where
+        T: PartialEq + Zero,
+    {
+        all(self, |i, j, d| i >= j || *d == T::zero())
+    }
+
+    /// Returns `true` if all the elements above the main diagonal are within
+    /// `epsilon` of zero.
+    pub fn is_lower_triangular_eps(&self, epsilon: T) -> bool
+    where
+        T: Copy + PartialOrd + Zero + Sub<Output = T>,
+    {
+        all(self, |i, j, &d| i >= j || approx_eq(d, T::zero(), epsilon))
+    }
+
+    /// Returns `true` if no element is infinite or NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// assert!(matrix![1.0, 2.0].is_finite());
+    /// assert!(!matrix![1.0, f64::NAN].is_finite());
+    /// ```
+    pub fn is_finite(&self) -> bool
+    where
+        T: Float,
+    {
+        self.iter().all(|d| d.is_finite())
+    }
+
+    /// Asserts that no element is infinite or NaN.
+    ///
+    /// # Panics
+    ///
+    /// If any element is infinite or NaN, the message includes the position of
+    /// the first offending element.
+    ///
+    /// # Examples
+    ///
+    /// ```should_panic
+    /// # use vectrix::matrix;
+    /// #
+    /// let m = matrix![1.0, 2.0; f64::INFINITY, 4.0];
+    /// m.assert_finite(); // panics with "element at (1, 0) is not finite: inf"
+    /// ```
+    #[track_caller]
+    pub fn assert_finite(&self)
+    where
+        T: Float + fmt::Debug,
+    {
+        if let Some((i, j)) = self.indices().find(|&i| !self[i].is_finite()) {
+            finite_panic(None, (i, j), &self[(i, j)]);
+        }
+    }
+
+    /// Asserts that no element is infinite or NaN, with a custom message.
+    ///
+    /// This is the same as [`assert_finite()`][Self::assert_finite] except the
+    /// panic message is prefixed with `msg`.
+    #[track_caller]
+    pub fn expect_finite(&self, msg: &str)
+    where
+        T: Float + fmt::Debug,
+    {
+        if let Some((i, j)) = self.indices().find(|&i| !self[i].is_finite()) {
+            finite_panic(Some(msg), (i, j), &self[(i, j)]);
+        }
+    }
+}
+
+#[cold]
+#[track_caller]
+fn finite_panic(msg: Option<&str>, (i, j): (usize, usize), d: &dyn fmt::Debug) -> ! {
+    match msg {
+        Some(msg) => panic!("{}: element at ({}, {}) is not finite: {:?}", msg, i, j, d),
+        None => panic!("element at ({}, {}) is not finite: {:?}", i, j, d),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Matrix<T, N, N> predicates
+////////////////////////////////////////////////////////////////////////////////
+
+impl<T, const N: usize> Matrix<T, N, N> {
+    /// Returns `true` if the matrix is equal to its transpose.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::matrix;
+    /// #
+    /// assert!(matrix![1, 2; 2, 3].is_symmetric());
+    /// assert!(!matrix![1, 2; 0, 3].is_symmetric());
+    /// ```
+    pub fn is_symmetric(&self) -> bool
+    where
+        T: PartialEq,
+    {
+        all(self, |i, j, d| *d == self[(j, i)])
+    }
+
+    /// Returns `true` if the matrix is within `epsilon` of its transpose.
+    pub fn is_symmetric_eps(&self, epsilon: T) -> bool
+    where
+        T: Copy + PartialOrd + Sub<Output = T>,
+    {
+        all(self, |i, j, &d| approx_eq(d, self[(j, i)], epsilon))
+    }
+
+    /// Returns `true` if the matrix is an identity matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{matrix, Matrix};
+    /// #
+    /// assert!(Matrix::<i64, 3, 3>::identity().is_identity());
+    /// assert!(!matrix![1, 0; 0, 2].is_identity());
+    /// ```
+    pub fn is_identity(&self) -> bool
+    where
+        T: PartialEq + Zero + One,
+    {
+        all(self, |i, j, d| {
+            if i == j {
+                *d == T::one()
+            } else {
+                *d == T::zero()
+            }
+        })
+    }
+
+    /// Returns `true` if every element of the matrix is within `epsilon` of
+    /// the corresponding element in an identity matrix.
+    pub fn is_identity_eps(&self, epsilon: T) -> bool
+    where
+        T: Copy + PartialOrd + Zero + One + Sub<Output = T>,
+    {
+        all(self, |i, j, &d| {
+            if i == j {
+                approx_eq(d, T::one(), epsilon)
+            } else {
+                approx_eq(d, T::zero(), epsilon)
+            }
+        })
+    }
+}
@@ -0,0 +1,110 @@
+//! Integration with the `rand` crate.
+
+use core::cmp::Ordering;
+
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+
+use crate::{new, Float, Matrix, Vector};
+
+////////////////////////////////////////////////////////////////////////////////
+// Random matrices
+////////////////////////////////////////////////////////////////////////////////
+
+impl<T, const M: usize, const N: usize> Distribution<Matrix<T, M, N>> for Standard
+where
+    Standard: Distribution<T>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Matrix<T, M, N> {
+        // SAFETY: the iterator has infinite length.
+        unsafe { new::collect_unchecked(core::iter::repeat_with(|| self.sample(rng))) }
+    }
+}
+
+impl<T, const M: usize, const N: usize> Matrix<T, M, N>
+where
+    Standard: Distribution<T>,
+{
+    /// Create a new matrix filled with random values using the provided
+    /// random number generator.
+    ///
+    /// Each element is sampled from the [`Standard`] distribution.
+    ///
+    /// *This method is only available when the `rand` feature is enabled.*
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::Matrix;
+    /// # use rand::{rngs::StdRng, SeedableRng};
+    /// #
+    /// let mut rng = StdRng::seed_from_u64(7);
+    /// let m = Matrix::<f64, 2, 3>::random_with(&mut rng);
+    /// assert!(m.iter().all(|&x| (0.0..1.0).contains(&x)));
+    /// ```
+    #[must_use]
+    pub fn random_with<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        <Standard as Distribution<Self>>::sample(&Standard, rng)
+    }
+
+    /// Create a new matrix filled with random values using the thread-local
+    /// random number generator.
+    ///
+    /// *This method is only available when the `rand` and `std` features are
+    /// enabled.*
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::Vector;
+    /// #
+    /// let v = Vector::<u8, 4>::random();
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn random() -> Self {
+        Self::random_with(&mut rand::thread_rng())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Weighted sampling
+////////////////////////////////////////////////////////////////////////////////
+
+impl<T: Float, const M: usize> Vector<T, M> {
+    /// Samples an index from this vector treated as a weighted distribution.
+    ///
+    /// Each component is the weight of its index, the weights don't need to
+    /// sum to one. Returns `None` if the vector is empty or the total weight
+    /// is not positive. The weights should all be non-negative, indices with
+    /// zero weight are never returned.
+    ///
+    /// *This method is only available when the `rand` feature is enabled.*
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::vector;
+    /// #
+    /// let weights = vector![0.0, 3.0, 1.0];
+    /// let i = weights.sample_index(&mut rand::thread_rng()).unwrap();
+    /// assert!(i == 1 || i == 2);
+    /// ```
+    pub fn sample_index<R>(&self, rng: &mut R) -> Option<usize>
+    where
+        R: Rng + ?Sized,
+        Standard: Distribution<T>,
+    {
+        let cdf = self.cumsum();
+        let total = *cdf.as_slice().last()?;
+        if total.partial_cmp(&T::zero()) != Some(Ordering::Greater) {
+            return None;
+        }
+        let x = rng.gen::<T>() * total;
+        cdf.iter()
+            .position(|&c| c > x)
+            // Rounding can make `x` equal to the total, in which case the last
+            // index with a non-zero weight is chosen.
+            .or_else(|| self.iter().rposition(|&w| w > T::zero()))
+    }
+}
@@ -0,0 +1,54 @@
+//! Rays in three dimensional space.
+
+use core::ops::{Add, Mul};
+
+use crate::Vector;
+
+/// A ray in three dimensional space.
+///
+/// A ray starts at the origin and extends infinitely in the direction. The
+/// point at parameter `t` is `origin + t * direction`.
+///
+/// # Examples
+///
+/// ```
+/// # use vectrix::{vector, Ray};
+/// #
+/// let ray = Ray::new(vector![1.0, 0.0, 0.0], vector![0.0, 0.0, -1.0]);
+/// assert_eq!(ray.at(2.0), vector![1.0, 0.0, -2.0]);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Ray<T> {
+    origin: Vector<T, 3>,
+    direction: Vector<T, 3>,
+}
+
+impl<T> Ray<T> {
+    /// Create a new ray from the origin and direction.
+    #[inline]
+    pub const fn new(origin: Vector<T, 3>, direction: Vector<T, 3>) -> Self {
+        Self { origin, direction }
+    }
+
+    /// Returns a reference to the origin of this ray.
+    #[inline]
+    pub const fn origin(&self) -> &Vector<T, 3> {
+        &self.origin
+    }
+
+    /// Returns a reference to the direction of this ray.
+    #[inline]
+    pub const fn direction(&self) -> &Vector<T, 3> {
+        &self.direction
+    }
+
+    /// Returns the point at parameter `t` along this ray.
+    #[must_use]
+    #[inline]
+    pub fn at(&self, t: T) -> Vector<T, 3>
+    where
+        T: Copy + Add<Output = T> + Mul<Output = T>,
+    {
+        self.origin + self.direction * t
+    }
+}
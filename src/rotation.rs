@@ -0,0 +1,549 @@
+//! Constructors for rotation matrices in three dimensions.
+//!
+//! All rotations are right-handed and act on column vectors, i.e. a positive
+//! angle rotates counter-clockwise when looking down the axis towards the
+//! origin.
+
+use core::iter::Sum;
+use core::ops::{Add, Deref, Mul};
+
+use crate::{linalg, Dot, Float, Matrix, One, Transpose, Unit, Vector, Zero};
+
+impl<T: Float + Sum> Matrix<T, 3, 3> {
+    /// Returns the rotation by `angle` radians about the x-axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{vector, Matrix};
+    /// #
+    /// let r = Matrix::from_rotation_x(std::f64::consts::FRAC_PI_2);
+    /// let v = r * vector![0.0, 1.0, 0.0];
+    /// assert!((v - vector![0.0, 0.0, 1.0]).frobenius_norm() < 1e-12);
+    /// ```
+    #[must_use]
+    pub fn from_rotation_x(angle: T) -> Self {
+        Self::givens(1, 2, angle)
+    }
+
+    /// Returns the rotation by `angle` radians about the y-axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{vector, Matrix};
+    /// #
+    /// let r = Matrix::from_rotation_y(std::f64::consts::FRAC_PI_2);
+    /// let v = r * vector![0.0, 0.0, 1.0];
+    /// assert!((v - vector![1.0, 0.0, 0.0]).frobenius_norm() < 1e-12);
+    /// ```
+    #[must_use]
+    pub fn from_rotation_y(angle: T) -> Self {
+        Self::givens(2, 0, angle)
+    }
+
+    /// Returns the rotation by `angle` radians about the z-axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{vector, Matrix};
+    /// #
+    /// let r = Matrix::from_rotation_z(std::f64::consts::FRAC_PI_2);
+    /// let v = r * vector![1.0, 0.0, 0.0];
+    /// assert!((v - vector![0.0, 1.0, 0.0]).frobenius_norm() < 1e-12);
+    /// ```
+    #[must_use]
+    pub fn from_rotation_z(angle: T) -> Self {
+        Self::givens(0, 1, angle)
+    }
+
+    /// Returns the rotation by `angle` radians about the given axis.
+    ///
+    /// The axis does not need to be normalized. If the axis is zero then the
+    /// identity matrix is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{vector, Matrix};
+    /// #
+    /// let angle = 2.0 * std::f64::consts::FRAC_PI_3;
+    /// let r = Matrix::from_axis_angle(&vector![1.0, 1.0, 1.0], angle);
+    /// let v = r * vector![1.0, 0.0, 0.0];
+    /// assert!((v - vector![0.0, 1.0, 0.0]).frobenius_norm() < 1e-12);
+    /// ```
+    #[must_use]
+    pub fn from_axis_angle(axis: &Vector<T, 3>, angle: T) -> Self {
+        match Unit::new_normalize(*axis) {
+            Some(axis) => Self::from_unit_axis_angle(&axis, angle),
+            None => Self::identity(),
+        }
+    }
+
+    /// Returns the rotation by `angle` radians about the given unit axis.
+    ///
+    /// This is the same as [`from_axis_angle()`][Matrix::from_axis_angle]
+    /// except that the axis is not normalized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{vector, Matrix, Unit};
+    /// #
+    /// let axis = Unit::new_unchecked(vector![0.0, 0.0, 1.0]);
+    /// let r = Matrix::from_unit_axis_angle(&axis, std::f64::consts::FRAC_PI_2);
+    /// let v = r * vector![1.0, 0.0, 0.0];
+    /// assert!((v - vector![0.0, 1.0, 0.0]).frobenius_norm() < 1e-12);
+    /// ```
+    #[must_use]
+    pub fn from_unit_axis_angle(axis: &Unit<Vector<T, 3>>, angle: T) -> Self {
+        let k = axis.as_ref();
+        let (sin, cos) = (angle.sin(), angle.cos());
+        let t = T::one() - cos;
+        // Rodrigues' rotation formula: R = cos * I + sin * [k]ₓ + t * k * kᵀ
+        let mut matrix = k.skew_symmetric() * sin;
+        for j in 0..3 {
+            for i in 0..3 {
+                matrix[(i, j)] = matrix[(i, j)] + t * k[i] * k[j];
+            }
+            matrix[(j, j)] = matrix[(j, j)] + cos;
+        }
+        matrix
+    }
+
+    /// Returns the rotation from the given Euler angles in radians.
+    ///
+    /// The rotation is composed as yaw about the z-axis, then pitch about the
+    /// new y-axis, then roll about the new x-axis. This is equivalent to
+    /// `Rz(yaw) * Ry(pitch) * Rx(roll)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{vector, Matrix};
+    /// #
+    /// let r = Matrix::from_euler(std::f64::consts::FRAC_PI_2, 0.0, 0.0);
+    /// assert!((r - Matrix::from_rotation_z(std::f64::consts::FRAC_PI_2)).frobenius_norm() < 1e-12);
+    /// ```
+    #[must_use]
+    pub fn from_euler(yaw: T, pitch: T, roll: T) -> Self {
+        Self::from_rotation_z(yaw) * Self::from_rotation_y(pitch) * Self::from_rotation_x(roll)
+    }
+
+    /// Returns the rotation from the given Euler angles in radians, applied in
+    /// the given order.
+    ///
+    /// See [`EulerOrder`] for how the angles are composed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{EulerOrder, Matrix};
+    /// #
+    /// let r = Matrix::from_euler_angles(EulerOrder::ZYX, 0.1, 0.2, 0.3);
+    /// assert!((r - Matrix::from_euler(0.1, 0.2, 0.3)).frobenius_norm() < 1e-12);
+    /// ```
+    #[must_use]
+    pub fn from_euler_angles(order: EulerOrder, a: T, b: T, c: T) -> Self {
+        let (i, j, k) = order.axes();
+        let rotation = |axis: usize, angle| Self::givens((axis + 1) % 3, (axis + 2) % 3, angle);
+        rotation(i, a) * rotation(j, b) * rotation(k, c)
+    }
+
+    /// Returns the Euler angles in radians of this rotation matrix in the
+    /// given order.
+    ///
+    /// This is the inverse of
+    /// [`from_euler_angles()`][Matrix::from_euler_angles]. The first and last
+    /// angles are in `[-π, π]` and the middle angle is in `[-π/2, π/2]`. When
+    /// the middle angle is close to `±π/2` (gimbal lock) only the sum or
+    /// difference of the other two angles is determined, in which case the
+    /// last angle is chosen to be zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{EulerOrder, Matrix};
+    /// #
+    /// let r = Matrix::<f64, 3, 3>::from_euler(0.1, 0.2, 0.3);
+    /// let (yaw, pitch, roll) = r.to_euler_angles(EulerOrder::ZYX);
+    /// assert!((yaw - 0.1).abs() < 1e-12);
+    /// assert!((pitch - 0.2).abs() < 1e-12);
+    /// assert!((roll - 0.3).abs() < 1e-12);
+    /// ```
+    #[must_use]
+    pub fn to_euler_angles(&self, order: EulerOrder) -> (T, T, T) {
+        let (i, j, k) = order.axes();
+        let m = |r: usize, c: usize| self[(r, c)];
+        let sign = if j == (i + 1) % 3 {
+            T::one()
+        } else {
+            -T::one()
+        };
+        let cos_b = (m(i, i) * m(i, i) + m(i, j) * m(i, j)).sqrt();
+        let b = (sign * m(i, k)).atan2(cos_b);
+        // Below this threshold the error of the general formulas, which
+        // divide out `cos(b)`, is larger than the error of assuming gimbal
+        // lock.
+        if cos_b > T::epsilon().sqrt() {
+            let a = (-sign * m(j, k)).atan2(m(k, k));
+            let c = (-sign * m(i, j)).atan2(m(i, i));
+            (a, b, c)
+        } else {
+            let a = (sign * m(k, j)).atan2(m(j, j));
+            (a, b, T::zero())
+        }
+    }
+
+    /// Returns the axis and angle in radians of this rotation matrix.
+    ///
+    /// This is the inverse of
+    /// [`from_unit_axis_angle()`][Matrix::from_unit_axis_angle]. The angle is
+    /// in `[0, π]`. If the angle is zero the axis is arbitrary and the x-axis
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{vector, Matrix};
+    /// #
+    /// let r = Matrix::<f64, 3, 3>::from_axis_angle(&vector![0.0, 3.0, 4.0], 0.5);
+    /// let (axis, angle) = r.to_axis_angle();
+    /// assert!((*axis - vector![0.0, 0.6, 0.8]).l2_norm() < 1e-12);
+    /// assert!((angle - 0.5).abs() < 1e-12);
+    /// ```
+    #[must_use]
+    pub fn to_axis_angle(&self) -> (Unit<Vector<T, 3>>, T) {
+        let (o, l) = (T::zero(), T::one());
+        let two = l + l;
+        let m = |r: usize, c: usize| self[(r, c)];
+        // The skew-symmetric part of the matrix is `sin * [k]ₓ`.
+        let skew = Vector::from_column_major_order([[
+            m(2, 1) - m(1, 2),
+            m(0, 2) - m(2, 0),
+            m(1, 0) - m(0, 1),
+        ]]) / two;
+        let cos = (m(0, 0) + m(1, 1) + m(2, 2) - l) / two;
+        let angle = skew.l2_norm().atan2(cos);
+
+        let axis = if cos > o {
+            skew
+        } else {
+            // Close to π the skew-symmetric part vanishes, so the axis is
+            // taken from the largest column of the symmetric part which is
+            // `(1 - cos) * k * kᵀ`, with the sign taken from the skew part.
+            let c = (0..3).fold(0, |c, d| if m(d, d) > m(c, c) { d } else { c });
+            let mut axis = Vector::from_column_major_order([[
+                (m(0, c) + m(c, 0)) / two,
+                (m(1, c) + m(c, 1)) / two,
+                (m(2, c) + m(c, 2)) / two,
+            ]]);
+            axis[c] = axis[c] - cos;
+            if axis.dot(&skew) < o {
+                -axis
+            } else {
+                axis
+            }
+        };
+
+        match Unit::new_normalize(axis) {
+            Some(axis) => (axis, angle),
+            None => {
+                let x = Vector::from_column_major_order([[l, o, o]]);
+                (Unit::new_unchecked(x), o)
+            }
+        }
+    }
+}
+
+/// The order of the axes of Euler angles.
+///
+/// The angles `(a, b, c)` with the order `XYZ` represent the rotation
+/// `Rx(a) * Ry(b) * Rz(c)`, and similarly for the other orders. For column
+/// vectors this means the last axis is applied first. The order `ZYX`
+/// corresponds to the yaw, pitch and roll used by
+/// [`Matrix::from_euler()`].
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EulerOrder {
+    /// The rotation `Rx(a) * Ry(b) * Rz(c)`.
+    XYZ,
+    /// The rotation `Rx(a) * Rz(b) * Ry(c)`.
+    XZY,
+    /// The rotation `Ry(a) * Rx(b) * Rz(c)`.
+    YXZ,
+    /// The rotation `Ry(a) * Rz(b) * Rx(c)`.
+    YZX,
+    /// The rotation `Rz(a) * Rx(b) * Ry(c)`.
+    ZXY,
+    /// The rotation `Rz(a) * Ry(b) * Rx(c)`.
+    ZYX,
+}
+
+impl EulerOrder {
+    /// Returns the indices of the axes in order.
+    fn axes(self) -> (usize, usize, usize) {
+        match self {
+            Self::XYZ => (0, 1, 2),
+            Self::XZY => (0, 2, 1),
+            Self::YXZ => (1, 0, 2),
+            Self::YZX => (1, 2, 0),
+            Self::ZXY => (2, 0, 1),
+            Self::ZYX => (2, 1, 0),
+        }
+    }
+}
+
+impl<T: Float + Sum, const N: usize> Matrix<T, N, N> {
+    /// Converts the matrix to a [`Rotation`] if it is a rotation matrix.
+    ///
+    /// See [`Rotation::try_new()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{matrix, Matrix};
+    /// #
+    /// assert!(Matrix::from_rotation_x(0.5).try_into_rotation(1e-12).is_some());
+    /// assert!(matrix![1.0, 0.0, 0.0; 0.0, 1.0, 0.0; 0.0, 0.0, -1.0]
+    ///     .try_into_rotation(1e-12)
+    ///     .is_none());
+    /// ```
+    #[must_use]
+    pub fn try_into_rotation(self, tolerance: T) -> Option<Rotation<T, N>> {
+        Rotation::try_new(self, tolerance)
+    }
+}
+
+/// A wrapper that guarantees the wrapped matrix is a rotation in `N`
+/// dimensions.
+///
+/// A rotation matrix is orthonormal and has a determinant of one, so its
+/// inverse is simply its transpose. Rotations can only be composed with other
+/// rotations and applied to vectors, which preserves the invariant. The
+/// wrapped matrix can be accessed through [`Deref`] but not mutated.
+///
+/// # Examples
+///
+/// ```
+/// # use vectrix::{vector, Matrix, Rotation3};
+/// #
+/// let r = Rotation3::try_new(Matrix::from_rotation_z(0.5), 1e-12).unwrap();
+/// let v = r * vector![1.0, 2.0, 3.0];
+/// assert!((r.inverse() * v - vector![1.0, 2.0, 3.0]).l2_norm() < 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct Rotation<T, const N: usize> {
+    matrix: Matrix<T, N, N>,
+}
+
+/// A rotation in two dimensions.
+pub type Rotation2<T> = Rotation<T, 2>;
+
+/// A rotation in three dimensions.
+pub type Rotation3<T> = Rotation<T, 3>;
+
+impl<T, const N: usize> Rotation<T, N> {
+    /// Wraps the matrix without verifying it.
+    ///
+    /// The caller should make sure that the matrix is a rotation, otherwise
+    /// methods that rely on this invariant will return incorrect results.
+    #[inline]
+    pub const fn new_unchecked(matrix: Matrix<T, N, N>) -> Self {
+        Self { matrix }
+    }
+
+    /// Returns the identity rotation.
+    #[must_use]
+    #[inline]
+    pub fn identity() -> Self
+    where
+        T: Copy + Zero + One,
+    {
+        Self::new_unchecked(Matrix::identity())
+    }
+
+    /// Returns the wrapped matrix.
+    #[inline]
+    pub fn into_inner(self) -> Matrix<T, N, N> {
+        self.matrix
+    }
+
+    /// Returns the inverse rotation.
+    ///
+    /// This is the transpose of the matrix, which is exact and much cheaper
+    /// than a general inverse.
+    #[must_use]
+    pub fn inverse(&self) -> Self
+    where
+        T: Copy,
+    {
+        Self::new_unchecked(self.matrix.transpose())
+    }
+}
+
+impl<T: Float + Sum, const N: usize> Rotation<T, N> {
+    /// Wraps the matrix if it is a rotation within `tolerance`.
+    ///
+    /// The matrix is a rotation if every element of `Mᵀ * M` is within
+    /// `tolerance` of the identity and the determinant is within `tolerance`
+    /// of one. Orthonormal matrices with a determinant of minus one are
+    /// reflections and are rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{matrix, Rotation3};
+    /// #
+    /// let m = matrix![0.0, -1.0, 0.0; 1.0, 0.0, 0.0; 0.0, 0.0, 1.0];
+    /// assert!(Rotation3::try_new(m, 1e-12).is_some());
+    /// assert!(Rotation3::try_new(m * 2.0, 1e-12).is_none());
+    /// ```
+    #[must_use]
+    pub fn try_new(matrix: Matrix<T, N, N>, tolerance: T) -> Option<Self> {
+        let orthonormal = (matrix.transpose() * matrix).is_identity_eps(tolerance);
+        if orthonormal && (linalg::determinant(&matrix) - T::one()).abs() <= tolerance {
+            Some(Self::new_unchecked(matrix))
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: Float + Sum> Rotation<T, 2> {
+    /// Returns the rotation by `angle` radians counter-clockwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{vector, Rotation2};
+    /// #
+    /// let r = Rotation2::from_angle(std::f64::consts::FRAC_PI_2);
+    /// assert!((r * vector![1.0, 0.0] - vector![0.0, 1.0]).l2_norm() < 1e-12);
+    /// ```
+    #[must_use]
+    pub fn from_angle(angle: T) -> Self {
+        Self::new_unchecked(Matrix::givens(0, 1, angle))
+    }
+
+    /// Returns the angle of this rotation in radians, in `[-π, π]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::Rotation2;
+    /// #
+    /// let r = Rotation2::from_angle(1.0) * Rotation2::from_angle(0.5);
+    /// assert!((r.angle() - 1.5f64).abs() < 1e-12);
+    /// ```
+    #[must_use]
+    pub fn angle(&self) -> T {
+        self.matrix[(1, 0)].atan2(self.matrix[(0, 0)])
+    }
+}
+
+impl<T: Float + Sum> Rotation<T, 3> {
+    /// Returns the rotation by `angle` radians about the given unit axis.
+    ///
+    /// See [`Matrix::from_unit_axis_angle()`].
+    #[must_use]
+    pub fn from_axis_angle(axis: &Unit<Vector<T, 3>>, angle: T) -> Self {
+        Self::new_unchecked(Matrix::from_unit_axis_angle(axis, angle))
+    }
+
+    /// Returns the rotation from the given Euler angles in radians, applied in
+    /// the given order.
+    ///
+    /// See [`Matrix::from_euler_angles()`].
+    #[must_use]
+    pub fn from_euler_angles(order: EulerOrder, a: T, b: T, c: T) -> Self {
+        Self::new_unchecked(Matrix::from_euler_angles(order, a, b, c))
+    }
+
+    /// Returns the rotation for the given rotation vector, the exponential
+    /// map from the Lie algebra so(3) to SO(3).
+    ///
+    /// The direction of the vector is the axis and its length is the angle in
+    /// radians. This is the inverse of [`log()`][Rotation::log].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{vector, Rotation3};
+    /// #
+    /// let r = Rotation3::exp(&vector![0.0, 0.0, std::f64::consts::FRAC_PI_2]);
+    /// assert!((r * vector![1.0, 0.0, 0.0] - vector![0.0, 1.0, 0.0]).l2_norm() < 1e-12);
+    /// ```
+    #[must_use]
+    pub fn exp(omega: &Vector<T, 3>) -> Self {
+        match Unit::new_normalize(*omega) {
+            Some(axis) => Self::from_axis_angle(&axis, omega.l2_norm()),
+            None => Self::identity(),
+        }
+    }
+
+    /// Returns the rotation vector of this rotation, the logarithm map from
+    /// SO(3) to the Lie algebra so(3).
+    ///
+    /// The result is the axis scaled by the angle, which is in `[0, π]`. This
+    /// is the inverse of [`exp()`][Rotation::exp].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{vector, Rotation3};
+    /// #
+    /// let omega = vector![0.1, -0.2, 0.3];
+    /// assert!((Rotation3::exp(&omega).log() - omega).l2_norm() < 1e-12);
+    /// ```
+    #[must_use]
+    pub fn log(&self) -> Vector<T, 3> {
+        let (axis, angle) = self.matrix.to_axis_angle();
+        axis.into_inner() * angle
+    }
+}
+
+impl<T, const N: usize> Deref for Rotation<T, N> {
+    type Target = Matrix<T, N, N>;
+
+    #[inline]
+    fn deref(&self) -> &Matrix<T, N, N> {
+        &self.matrix
+    }
+}
+
+impl<T, const N: usize> AsRef<Matrix<T, N, N>> for Rotation<T, N> {
+    #[inline]
+    fn as_ref(&self) -> &Matrix<T, N, N> {
+        &self.matrix
+    }
+}
+
+impl<T, const N: usize> Mul for Rotation<T, N>
+where
+    T: Copy + Zero + Add<Output = T> + Mul<Output = T> + Sum,
+{
+    type Output = Self;
+
+    /// Composing two rotations is a rotation, the result applies `rhs` first
+    /// and then `self`.
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self::new_unchecked(self.matrix * rhs.matrix)
+    }
+}
+
+impl<T, const N: usize> Mul<Vector<T, N>> for Rotation<T, N>
+where
+    T: Copy + Zero + Add<Output = T> + Mul<Output = T> + Sum,
+{
+    type Output = Vector<T, N>;
+
+    /// Rotates the vector.
+    #[inline]
+    fn mul(self, rhs: Vector<T, N>) -> Vector<T, N> {
+        self.matrix * rhs
+    }
+}
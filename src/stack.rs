@@ -0,0 +1,116 @@
+//! A stack of two dimensional transformations.
+
+use core::iter::Sum;
+use core::ops::{Add, Mul};
+
+use std::vec::Vec;
+
+use crate::{Matrix, One, Vector, Zero};
+
+/// A stack of two dimensional affine transformations.
+///
+/// This works like the transformation state of a canvas. Each transformation
+/// pushed onto the stack is composed with the current transformation, and
+/// popping it restores the previous one. The transformations are stored as
+/// `3 × 3` homogeneous matrices and the bottom of the stack is always the
+/// identity.
+///
+/// *This type is only available when the `std` feature is enabled.*
+///
+/// # Examples
+///
+/// ```
+/// # use vectrix::{matrix, vector, TransformStack};
+/// #
+/// let mut stack = TransformStack::new();
+/// stack.push(&matrix![
+///     1, 0, 10;
+///     0, 1, 20;
+///     0, 0, 1;
+/// ]);
+/// stack.push(&matrix![
+///     2, 0, 0;
+///     0, 2, 0;
+///     0, 0, 1;
+/// ]);
+/// assert_eq!(stack.apply_point(&vector![1, 1]), vector![12, 22]);
+/// stack.pop();
+/// assert_eq!(stack.apply_point(&vector![1, 1]), vector![11, 21]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TransformStack<T> {
+    stack: Vec<Matrix<T, 3, 3>>,
+}
+
+impl<T: Copy + Zero + One> TransformStack<T> {
+    /// Create a new stack with only the identity transformation.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            stack: Vec::from([Matrix::identity()]),
+        }
+    }
+}
+
+impl<T: Copy + Zero + One> Default for TransformStack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> TransformStack<T> {
+    /// Returns the current transformation.
+    ///
+    /// This is the composition of all the pushed transformations, or the
+    /// identity if the stack is empty.
+    #[inline]
+    pub fn current(&self) -> &Matrix<T, 3, 3> {
+        // the identity at the bottom of the stack is never popped
+        self.stack.last().unwrap()
+    }
+
+    /// Returns the number of pushed transformations.
+    #[inline]
+    pub fn depth(&self) -> usize {
+        self.stack.len() - 1
+    }
+
+    /// Removes the most recently pushed transformation and returns the
+    /// current transformation before it was removed.
+    ///
+    /// Returns `None` if there are no pushed transformations.
+    pub fn pop(&mut self) -> Option<Matrix<T, 3, 3>> {
+        if self.stack.len() > 1 {
+            self.stack.pop()
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> TransformStack<T>
+where
+    T: Copy + Zero + Add<Output = T> + Mul<Output = T> + Sum,
+{
+    /// Composes the transformation with the current transformation and pushes
+    /// the result onto the stack.
+    ///
+    /// The given transformation is applied to points first, followed by the
+    /// previously current transformation.
+    pub fn push(&mut self, transform: &Matrix<T, 3, 3>) {
+        let current = self.current() * transform;
+        self.stack.push(current);
+    }
+
+    /// Transforms a point by the current transformation.
+    #[must_use]
+    pub fn apply_point(&self, point: &Vector<T, 2>) -> Vector<T, 2>
+    where
+        T: One,
+    {
+        let [[x, y]] = point.data;
+        let [[x, y, _]] =
+            (self.current() * Vector::from_column_major_order([[x, y, T::one()]])).data;
+        Vector::from_column_major_order([[x, y]])
+    }
+}
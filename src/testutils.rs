@@ -0,0 +1,161 @@
+//! Utilities for testing numeric code built on this crate.
+//!
+//! This module is only available with the `testutils` feature. It provides
+//! deterministic generators for well-conditioned and ill-conditioned matrices
+//! along with exact reference results, and an assertion for comparing
+//! matrices with a tolerance.
+//!
+//! # Examples
+//!
+//! ```
+//! use vectrix::testutils;
+//!
+//! let h = testutils::hilbert::<f64, 3>();
+//! let exp = testutils::hilbert_inverse::<f64, 3>();
+//! testutils::assert_close(&h.pseudo_inverse(), &exp, 1e-9);
+//! ```
+
+use core::fmt;
+
+use crate::{Float, Matrix};
+
+/// Returns a matrix with elements uniformly distributed in `[-1, 1)`.
+///
+/// The elements are generated from `seed` using a fixed algorithm, so the same
+/// seed always returns the same matrix on every platform.
+#[must_use]
+pub fn random<T: Float, const M: usize, const N: usize>(seed: u64) -> Matrix<T, M, N> {
+    let mut state = seed;
+    let scale = from_u128::<T>(1 << 24);
+    Matrix::repeat_with(|| {
+        let r = from_u128::<T>(u128::from(splitmix64(&mut state) >> 40));
+        (r + r) / scale - T::one()
+    })
+}
+
+/// Returns a well-conditioned square matrix.
+///
+/// The matrix is strictly diagonally dominant, so it is always invertible and
+/// its condition number stays small as `N` grows. The elements are generated
+/// from `seed` in the same way as [`random()`].
+#[must_use]
+pub fn well_conditioned<T: Float, const N: usize>(seed: u64) -> Matrix<T, N, N> {
+    let mut matrix = random::<T, N, N>(seed);
+    let shift = from_u128::<T>(N as u128 + 1);
+    for i in 0..N {
+        matrix[(i, i)] = matrix[(i, i)] + shift;
+    }
+    matrix
+}
+
+/// Returns the `N × N` Hilbert matrix.
+///
+/// The element at `(i, j)` is `1 / (i + j + 1)`. Hilbert matrices are
+/// notoriously ill-conditioned, the condition number grows exponentially with
+/// `N`. The exact inverse is given by [`hilbert_inverse()`].
+#[must_use]
+pub fn hilbert<T: Float, const N: usize>() -> Matrix<T, N, N> {
+    let mut matrix = Matrix::<T, N, N>::zero();
+    for (i, j) in matrix.indices() {
+        matrix[(i, j)] = T::one() / from_u128((i + j + 1) as u128);
+    }
+    matrix
+}
+
+/// Returns the exact inverse of the `N × N` Hilbert matrix.
+///
+/// All the elements are integers which are computed exactly before being
+/// converted to `T`, so this can be used as a reference result.
+#[must_use]
+pub fn hilbert_inverse<T: Float, const N: usize>() -> Matrix<T, N, N> {
+    let n = N as u128;
+    let mut matrix = Matrix::<T, N, N>::zero();
+    for (i, j) in matrix.indices() {
+        let (a, b) = (i as u128, j as u128);
+        let c = binomial(a + b, a);
+        let value = from_u128::<T>(
+            (a + b + 1) * binomial(n + a, n - b - 1) * binomial(n + b, n - a - 1) * c * c,
+        );
+        matrix[(i, j)] = if (i + j) % 2 == 0 { value } else { -value };
+    }
+    matrix
+}
+
+/// Asserts that two matrices are equal within a relative tolerance.
+///
+/// Elements `a` and `b` are considered equal if `|a - b| <= tolerance *
+/// max(1, |b|)`.
+///
+/// # Panics
+///
+/// If any pair of elements is not equal, the message includes the position of
+/// the first offending element.
+#[track_caller]
+pub fn assert_close<T, const M: usize, const N: usize>(
+    actual: &Matrix<T, M, N>,
+    expected: &Matrix<T, M, N>,
+    tolerance: T,
+) where
+    T: Float + fmt::Debug,
+{
+    for (i, j) in actual.indices() {
+        let (a, b) = (actual[(i, j)], expected[(i, j)]);
+        let scale = if b.abs() > T::one() {
+            b.abs()
+        } else {
+            T::one()
+        };
+        // Written so that NaN elements are never considered close.
+        let close = (a - b).abs() <= tolerance * scale;
+        if !close {
+            assert_close_panic(actual, expected, (i, j), &tolerance);
+        }
+    }
+}
+
+#[cold]
+#[track_caller]
+fn assert_close_panic<T: fmt::Debug, const M: usize, const N: usize>(
+    actual: &Matrix<T, M, N>,
+    expected: &Matrix<T, M, N>,
+    (i, j): (usize, usize),
+    tolerance: &T,
+) -> ! {
+    panic!(
+        "matrices differ at ({}, {}): {:?} != {:?} with tolerance {:?}\n  actual: {:+?}\nexpected: {:+?}",
+        i,
+        j,
+        actual[(i, j)],
+        expected[(i, j)],
+        tolerance,
+        actual,
+        expected
+    );
+}
+
+/// Returns the binomial coefficient `n` choose `k`.
+fn binomial(n: u128, k: u128) -> u128 {
+    (0..k).fold(1, |acc, i| acc * (n - i) / (i + 1))
+}
+
+/// Converts an integer to a float exactly, as long as it is representable.
+fn from_u128<T: Float>(n: u128) -> T {
+    let two = T::one() + T::one();
+    (0..128).rev().fold(T::zero(), |acc, bit| {
+        let acc = acc * two;
+        if n >> bit & 1 == 1 {
+            acc + T::one()
+        } else {
+            acc
+        }
+    })
+}
+
+/// The SplitMix64 generator, see <https://prng.di.unimi.it/splitmix64.c>.
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e3779b97f4a7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+}
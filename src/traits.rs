@@ -1,5 +1,11 @@
 //! Abstractions over number types.
 
+use core::cmp::Ordering;
+use core::iter::Sum;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::{linalg, new, Column, Matrix, Row};
+
 /// Defines the absolute value for a type.
 pub trait Abs {
     /// Returns the absolute value of this type.
@@ -18,6 +24,242 @@ pub trait Zero {
     fn zero() -> Self;
 }
 
+/// Defines a multiplicative identity element for a type that is usable in
+/// `const` contexts.
+pub trait ConstOne: One {
+    /// The multiplicative identity element of this type.
+    const ONE: Self;
+}
+
+/// Defines a additive identity element for a type that is usable in `const`
+/// contexts.
+pub trait ConstZero: Zero {
+    /// The additive identity element of this type.
+    const ZERO: Self;
+}
+
+/// Defines arithmetic that detects overflow for a type.
+///
+/// This is used by [`Matrix::checked_mul()`][crate::Matrix::checked_mul] to
+/// catch integer overflow in matrix products regardless of whether overflow
+/// checks are enabled for the build.
+pub trait Checked: Sized {
+    /// Returns the sum of two values or `None` if overflow occurred.
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    /// Returns the product of two values or `None` if overflow occurred.
+    fn checked_mul(self, other: Self) -> Option<Self>;
+}
+
+/// Defines a lossless conversion of a type to a wider type.
+///
+/// This is used by [`Matrix::widening_mul()`][crate::Matrix::widening_mul] to
+/// reduce the risk of overflow when accumulating matrix products. The product
+/// of two widened values cannot overflow, but a sum of such products still
+/// can.
+pub trait Widen {
+    /// The wider type.
+    type Output;
+
+    /// Converts this value to the wider type.
+    fn widen(self) -> Self::Output;
+}
+
+/// Defines a fixed size little-endian byte representation for a type.
+///
+/// This is used by [`Matrix::write_into()`][crate::Matrix::write_into] and
+/// [`Matrix::read_from()`][crate::Matrix::read_from].
+pub trait LeBytes: Sized {
+    /// The number of bytes in the representation.
+    const SIZE: usize;
+
+    /// Writes the little-endian representation of this value into the first
+    /// [`SIZE`][LeBytes::SIZE] bytes of `buf`.
+    fn write_le_bytes(&self, buf: &mut [u8]);
+
+    /// Reads a value from the little-endian representation in the first
+    /// [`SIZE`][LeBytes::SIZE] bytes of `buf`.
+    fn read_le_bytes(buf: &[u8]) -> Self;
+}
+
+/// Defines the trace for a type.
+///
+/// For a square matrix this is the sum of the elements on the main diagonal.
+/// Scalars are their own trace, which allows the trace of a block matrix to be
+/// computed recursively as the trace of the sum of its diagonal blocks.
+///
+/// # Examples
+///
+/// ```
+/// # use vectrix::{matrix, Trace};
+/// #
+/// let a = matrix![1, 2; 3, 4];
+/// assert_eq!(a.trace(), 5);
+///
+/// let b = matrix![a, a; a, a];
+/// assert_eq!(b.trace(), 10);
+/// ```
+pub trait Trace {
+    /// The type of the trace.
+    type Output;
+
+    /// Returns the trace of this type.
+    fn trace(&self) -> Self::Output;
+}
+
+/// Defines the determinant for a type.
+///
+/// For a square matrix the determinant is computed without division, so it is
+/// exact for integer matrices. Scalars are their own determinant, which allows
+/// the determinant of a block matrix to be computed recursively as the
+/// determinant of the determinant over the blocks.
+///
+/// The recursive computation is only valid if all the blocks of the matrix
+/// commute with each other, for example if they are all diagonal or are all
+/// powers of the same matrix. Non-square matrices do not implement this trait.
+///
+/// # Examples
+///
+/// ```
+/// # use vectrix::{matrix, Det, Matrix};
+/// #
+/// let a = matrix![1, 2; 3, 4];
+/// assert_eq!(a.det(), -2);
+///
+/// let i = Matrix::identity();
+/// let b = matrix![a, i; i, a];
+/// assert_eq!(b.det(), (a * a - i).det());
+/// ```
+///
+/// ```compile_fail
+/// # use vectrix::{matrix, Det};
+/// #
+/// matrix![1, 2, 3; 4, 5, 6].det();
+/// ```
+pub trait Det {
+    /// The type of the determinant.
+    type Output;
+
+    /// Returns the determinant of this type.
+    fn det(&self) -> Self::Output;
+}
+
+/// Defines the dot product for a type.
+///
+/// For matrices this is the sum of the products of the corresponding elements,
+/// also known as the Frobenius inner product. For row and column vectors this
+/// is the usual dot product.
+///
+/// # Examples
+///
+/// ```
+/// # use vectrix::{matrix, vector, Dot};
+/// #
+/// assert_eq!(vector![1, 2, 3].dot(&vector![4, 5, 6]), 32);
+/// assert_eq!(matrix![1, 2; 3, 4].dot(&matrix![1, 0; 0, 1]), 5);
+/// ```
+pub trait Dot<Rhs: ?Sized = Self> {
+    /// The type of the dot product.
+    type Output;
+
+    /// Returns the dot product of this type and `other`.
+    fn dot(&self, other: &Rhs) -> Self::Output;
+}
+
+/// Defines the norm for a type.
+///
+/// For matrices this is the Frobenius norm, which for row and column vectors
+/// is the Euclidean length of the vector.
+///
+/// # Examples
+///
+/// ```
+/// # use vectrix::{vector, Norm};
+/// #
+/// assert_eq!(vector![3.0, 4.0].norm(), 5.0);
+/// ```
+pub trait Norm {
+    /// The type of the norm.
+    type Output;
+
+    /// Returns the norm of this type.
+    fn norm(&self) -> Self::Output;
+}
+
+/// Defines the transpose for a type.
+///
+/// # Examples
+///
+/// ```
+/// # use vectrix::{matrix, Transpose};
+/// #
+/// let m = matrix![1, 2, 3; 4, 5, 6];
+/// assert_eq!(m.transpose(), matrix![1, 4; 2, 5; 3, 6]);
+/// ```
+pub trait Transpose {
+    /// The type of the transpose.
+    type Output;
+
+    /// Returns the transpose of this type.
+    fn transpose(&self) -> Self::Output;
+}
+
+/// Defines floating point operations for a type.
+///
+/// This trait is used by methods that only make sense for real numbers, for
+/// example computing norms and decompositions.
+///
+/// It is implemented for `f32` and `f64` when either the `std` or the `libm`
+/// feature is enabled. With the `deterministic` feature enabled the `libm`
+/// implementations are always used.
+pub trait Float:
+    Copy
+    + PartialOrd
+    + Zero
+    + One
+    + Abs
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    /// Returns the machine epsilon value for this type.
+    fn epsilon() -> Self;
+
+    /// Returns the square root of this number.
+    fn sqrt(self) -> Self;
+
+    /// Returns `e` raised to the power of this number.
+    fn exp(self) -> Self;
+
+    /// Returns the natural logarithm of this number.
+    fn ln(self) -> Self;
+
+    /// Returns this number raised to the floating point power `n`.
+    fn powf(self, n: Self) -> Self;
+
+    /// Returns the sine of this number (in radians).
+    fn sin(self) -> Self;
+
+    /// Returns the cosine of this number (in radians).
+    fn cos(self) -> Self;
+
+    /// Returns the tangent of this number (in radians).
+    fn tan(self) -> Self;
+
+    /// Returns the four quadrant arctangent of this number and `other` (in
+    /// radians).
+    fn atan2(self, other: Self) -> Self;
+
+    /// Returns `true` if this number is neither infinite nor NaN.
+    fn is_finite(self) -> bool;
+
+    /// Returns the ordering between this number and `other` using the total
+    /// ordering defined by IEEE 754.
+    fn total_cmp(&self, other: &Self) -> Ordering;
+}
+
 macro_rules! impl_one {
     ($one:literal $($ty:ty)+) => ($(
         impl One for $ty {
@@ -26,6 +268,10 @@ macro_rules! impl_one {
                 $one
             }
         }
+
+        impl ConstOne for $ty {
+            const ONE: $ty = $one;
+        }
     )+)
 }
 
@@ -37,6 +283,72 @@ macro_rules! impl_zero {
                 $zero
             }
         }
+
+        impl ConstZero for $ty {
+            const ZERO: $ty = $zero;
+        }
+    )+)
+}
+
+macro_rules! impl_trace_det_self {
+    ($trt:ident, $meth:ident, $($ty:ty)+) => ($(
+        impl $trt for $ty {
+            type Output = $ty;
+
+            #[inline]
+            fn $meth(&self) -> $ty {
+                *self
+            }
+        }
+    )+)
+}
+
+macro_rules! impl_le_bytes {
+    ($($ty:ident)+) => ($(
+        impl LeBytes for $ty {
+            const SIZE: usize = core::mem::size_of::<$ty>();
+
+            #[inline]
+            fn write_le_bytes(&self, buf: &mut [u8]) {
+                buf[..Self::SIZE].copy_from_slice(&self.to_le_bytes());
+            }
+
+            #[inline]
+            fn read_le_bytes(buf: &[u8]) -> $ty {
+                let mut bytes = [0; core::mem::size_of::<$ty>()];
+                bytes.copy_from_slice(&buf[..Self::SIZE]);
+                $ty::from_le_bytes(bytes)
+            }
+        }
+    )+)
+}
+
+macro_rules! impl_checked {
+    ($($ty:ident)+) => ($(
+        impl Checked for $ty {
+            #[inline]
+            fn checked_add(self, other: $ty) -> Option<$ty> {
+                $ty::checked_add(self, other)
+            }
+
+            #[inline]
+            fn checked_mul(self, other: $ty) -> Option<$ty> {
+                $ty::checked_mul(self, other)
+            }
+        }
+    )+)
+}
+
+macro_rules! impl_widen {
+    ($($ty:ident -> $wide:ident)+) => ($(
+        impl Widen for $ty {
+            type Output = $wide;
+
+            #[inline]
+            fn widen(self) -> $wide {
+                $wide::from(self)
+            }
+        }
     )+)
 }
 
@@ -70,7 +382,207 @@ impl_zero! { false bool }
 impl_zero! { 0 usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
 impl_zero! { 0.0 f32 f64 }
 
+impl_trace_det_self! { Trace, trace, usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64 }
+impl_trace_det_self! { Det, det, isize i8 i16 i32 i64 i128 f32 f64 }
+
+impl_le_bytes! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64 }
+
+impl_checked! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
+
+impl_widen! { u8 -> u16 u16 -> u32 u32 -> u64 u64 -> u128 }
+impl_widen! { i8 -> i16 i16 -> i32 i32 -> i64 i64 -> i128 }
+impl_widen! { f32 -> f64 }
+
 impl_abs_self! { usize u8 u16 u32 u64 u128 }
 impl_abs! { isize i8 i16 i32 i64 i128 }
-#[cfg(feature = "std")]
-impl_abs! { f32 f64 }
+/// Calls the `std` implementation of a float function if available, otherwise
+/// the `libm` implementation. The `deterministic` feature always uses `libm`.
+#[cfg(all(feature = "std", not(feature = "deterministic")))]
+macro_rules! float_call {
+    ($ty:ident::$std:ident | $libm:ident ($($arg:expr),*)) => {
+        $ty::$std($($arg),*)
+    };
+}
+
+#[cfg(all(feature = "libm", any(not(feature = "std"), feature = "deterministic")))]
+macro_rules! float_call {
+    ($ty:ident::$std:ident | $libm:ident ($($arg:expr),*)) => {
+        libm::$libm($($arg),*)
+    };
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+macro_rules! impl_abs_float {
+    ($($ty:ident: $abs:ident)+) => ($(
+        impl Abs for $ty {
+            #[inline]
+            fn abs(self) -> $ty {
+                float_call!($ty::abs | $abs(self))
+            }
+        }
+    )+)
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl_abs_float! { f32: fabsf f64: fabs }
+
+#[cfg(any(feature = "std", feature = "libm"))]
+macro_rules! impl_float {
+    ($($ty:ident: $sqrt:ident $exp:ident $ln:ident $powf:ident $sin:ident $cos:ident $tan:ident $atan2:ident;)+) => ($(
+        impl Float for $ty {
+            #[inline]
+            fn epsilon() -> $ty {
+                $ty::EPSILON
+            }
+
+            #[inline]
+            fn sqrt(self) -> $ty {
+                float_call!($ty::sqrt | $sqrt(self))
+            }
+
+            #[inline]
+            fn exp(self) -> $ty {
+                float_call!($ty::exp | $exp(self))
+            }
+
+            #[inline]
+            fn ln(self) -> $ty {
+                float_call!($ty::ln | $ln(self))
+            }
+
+            #[inline]
+            fn powf(self, n: $ty) -> $ty {
+                float_call!($ty::powf | $powf(self, n))
+            }
+
+            #[inline]
+            fn sin(self) -> $ty {
+                float_call!($ty::sin | $sin(self))
+            }
+
+            #[inline]
+            fn cos(self) -> $ty {
+                float_call!($ty::cos | $cos(self))
+            }
+
+            #[inline]
+            fn tan(self) -> $ty {
+                float_call!($ty::tan | $tan(self))
+            }
+
+            #[inline]
+            fn atan2(self, other: $ty) -> $ty {
+                float_call!($ty::atan2 | $atan2(self, other))
+            }
+
+            #[inline]
+            fn is_finite(self) -> bool {
+                $ty::is_finite(self)
+            }
+
+            #[inline]
+            fn total_cmp(&self, other: &$ty) -> Ordering {
+                $ty::total_cmp(self, other)
+            }
+        }
+    )+)
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl_float! {
+    f32: sqrtf expf logf powf sinf cosf tanf atan2f;
+    f64: sqrt exp log pow sin cos tan atan2;
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Matrix<T, M, N> implementations
+////////////////////////////////////////////////////////////////////////////////
+
+/// The zero matrix is the additive identity.
+impl<T, const M: usize, const N: usize> Zero for Matrix<T, M, N>
+where
+    T: Copy + Zero,
+{
+    #[inline]
+    fn zero() -> Self {
+        Self::repeat(T::zero())
+    }
+}
+
+/// The identity matrix is the multiplicative identity.
+impl<T, const N: usize> One for Matrix<T, N, N>
+where
+    T: Copy + One + Zero,
+{
+    #[inline]
+    fn one() -> Self {
+        Self::identity()
+    }
+}
+
+impl<T, const N: usize> Trace for Matrix<T, N, N>
+where
+    T: Copy + Zero + Add<Output = T> + Trace,
+{
+    type Output = <T as Trace>::Output;
+
+    #[inline]
+    fn trace(&self) -> Self::Output {
+        (0..N).fold(T::zero(), |acc, i| acc + self[(i, i)]).trace()
+    }
+}
+
+impl<T, const N: usize> Det for Matrix<T, N, N>
+where
+    T: Copy + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Det,
+{
+    type Output = <T as Det>::Output;
+
+    #[inline]
+    fn det(&self) -> Self::Output {
+        linalg::determinant(self).det()
+    }
+}
+
+impl<T, const M: usize, const N: usize> Dot for Matrix<T, M, N>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T> + Sum,
+{
+    type Output = T;
+
+    #[inline]
+    fn dot(&self, other: &Self) -> T {
+        crate::sum_products(self.iter().zip(other.iter()).map(|(&a, &b)| a * b))
+    }
+}
+
+impl<T, const M: usize, const N: usize, const P: usize> Dot<Column<T, N, P>> for Row<T, M, N>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T> + Sum,
+{
+    type Output = T;
+
+    #[inline]
+    fn dot(&self, other: &Column<T, N, P>) -> T {
+        Row::dot(self, other)
+    }
+}
+
+impl<T: Float, const M: usize, const N: usize> Norm for Matrix<T, M, N> {
+    type Output = T;
+
+    #[inline]
+    fn norm(&self) -> T {
+        self.frobenius_norm()
+    }
+}
+
+impl<T: Copy, const M: usize, const N: usize> Transpose for Matrix<T, M, N> {
+    type Output = Matrix<T, N, M>;
+
+    #[inline]
+    fn transpose(&self) -> Matrix<T, N, M> {
+        // SAFETY: the iterator yields exactly `M * N` elements.
+        unsafe { new::collect_unchecked(self.iter_row_major().copied()) }
+    }
+}
@@ -0,0 +1,643 @@
+//! Constructors for homogeneous transformation matrices in three dimensions.
+//!
+//! The matrices act on column vectors with homogeneous coordinates, i.e. a
+//! point `p` is transformed as `m * vector![p.x, p.y, p.z, 1.0]`. Projections
+//! follow the OpenGL conventions, they are right-handed and map the view
+//! volume to normalized device coordinates in the range `[-1, 1]` on all
+//! axes.
+
+use core::iter::Sum;
+use core::ops::Div;
+
+use crate::{Dot, Float, Matrix, One, Plane, Ray, Transpose, Vector, Zero};
+
+impl<T: Float + Sum> Matrix<T, 4, 4> {
+    /// Returns the transformation that translates by the given vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{vector, Matrix};
+    /// #
+    /// let m = Matrix::from_translation(&vector![1.0, 2.0, 3.0]);
+    /// assert_eq!(m * vector![1.0, 1.0, 1.0, 1.0], vector![2.0, 3.0, 4.0, 1.0]);
+    /// ```
+    #[must_use]
+    pub fn from_translation(v: &Vector<T, 3>) -> Self {
+        let mut matrix = Self::identity();
+        for i in 0..3 {
+            matrix[(i, 3)] = v[i];
+        }
+        matrix
+    }
+
+    /// Returns the transformation that scales each axis by the corresponding
+    /// component of the given vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{vector, Matrix};
+    /// #
+    /// let m = Matrix::from_scale(&vector![2.0, 3.0, 4.0]);
+    /// assert_eq!(m * vector![1.0, 1.0, 1.0, 1.0], vector![2.0, 3.0, 4.0, 1.0]);
+    /// ```
+    #[must_use]
+    pub fn from_scale(v: &Vector<T, 3>) -> Self {
+        let mut matrix = Self::identity();
+        for i in 0..3 {
+            matrix[(i, i)] = v[i];
+        }
+        matrix
+    }
+
+    /// Returns a right-handed view transformation for a camera at `eye`
+    /// looking at `target`.
+    ///
+    /// In view space the camera looks down the negative z-axis and `up`
+    /// points along the positive y-axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{vector, Matrix};
+    /// #
+    /// let eye = vector![0.0, 0.0, 5.0];
+    /// let m = Matrix::look_at_rh(&eye, &vector![0.0, 0.0, 0.0], &vector![0.0, 1.0, 0.0]);
+    /// assert_eq!(m * vector![0.0, 0.0, 0.0, 1.0], vector![0.0, 0.0, -5.0, 1.0]);
+    /// ```
+    #[must_use]
+    pub fn look_at_rh(eye: &Vector<T, 3>, target: &Vector<T, 3>, up: &Vector<T, 3>) -> Self {
+        let f = *target - *eye;
+        let f = f / f.l2_norm();
+        let s = f.skew_symmetric() * *up;
+        let s = s / s.l2_norm();
+        let u = s.skew_symmetric() * f;
+        view(eye, &s, &u, &-f)
+    }
+
+    /// Returns a left-handed view transformation for a camera at `eye`
+    /// looking at `target`.
+    ///
+    /// In view space the camera looks down the positive z-axis and `up`
+    /// points along the positive y-axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{vector, Matrix};
+    /// #
+    /// let eye = vector![0.0, 0.0, 5.0];
+    /// let m = Matrix::look_at_lh(&eye, &vector![0.0, 0.0, 0.0], &vector![0.0, 1.0, 0.0]);
+    /// assert_eq!(m * vector![0.0, 0.0, 0.0, 1.0], vector![0.0, 0.0, 5.0, 1.0]);
+    /// ```
+    #[must_use]
+    pub fn look_at_lh(eye: &Vector<T, 3>, target: &Vector<T, 3>, up: &Vector<T, 3>) -> Self {
+        let f = *target - *eye;
+        let f = f / f.l2_norm();
+        let s = up.skew_symmetric() * f;
+        let s = s / s.l2_norm();
+        let u = f.skew_symmetric() * s;
+        view(eye, &s, &u, &f)
+    }
+
+    /// Returns a right-handed perspective projection.
+    ///
+    /// `fov_y` is the vertical field of view in radians, `aspect` is the ratio
+    /// of the width to the height of the viewport, and `near` and `far` are
+    /// the positive distances to the clipping planes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{vector, Matrix};
+    /// #
+    /// let m = Matrix::perspective(std::f64::consts::FRAC_PI_2, 1.0, 1.0, 10.0);
+    /// let p = m * vector![0.0, 0.0, -1.0, 1.0];
+    /// assert_eq!(p.z / p.w, -1.0);
+    /// ```
+    #[must_use]
+    pub fn perspective(fov_y: T, aspect: T, near: T, far: T) -> Self {
+        let two = T::one() + T::one();
+        let f = T::one() / (fov_y / two).tan();
+        let mut matrix = Self::zero();
+        matrix[(0, 0)] = f / aspect;
+        matrix[(1, 1)] = f;
+        matrix[(2, 2)] = (far + near) / (near - far);
+        matrix[(2, 3)] = two * far * near / (near - far);
+        matrix[(3, 2)] = -T::one();
+        matrix
+    }
+
+    /// Returns a right-handed orthographic projection.
+    ///
+    /// The box bounded by `left`, `right`, `bottom`, `top` and the planes at
+    /// the distances `near` and `far` in front of the camera is mapped to
+    /// normalized device coordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{vector, Matrix};
+    /// #
+    /// let m = Matrix::orthographic(0.0, 4.0, 0.0, 2.0, 1.0, 3.0);
+    /// assert_eq!(m * vector![4.0, 2.0, -3.0, 1.0], vector![1.0, 1.0, 1.0, 1.0]);
+    /// ```
+    #[must_use]
+    pub fn orthographic(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Self {
+        let two = T::one() + T::one();
+        let mut matrix = Self::identity();
+        matrix[(0, 0)] = two / (right - left);
+        matrix[(1, 1)] = two / (top - bottom);
+        matrix[(2, 2)] = -two / (far - near);
+        matrix[(0, 3)] = -(right + left) / (right - left);
+        matrix[(1, 3)] = -(top + bottom) / (top - bottom);
+        matrix[(2, 3)] = -(far + near) / (far - near);
+        matrix
+    }
+
+    /// Returns a viewport transformation.
+    ///
+    /// Normalized device coordinates in `[-1, 1]` are mapped to screen
+    /// coordinates in the rectangle with the corner at `x`, `y` and the given
+    /// `width` and `height`. The depth in `[-1, 1]` is mapped to `[0, 1]`. The
+    /// `y` axis is not flipped, so `y` increases in the same direction in both
+    /// coordinate systems.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{vector, Matrix};
+    /// #
+    /// let m = Matrix::viewport(0.0, 0.0, 800.0, 600.0);
+    /// assert_eq!(m * vector![1.0, -1.0, 0.0, 1.0], vector![800.0, 0.0, 0.5, 1.0]);
+    /// ```
+    #[must_use]
+    pub fn viewport(x: T, y: T, width: T, height: T) -> Self {
+        let half = T::one() / (T::one() + T::one());
+        let mut matrix = Self::identity();
+        matrix[(0, 0)] = width * half;
+        matrix[(1, 1)] = height * half;
+        matrix[(2, 2)] = half;
+        matrix[(0, 3)] = x + width * half;
+        matrix[(1, 3)] = y + height * half;
+        matrix[(2, 3)] = half;
+        matrix
+    }
+
+    /// Transforms a point by this matrix, including the perspective divide.
+    ///
+    /// The point is extended with `w = 1`, multiplied by the matrix and then
+    /// divided by the resulting `w`. For a projection matrix this maps a point
+    /// to normalized device coordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{vector, Matrix};
+    /// #
+    /// let m = Matrix::perspective(std::f64::consts::FRAC_PI_2, 1.0, 1.0, 3.0);
+    /// let p = m.project_point(&vector![0.0, 0.0, -2.0]);
+    /// assert_eq!(p, vector![0.0, 0.0, 0.5]);
+    /// ```
+    #[must_use]
+    pub fn project_point(&self, point: &Vector<T, 3>) -> Vector<T, 3> {
+        (*self * point.to_homogeneous()).from_homogeneous()
+    }
+
+    /// Transforms a point in screen coordinates back to world coordinates.
+    ///
+    /// This matrix should be the inverse of the projection-view matrix. The
+    /// point is first converted to normalized device coordinates using the
+    /// viewport given by `x`, `y`, `width` and `height`, see
+    /// [`viewport()`][Self::viewport], and then transformed by this matrix
+    /// including the perspective divide.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{vector, Matrix};
+    /// #
+    /// let m = Matrix::orthographic(-2.0, 2.0, -1.0, 1.0, 1.0, 3.0);
+    /// let inv = m.pseudo_inverse();
+    /// let p = inv.unproject(&vector![400.0, 300.0, 0.0], 0.0, 0.0, 800.0, 600.0);
+    /// assert!((p - vector![0.0, 0.0, -1.0]).l2_norm() < 1e-12);
+    /// ```
+    #[must_use]
+    pub fn unproject(
+        &self,
+        screen: &Vector<T, 3>,
+        x: T,
+        y: T,
+        width: T,
+        height: T,
+    ) -> Vector<T, 3> {
+        self.project_point(&screen.screen_to_ndc(x, y, width, height))
+    }
+
+    /// Returns the ray in world coordinates through a position on the screen.
+    ///
+    /// This matrix should be the inverse of the projection-view matrix. The
+    /// ray starts on the near plane and its direction is normalized and points
+    /// towards the far plane. This is the usual way to pick objects under the
+    /// cursor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{vector, Matrix};
+    /// #
+    /// let m = Matrix::perspective(std::f64::consts::FRAC_PI_2, 1.0, 1.0, 10.0);
+    /// let inv = m.pseudo_inverse();
+    /// let ray = inv.screen_to_ray(&vector![50.0, 50.0], 0.0, 0.0, 100.0, 100.0);
+    /// assert!((ray.origin() - vector![0.0, 0.0, -1.0]).l2_norm() < 1e-9);
+    /// assert!((ray.direction() - vector![0.0, 0.0, -1.0]).l2_norm() < 1e-9);
+    /// ```
+    #[must_use]
+    pub fn screen_to_ray(&self, screen: &Vector<T, 2>, x: T, y: T, width: T, height: T) -> Ray<T> {
+        let [[sx, sy]] = screen.data;
+        let (o, l) = (T::zero(), T::one());
+        let near = self.unproject(
+            &Vector::from_column_major_order([[sx, sy, o]]),
+            x,
+            y,
+            width,
+            height,
+        );
+        let far = self.unproject(
+            &Vector::from_column_major_order([[sx, sy, l]]),
+            x,
+            y,
+            width,
+            height,
+        );
+        let direction = far - near;
+        Ray::new(near, direction / direction.l2_norm())
+    }
+
+    /// Returns the transformation that scales, then rotates and then
+    /// translates.
+    ///
+    /// This is the same as `T * R * S` where `T`, `R` and `S` are the
+    /// homogeneous translation, rotation and scale matrices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{vector, Matrix};
+    /// #
+    /// let r = Matrix::from_rotation_z(std::f64::consts::FRAC_PI_2);
+    /// let m = Matrix::from_scale_rotation_translation(
+    ///     &vector![2.0, 2.0, 2.0],
+    ///     &r,
+    ///     &vector![1.0, 0.0, 0.0],
+    /// );
+    /// let p = m * vector![1.0, 0.0, 0.0, 1.0];
+    /// assert!((p - vector![1.0, 2.0, 0.0, 1.0]).l2_norm() < 1e-12);
+    /// ```
+    #[must_use]
+    pub fn from_scale_rotation_translation(
+        scale: &Vector<T, 3>,
+        rotation: &Matrix<T, 3, 3>,
+        translation: &Vector<T, 3>,
+    ) -> Self {
+        let mut matrix = Self::identity();
+        for j in 0..3 {
+            for i in 0..3 {
+                matrix[(i, j)] = rotation[(i, j)] * scale[j];
+            }
+            matrix[(j, 3)] = translation[j];
+        }
+        matrix
+    }
+
+    /// Decomposes an affine transformation into translation, rotation, scale
+    /// and shear.
+    ///
+    /// This is the inverse of
+    /// [`from_scale_rotation_translation()`][Self::from_scale_rotation_translation]
+    /// when there is no shear. In general the upper left `3 × 3` part of the
+    /// matrix is factored as `rotation * diag(scale) * H` where `H` is the
+    /// unit upper triangular shear matrix
+    ///
+    /// ```text
+    /// [1 xy xz]
+    /// [0  1 yz]
+    /// [0  0  1]
+    /// ```
+    ///
+    /// and the rotation is always a proper rotation, a reflection is
+    /// represented by a negative `x` scale.
+    ///
+    /// Returns `None` if the last row is not `[0, 0, 0, 1]` or if the matrix
+    /// is singular.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{vector, Matrix};
+    /// #
+    /// let r = Matrix::from_rotation_x(0.5);
+    /// let m = Matrix::from_scale_rotation_translation(
+    ///     &vector![1.0, 2.0, 3.0],
+    ///     &r,
+    ///     &vector![4.0, 5.0, 6.0],
+    /// );
+    /// let trs = m.decompose_trs().unwrap();
+    /// assert_eq!(trs.translation, vector![4.0, 5.0, 6.0]);
+    /// assert!((trs.rotation - r).frobenius_norm() < 1e-12);
+    /// assert!((trs.scale - vector![1.0, 2.0, 3.0]).l2_norm() < 1e-12);
+    /// assert!(!trs.has_shear(1e-12));
+    /// ```
+    #[must_use]
+    pub fn decompose_trs(&self) -> Option<TrsDecomposition<T>> {
+        let (o, l) = (T::zero(), T::one());
+        if self[(3, 0)] != o || self[(3, 1)] != o || self[(3, 2)] != o || self[(3, 3)] != l {
+            return None;
+        }
+        let column = |j: usize| {
+            Vector::from_column_major_order([[self[(0, j)], self[(1, j)], self[(2, j)]]])
+        };
+        let (c0, c1, c2) = (column(0), column(1), column(2));
+
+        // Gram-Schmidt orthogonalization of the columns, the upper triangular
+        // factor contains the scale on the diagonal and the scaled shear above.
+        let u00 = c0.l2_norm();
+        if u00 == o {
+            return None;
+        }
+        let mut r0 = c0 / u00;
+        let u01 = r0.dot(&c1);
+        let v1 = c1 - r0 * u01;
+        let u11 = v1.l2_norm();
+        if u11 == o {
+            return None;
+        }
+        let r1 = v1 / u11;
+        let u02 = r0.dot(&c2);
+        let u12 = r1.dot(&c2);
+        let v2 = c2 - r0 * u02 - r1 * u12;
+        let u22 = v2.l2_norm();
+        if u22 == o {
+            return None;
+        }
+        let r2 = v2 / u22;
+
+        let mut scale = Vector::from_column_major_order([[u00, u11, u22]]);
+        if (r0.skew_symmetric() * r1).dot(&r2) < o {
+            r0 = -r0;
+            scale[0] = -scale[0];
+        }
+
+        Some(TrsDecomposition {
+            translation: Vector::from_column_major_order([[
+                self[(0, 3)],
+                self[(1, 3)],
+                self[(2, 3)],
+            ]]),
+            rotation: Matrix::from_column_major_order([r0.data[0], r1.data[0], r2.data[0]]),
+            scale,
+            shear: Vector::from_column_major_order([[u01 / u00, u02 / u00, u12 / u11]]),
+        })
+    }
+
+    /// Returns the matrix used to transform normals by this transformation.
+    ///
+    /// This is the inverse transpose of the upper left `3 × 3` part of the
+    /// matrix. It is computed directly from the cofactors of the `3 × 3` part,
+    /// without computing a full `4 × 4` inverse.
+    ///
+    /// Returns `None` if the upper left `3 × 3` part is singular.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{matrix, vector, Matrix};
+    /// #
+    /// let m = Matrix::from_scale(&vector![2.0, 4.0, 1.0]);
+    /// assert_eq!(
+    ///     m.normal_matrix(),
+    ///     Some(matrix![
+    ///         0.5, 0.0,  0.0;
+    ///         0.0, 0.25, 0.0;
+    ///         0.0, 0.0,  1.0;
+    ///     ])
+    /// );
+    /// ```
+    #[must_use]
+    pub fn normal_matrix(&self) -> Option<Matrix<T, 3, 3>> {
+        let column = |j: usize| {
+            Vector::from_column_major_order([[self[(0, j)], self[(1, j)], self[(2, j)]]])
+        };
+        let (c0, c1, c2) = (column(0), column(1), column(2));
+        let r0 = c1.skew_symmetric() * c2;
+        let det = c0.dot(&r0);
+        if det == T::zero() {
+            return None;
+        }
+        let r0 = r0 / det;
+        let r1 = c2.skew_symmetric() * c0 / det;
+        let r2 = c0.skew_symmetric() * c1 / det;
+        Some(Matrix::from_column_major_order([
+            r0.data[0], r1.data[0], r2.data[0],
+        ]))
+    }
+
+    /// Extracts the six planes of the view frustum from a projection or
+    /// projection-view matrix.
+    ///
+    /// The planes are returned in the order left, right, bottom, top, near and
+    /// far. They are normalized and their normals point into the frustum, so
+    /// a point is inside the frustum if its signed distance from every plane
+    /// is non-negative. The matrix is expected to map to clip space with `z`
+    /// in `[-1, 1]`, like [`perspective()`][Self::perspective] and
+    /// [`orthographic()`][Self::orthographic].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{vector, Matrix};
+    /// #
+    /// let m = Matrix::orthographic(-1.0, 1.0, -1.0, 1.0, 1.0, 10.0);
+    /// let planes = m.extract_frustum_planes();
+    /// assert_eq!(planes[0].signed_distance(&vector![0.5, 0.0, -5.0]), 1.5);
+    /// assert!(planes.iter().all(|p| p.signed_distance(&vector![0.0, 0.0, -5.0]) >= 0.0));
+    /// assert!(planes[4].signed_distance(&vector![0.0, 0.0, 0.0]) < 0.0);
+    /// ```
+    #[must_use]
+    pub fn extract_frustum_planes(&self) -> [Plane<T>; 6] {
+        let row = |i: usize| self.row_vector(i).transpose();
+        let (x, y, z, w) = (row(0), row(1), row(2), row(3));
+        [w + x, w - x, w + y, w - y, w + z, w - z]
+            .map(|coefficients| Plane::from_coefficients(coefficients).normalize())
+    }
+}
+
+/// An affine transformation decomposed into translation, rotation, scale and
+/// shear.
+///
+/// This `struct` is created by the
+/// [`decompose_trs()`][Matrix::decompose_trs] method on [`Matrix`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrsDecomposition<T> {
+    /// The translation.
+    pub translation: Vector<T, 3>,
+    /// The rotation, this is always a proper rotation matrix.
+    pub rotation: Matrix<T, 3, 3>,
+    /// The scale along each axis, a reflection is represented by a negative
+    /// `x` scale.
+    pub scale: Vector<T, 3>,
+    /// The `xy`, `xz` and `yz` shear factors.
+    pub shear: Vector<T, 3>,
+}
+
+impl<T: Float> TrsDecomposition<T> {
+    /// Returns `true` if the magnitude of any shear factor is larger than
+    /// `epsilon`.
+    #[must_use]
+    pub fn has_shear(&self, epsilon: T) -> bool {
+        self.shear.iter().any(|&s| s.abs() > epsilon)
+    }
+}
+
+impl<T: Copy + One> Vector<T, 3> {
+    /// Converts this point to homogeneous coordinates by appending a `w`
+    /// component of one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::vector;
+    /// #
+    /// let v = vector![1.0, 2.0, 3.0];
+    /// assert_eq!(v.to_homogeneous(), vector![1.0, 2.0, 3.0, 1.0]);
+    /// ```
+    #[must_use]
+    pub fn to_homogeneous(&self) -> Vector<T, 4> {
+        let [x, y, z] = self.data[0];
+        Vector::from_column_major_order([[x, y, z, T::one()]])
+    }
+}
+
+impl<T: Float> Vector<T, 3> {
+    /// Converts normalized device coordinates to screen coordinates.
+    ///
+    /// This is the same as transforming the point by
+    /// [`Matrix::viewport(x, y, width, height)`][Matrix::viewport].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::vector;
+    /// #
+    /// let ndc = vector![0.0, 0.5, -1.0];
+    /// assert_eq!(ndc.ndc_to_screen(0.0, 0.0, 800.0, 600.0), vector![400.0, 450.0, 0.0]);
+    /// ```
+    #[must_use]
+    pub fn ndc_to_screen(&self, x: T, y: T, width: T, height: T) -> Vector<T, 3> {
+        let half = T::one() / (T::one() + T::one());
+        let [[nx, ny, nz]] = self.data;
+        Vector::from_column_major_order([[
+            x + (nx + T::one()) * width * half,
+            y + (ny + T::one()) * height * half,
+            (nz + T::one()) * half,
+        ]])
+    }
+
+    /// Converts screen coordinates to normalized device coordinates.
+    ///
+    /// This is the inverse of [`ndc_to_screen()`][Vector::ndc_to_screen].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::vector;
+    /// #
+    /// let screen = vector![400.0, 450.0, 0.0];
+    /// assert_eq!(screen.screen_to_ndc(0.0, 0.0, 800.0, 600.0), vector![0.0, 0.5, -1.0]);
+    /// ```
+    #[must_use]
+    pub fn screen_to_ndc(&self, x: T, y: T, width: T, height: T) -> Vector<T, 3> {
+        let two = T::one() + T::one();
+        let [[sx, sy, sz]] = self.data;
+        Vector::from_column_major_order([[
+            (sx - x) * two / width - T::one(),
+            (sy - y) * two / height - T::one(),
+            sz * two - T::one(),
+        ]])
+    }
+}
+
+impl<T: Copy + Div<Output = T>> Vector<T, 4> {
+    /// Converts this point from homogeneous coordinates by dividing the `x`,
+    /// `y` and `z` components by the `w` component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::vector;
+    /// #
+    /// let v = vector![2.0, 4.0, 6.0, 2.0];
+    /// assert_eq!(v.from_homogeneous(), vector![1.0, 2.0, 3.0]);
+    /// ```
+    #[must_use]
+    pub fn from_homogeneous(&self) -> Vector<T, 3> {
+        let [x, y, z, w] = self.data[0];
+        Vector::from_column_major_order([[x / w, y / w, z / w]])
+    }
+}
+
+impl<T: Copy + Zero + One> Matrix<T, 3, 3> {
+    /// Converts this linear transformation to a homogeneous transformation
+    /// matrix.
+    ///
+    /// The matrix is placed in the upper left corner of the identity matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{matrix, vector};
+    /// #
+    /// let m = matrix![
+    ///     0, -1, 0;
+    ///     1, 0, 0;
+    ///     0, 0, 1;
+    /// ];
+    /// assert_eq!(
+    ///     m.to_homogeneous(),
+    ///     matrix![
+    ///         0, -1, 0, 0;
+    ///         1, 0, 0, 0;
+    ///         0, 0, 1, 0;
+    ///         0, 0, 0, 1;
+    ///     ]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_homogeneous(&self) -> Matrix<T, 4, 4> {
+        let mut matrix = Matrix::identity();
+        for j in 0..3 {
+            for i in 0..3 {
+                matrix[(i, j)] = self[(i, j)];
+            }
+        }
+        matrix
+    }
+}
+
+/// Returns the view transformation with the given camera position and basis.
+fn view<T: Float + Sum>(
+    eye: &Vector<T, 3>,
+    x: &Vector<T, 3>,
+    y: &Vector<T, 3>,
+    z: &Vector<T, 3>,
+) -> Matrix<T, 4, 4> {
+    let mut matrix = Matrix::identity();
+    for (i, axis) in [x, y, z].into_iter().enumerate() {
+        for j in 0..3 {
+            matrix[(i, j)] = axis[j];
+        }
+        matrix[(i, 3)] = -axis.dot(eye);
+    }
+    matrix
+}
@@ -0,0 +1,103 @@
+//! A wrapper type for normalized values.
+
+use core::ops::{Deref, Neg};
+
+use crate::{Float, Vector};
+
+/// A wrapper that guarantees the wrapped vector has unit length.
+///
+/// The constructors either normalize the vector or verify that it is already
+/// normalized, so functions that take a `Unit` can skip renormalization. The
+/// wrapped vector can be accessed through [`Deref`] but not mutated.
+///
+/// # Examples
+///
+/// ```
+/// # use vectrix::{vector, Unit};
+/// #
+/// let u = Unit::new_normalize(vector![3.0, 4.0]).unwrap();
+/// assert_eq!(*u, vector![0.6, 0.8]);
+/// assert_eq!(u.x, 0.6);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct Unit<V> {
+    value: V,
+}
+
+impl<V> Unit<V> {
+    /// Wraps the value without normalizing or verifying it.
+    ///
+    /// The caller should make sure that the value is normalized, otherwise
+    /// methods that rely on this invariant will return incorrect results.
+    #[inline]
+    pub const fn new_unchecked(value: V) -> Self {
+        Self { value }
+    }
+
+    /// Returns the wrapped value.
+    #[inline]
+    pub fn into_inner(self) -> V {
+        self.value
+    }
+}
+
+impl<T: Float, const N: usize> Unit<Vector<T, N>> {
+    /// Normalizes the vector and wraps it.
+    ///
+    /// Returns `None` if the vector has zero length or if its length is not
+    /// finite, for example if it has an infinite or `NaN` element.
+    #[must_use]
+    pub fn new_normalize(vector: Vector<T, N>) -> Option<Self> {
+        let norm = vector.l2_norm();
+        if norm == T::zero() || !norm.is_finite() {
+            return None;
+        }
+        Some(Self::new_unchecked(vector / norm))
+    }
+
+    /// Wraps the vector if its length is within `tolerance` of one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{vector, Unit};
+    /// #
+    /// assert!(Unit::try_new(vector![0.0, 1.0, 0.0], 1e-12).is_some());
+    /// assert!(Unit::try_new(vector![0.0, 2.0, 0.0], 1e-12).is_none());
+    /// ```
+    #[must_use]
+    pub fn try_new(vector: Vector<T, N>, tolerance: T) -> Option<Self> {
+        if (vector.l2_norm() - T::one()).abs() <= tolerance {
+            Some(Self::new_unchecked(vector))
+        } else {
+            None
+        }
+    }
+}
+
+impl<V> Deref for Unit<V> {
+    type Target = V;
+
+    #[inline]
+    fn deref(&self) -> &V {
+        &self.value
+    }
+}
+
+impl<V> AsRef<V> for Unit<V> {
+    #[inline]
+    fn as_ref(&self) -> &V {
+        &self.value
+    }
+}
+
+impl<V: Neg<Output = V>> Neg for Unit<V> {
+    type Output = Self;
+
+    /// Negating a unit value preserves its length.
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new_unchecked(-self.value)
+    }
+}
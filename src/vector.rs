@@ -1,8 +1,10 @@
 //! Component access for vectors and constructors from components.
 
-use core::ops::{Deref, DerefMut};
+use core::iter;
+use core::ops::{Add, Deref, DerefMut, Neg};
 
-use crate::{Matrix, RowVector, Vector};
+use crate::{linalg, new};
+use crate::{AssertInBounds, Float, Matrix, One, RowVector, Vector, Zero};
 
 ////////////////////////////////////////////////////////////////////////////////
 // Accessors
@@ -10,12 +12,25 @@ use crate::{Matrix, RowVector, Vector};
 
 macro_rules! struct_coord {
     ($Coord:ident: $($comp:ident),*) => {
+        #[doc = concat!("A coordinate with `", stringify!($($comp),*), "` components.")]
+        ///
+        /// Row and column vectors of the same length dereference to this type,
+        /// allowing their components to be accessed by name. It can also be
+        /// used to destructure a vector.
         #[allow(clippy::upper_case_acronyms)]
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
         #[repr(C)]
         pub struct $Coord<T> {
             $(pub $comp: T),*
         }
+
+        impl<T> $Coord<T> {
+            /// Creates a new coordinate from the given components.
+            #[inline]
+            pub const fn new($($comp: T),*) -> Self {
+                Self { $($comp),* }
+            }
+        }
     };
 }
 
@@ -41,6 +56,26 @@ macro_rules! impl_deref {
     };
 }
 
+macro_rules! impl_from_coord {
+    (($M:literal, $N:literal) <-> $Coord:ident) => {
+        impl<T> From<$Coord<T>> for Matrix<T, $M, $N> {
+            #[inline]
+            fn from(coord: $Coord<T>) -> Self {
+                // SAFETY: see `impl_deref!`.
+                unsafe { new::transmute_unchecked(coord) }
+            }
+        }
+
+        impl<T> From<Matrix<T, $M, $N>> for $Coord<T> {
+            #[inline]
+            fn from(matrix: Matrix<T, $M, $N>) -> Self {
+                // SAFETY: see `impl_deref!`.
+                unsafe { new::transmute_unchecked(matrix) }
+            }
+        }
+    };
+}
+
 struct_coord! { X: x }
 struct_coord! { XY: x, y }
 struct_coord! { XYZ: x, y, z }
@@ -65,11 +100,495 @@ impl_deref! { (4, 1) -> XYZW }
 impl_deref! { (5, 1) -> XYZWA }
 impl_deref! { (6, 1) -> XYZWAB }
 
+// SAFETY: given ($M, $N) <-> $Coord
+//         - $Coord should be marked #[repr(C)].
+//         - $Coord<T> should be the same size as [T; $N].
+impl_from_coord! { (1, 1) <-> X }
+// row vectors
+impl_from_coord! { (1, 2) <-> XY }
+impl_from_coord! { (1, 3) <-> XYZ }
+impl_from_coord! { (1, 4) <-> XYZW }
+impl_from_coord! { (1, 5) <-> XYZWA }
+impl_from_coord! { (1, 6) <-> XYZWAB }
+// column vectors
+impl_from_coord! { (2, 1) <-> XY }
+impl_from_coord! { (3, 1) <-> XYZ }
+impl_from_coord! { (4, 1) <-> XYZW }
+impl_from_coord! { (5, 1) <-> XYZWA }
+impl_from_coord! { (6, 1) <-> XYZWAB }
+
+impl<T, const M: usize, const N: usize> Matrix<T, M, N> {
+    /// Returns a reference to the `I`-th component.
+    ///
+    /// Components are indexed in column-major order like `usize` indexing, but
+    /// the index is checked at compile time. This is useful for vectors that
+    /// are too large to have named component accessors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::vector;
+    /// #
+    /// let v = vector![1, 2, 3, 4, 5, 6, 7];
+    /// assert_eq!(*v.comp::<6>(), 7);
+    /// ```
+    #[inline]
+    pub fn comp<const I: usize>(&self) -> &T {
+        let () = AssertInBounds::<I, M, N>::OK;
+        // SAFETY: the index is checked at compile time.
+        unsafe { self.get_unchecked(I) }
+    }
+
+    /// Returns a mutable reference to the `I`-th component.
+    ///
+    /// Components are indexed in column-major order like `usize` indexing, but
+    /// the index is checked at compile time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::vector;
+    /// #
+    /// let mut v = vector![1, 2, 3, 4, 5, 6, 0];
+    /// *v.comp_mut::<6>() = 7;
+    /// assert_eq!(v[6], 7);
+    /// ```
+    #[inline]
+    pub fn comp_mut<const I: usize>(&mut self) -> &mut T {
+        let () = AssertInBounds::<I, M, N>::OK;
+        // SAFETY: the index is checked at compile time.
+        unsafe { self.get_unchecked_mut(I) }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Reinterpretation
+////////////////////////////////////////////////////////////////////////////////
+
+impl<T, const M: usize> Vector<T, M> {
+    /// Views this column vector as a row vector.
+    ///
+    /// Column and row vectors have the same memory layout so this does not
+    /// copy any data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{row_vector, vector};
+    /// #
+    /// let v = vector![1, 2, 3];
+    /// assert_eq!(v.as_row_vector(), &row_vector![1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn as_row_vector(&self) -> &RowVector<T, M> {
+        // SAFETY: `[[T; M]; 1]` and `[[T; 1]; M]` have the same layout and
+        // `Matrix` is `repr(transparent)`.
+        unsafe { &*(self as *const Self as *const RowVector<T, M>) }
+    }
+
+    /// Views this column vector as a mutable row vector.
+    ///
+    /// Column and row vectors have the same memory layout so this does not
+    /// copy any data.
+    #[inline]
+    pub fn as_mut_row_vector(&mut self) -> &mut RowVector<T, M> {
+        // SAFETY: `[[T; M]; 1]` and `[[T; 1]; M]` have the same layout and
+        // `Matrix` is `repr(transparent)`.
+        unsafe { &mut *(self as *mut Self as *mut RowVector<T, M>) }
+    }
+}
+
+impl<T, const N: usize> RowVector<T, N> {
+    /// Views this row vector as a column vector.
+    ///
+    /// Column and row vectors have the same memory layout so this does not
+    /// copy any data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{row_vector, vector};
+    /// #
+    /// let v = row_vector![1, 2, 3];
+    /// assert_eq!(v.as_vector(), &vector![1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn as_vector(&self) -> &Vector<T, N> {
+        // SAFETY: `[[T; 1]; N]` and `[[T; N]; 1]` have the same layout and
+        // `Matrix` is `repr(transparent)`.
+        unsafe { &*(self as *const Self as *const Vector<T, N>) }
+    }
+
+    /// Views this row vector as a mutable column vector.
+    ///
+    /// Column and row vectors have the same memory layout so this does not
+    /// copy any data.
+    #[inline]
+    pub fn as_mut_vector(&mut self) -> &mut Vector<T, N> {
+        // SAFETY: `[[T; 1]; N]` and `[[T; N]; 1]` have the same layout and
+        // `Matrix` is `repr(transparent)`.
+        unsafe { &mut *(self as *mut Self as *mut Vector<T, N>) }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Scans
+////////////////////////////////////////////////////////////////////////////////
+
+impl<T, const M: usize> Vector<T, M> {
+    /// Applies a running accumulation to this vector in place.
+    ///
+    /// For each component after the first, `f` is called with the previous
+    /// component, which has already been updated, and a mutable reference to
+    /// the current component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::vector;
+    /// #
+    /// let mut v = vector![3, 1, 4, 1, 5];
+    /// v.scan_in_place(|prev, curr| *curr = (*curr).max(*prev));
+    /// assert_eq!(v, vector![3, 3, 4, 4, 5]);
+    /// ```
+    pub fn scan_in_place<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T, &mut T),
+    {
+        let data = &mut self.data[0];
+        for i in 1..M {
+            let (prev, curr) = data.split_at_mut(i);
+            f(&prev[i - 1], &mut curr[0]);
+        }
+    }
+
+    /// Returns the cumulative sum of this vector.
+    ///
+    /// Each component of the result is the sum of all the components of this
+    /// vector up to and including that position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::vector;
+    /// #
+    /// let pdf = vector![0.125, 0.25, 0.5, 0.125];
+    /// assert_eq!(pdf.cumsum(), vector![0.125, 0.375, 0.875, 1.0]);
+    /// ```
+    #[must_use]
+    pub fn cumsum(mut self) -> Self
+    where
+        T: Copy + Add<Output = T>,
+    {
+        self.scan_in_place(|&prev, curr| *curr = prev + *curr);
+        self
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Sequences
+////////////////////////////////////////////////////////////////////////////////
+
+impl<T: Copy + Add<Output = T>, const M: usize> Vector<T, M> {
+    /// Create a new vector of values starting at `start` and increasing by
+    /// `step` for each component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{vector, Vector};
+    /// #
+    /// let v = Vector::<i64, 4>::from_range(3, 2);
+    /// assert_eq!(v, vector![3, 5, 7, 9]);
+    /// ```
+    #[must_use]
+    pub fn from_range(start: T, step: T) -> Self {
+        let mut vector = Self::repeat(start);
+        vector.scan_in_place(|&prev, curr| *curr = prev + step);
+        vector
+    }
+}
+
+impl<T: Float, const M: usize> Vector<T, M> {
+    /// Create a new vector of `M` evenly spaced values from `start` to `end`
+    /// inclusive.
+    ///
+    /// If `M` is one then the vector only contains `start`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{vector, Vector};
+    /// #
+    /// let v = Vector::<f64, 5>::linspace(0.0, 1.0);
+    /// assert_eq!(v, vector![0.0, 0.25, 0.5, 0.75, 1.0]);
+    /// ```
+    #[must_use]
+    pub fn linspace(start: T, end: T) -> Self {
+        let mut vector = Self::repeat(start);
+        if M > 1 {
+            let step = (end - start) / linalg::from_usize(M - 1);
+            let mut t = T::zero();
+            for x in vector.iter_mut() {
+                *x = start + step * t;
+                t = t + T::one();
+            }
+            // Avoid rounding errors in the last component.
+            vector[M - 1] = end;
+        }
+        vector
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Extrema and length
+////////////////////////////////////////////////////////////////////////////////
+
+impl<T: Float, const M: usize> Vector<T, M> {
+    /// Returns the vector rescaled so that its length is between `min` and
+    /// `max`.
+    ///
+    /// The direction of the vector is preserved. A zero vector has no
+    /// direction so it is returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::vector;
+    /// #
+    /// let v = vector![3.0, 4.0];
+    /// assert_eq!(v.clamp_length(0.0, 2.5), vector![1.5, 2.0]);
+    /// assert_eq!(v.clamp_length(10.0, 20.0), vector![6.0, 8.0]);
+    /// assert_eq!(v.clamp_length(1.0, 10.0), v);
+    /// ```
+    #[must_use]
+    pub fn clamp_length(self, min: T, max: T) -> Self {
+        let length = self.l2_norm();
+        if length > max {
+            self * (max / length)
+        } else if length < min && length > T::zero() {
+            self * (min / length)
+        } else {
+            self
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Cross product matrices
+////////////////////////////////////////////////////////////////////////////////
+
+impl<T: Copy + Zero + Neg<Output = T>> Vector<T, 3> {
+    /// Returns the skew-symmetric matrix that computes the cross product with
+    /// this vector.
+    ///
+    /// For vectors `a` and `b` the product `a.skew_symmetric() * b` is equal to
+    /// the cross product `a × b`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{matrix, vector};
+    /// #
+    /// let v = vector![1, 2, 3];
+    /// assert_eq!(
+    ///     v.skew_symmetric(),
+    ///     matrix![
+    ///         0, -3, 2;
+    ///         3, 0, -1;
+    ///         -2, 1, 0;
+    ///     ]
+    /// );
+    /// assert_eq!(v.skew_symmetric() * vector![4, 5, 6], vector![-3, 6, -3]);
+    /// ```
+    #[must_use]
+    pub fn skew_symmetric(&self) -> Matrix<T, 3, 3> {
+        let [x, y, z] = self.data[0];
+        let o = T::zero();
+        Matrix::from_column_major_order([[o, z, -y], [-z, o, x], [y, -x, o]])
+    }
+
+    /// Returns the skew-symmetric matrix that computes the cross product with
+    /// this vector.
+    ///
+    /// This is the same as [`skew_symmetric()`][Self::skew_symmetric], named
+    /// after the hat operator commonly used for it. The inverse is
+    /// [`vee()`][Matrix::vee].
+    #[must_use]
+    #[inline]
+    pub fn hat(&self) -> Matrix<T, 3, 3> {
+        self.skew_symmetric()
+    }
+}
+
+impl<T: Copy> Matrix<T, 3, 3> {
+    /// Returns the vector corresponding to this skew-symmetric matrix.
+    ///
+    /// This is the inverse of [`hat()`][Vector::hat]. Only the elements at
+    /// `(2, 1)`, `(0, 2)` and `(1, 0)` are read, the matrix is not checked to
+    /// actually be skew-symmetric.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::vector;
+    /// #
+    /// let v = vector![1, 2, 3];
+    /// assert_eq!(v.hat().vee(), v);
+    /// ```
+    #[must_use]
+    pub fn vee(&self) -> Vector<T, 3> {
+        Vector::from_column_major_order([[self[(2, 1)], self[(0, 2)], self[(1, 0)]]])
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Swizzles
+////////////////////////////////////////////////////////////////////////////////
+
+macro_rules! impl_swizzle {
+    ($M:literal: $($N:literal => $($name:ident: $($comp:ident)+),+;)+) => {
+        impl<T: Copy> Vector<T, $M> {
+            $($(
+                #[doc = concat!("Returns the `", stringify!($name), "` swizzle of this vector.")]
+                #[must_use]
+                #[inline]
+                pub fn $name(&self) -> Vector<T, $N> {
+                    Matrix::from_column_major_order([[$(self.$comp),+]])
+                }
+            )+)+
+        }
+    };
+}
+
+impl_swizzle! { 2:
+    2 => xx: x x, xy: x y, yx: y x, yy: y y;
+    3 => xxx: x x x, xxy: x x y, xyx: x y x, xyy: x y y, yxx: y x x, yxy: y x y, yyx: y y x,
+        yyy: y y y;
+}
+
+impl_swizzle! { 3:
+    2 => xx: x x, xy: x y, xz: x z, yx: y x, yy: y y, yz: y z, zx: z x, zy: z y, zz: z z;
+    3 => xxx: x x x, xxy: x x y, xxz: x x z, xyx: x y x, xyy: x y y, xyz: x y z, xzx: x z x,
+        xzy: x z y, xzz: x z z, yxx: y x x, yxy: y x y, yxz: y x z, yyx: y y x, yyy: y y y,
+        yyz: y y z, yzx: y z x, yzy: y z y, yzz: y z z, zxx: z x x, zxy: z x y, zxz: z x z,
+        zyx: z y x, zyy: z y y, zyz: z y z, zzx: z z x, zzy: z z y, zzz: z z z;
+}
+
+impl_swizzle! { 4:
+    2 => xx: x x, xy: x y, xz: x z, xw: x w, yx: y x, yy: y y, yz: y z, yw: y w, zx: z x, zy: z y,
+        zz: z z, zw: z w, wx: w x, wy: w y, wz: w z, ww: w w;
+    3 => xxx: x x x, xxy: x x y, xxz: x x z, xxw: x x w, xyx: x y x, xyy: x y y, xyz: x y z,
+        xyw: x y w, xzx: x z x, xzy: x z y, xzz: x z z, xzw: x z w, xwx: x w x, xwy: x w y,
+        xwz: x w z, xww: x w w, yxx: y x x, yxy: y x y, yxz: y x z, yxw: y x w, yyx: y y x,
+        yyy: y y y, yyz: y y z, yyw: y y w, yzx: y z x, yzy: y z y, yzz: y z z, yzw: y z w,
+        ywx: y w x, ywy: y w y, ywz: y w z, yww: y w w, zxx: z x x, zxy: z x y, zxz: z x z,
+        zxw: z x w, zyx: z y x, zyy: z y y, zyz: z y z, zyw: z y w, zzx: z z x, zzy: z z y,
+        zzz: z z z, zzw: z z w, zwx: z w x, zwy: z w y, zwz: z w z, zww: z w w, wxx: w x x,
+        wxy: w x y, wxz: w x z, wxw: w x w, wyx: w y x, wyy: w y y, wyz: w y z, wyw: w y w,
+        wzx: w z x, wzy: w z y, wzz: w z z, wzw: w z w, wwx: w w x, wwy: w w y, wwz: w w z,
+        www: w w w;
+}
+
+impl<T: Copy + Zero + One> Vector<T, 2> {
+    /// Returns a vector with the `x` and `y` components of this vector
+    /// followed by zero.
+    #[must_use]
+    #[inline]
+    pub fn xy0(&self) -> Vector<T, 3> {
+        Matrix::from_column_major_order([[self.x, self.y, T::zero()]])
+    }
+
+    /// Returns a vector with the `x` and `y` components of this vector
+    /// followed by one.
+    #[must_use]
+    #[inline]
+    pub fn xy1(&self) -> Vector<T, 3> {
+        Matrix::from_column_major_order([[self.x, self.y, T::one()]])
+    }
+}
+
+impl<T: Copy + Zero + One> Vector<T, 3> {
+    /// Returns a vector with the `x`, `y` and `z` components of this vector
+    /// followed by zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::vector;
+    /// #
+    /// let v = vector![1, 2, 3];
+    /// assert_eq!(v.xyz0(), vector![1, 2, 3, 0]);
+    /// assert_eq!(v.zyx(), vector![3, 2, 1]);
+    /// assert_eq!(v.xz(), vector![1, 3]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn xyz0(&self) -> Vector<T, 4> {
+        Matrix::from_column_major_order([[self.x, self.y, self.z, T::zero()]])
+    }
+
+    /// Returns a vector with the `x`, `y` and `z` components of this vector
+    /// followed by one.
+    #[must_use]
+    #[inline]
+    pub fn xyz1(&self) -> Vector<T, 4> {
+        Matrix::from_column_major_order([[self.x, self.y, self.z, T::one()]])
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Extend and truncate
+////////////////////////////////////////////////////////////////////////////////
+
+macro_rules! impl_extend_truncate {
+    ($($M:literal -> $P:literal),+) => ($(
+        impl<T> Vector<T, $M> {
+            /// Returns a vector with the given value appended as the last
+            /// component.
+            #[must_use]
+            #[inline]
+            pub fn extend(self, value: T) -> Vector<T, $P> {
+                // SAFETY: the iterator yields exactly `$M + 1` elements.
+                unsafe { new::collect_unchecked(self.into_iter().chain(iter::once(value))) }
+            }
+        }
+
+        impl<T> Vector<T, $P> {
+            /// Returns a vector with the last component removed.
+            #[must_use]
+            #[inline]
+            pub fn truncate(self) -> Vector<T, $M> {
+                // SAFETY: the iterator yields exactly `$M` elements, the
+                // remaining element is dropped along with the iterator.
+                unsafe { new::collect_unchecked(self.into_iter().take($M)) }
+            }
+        }
+    )+)
+}
+
+impl_extend_truncate! { 1 -> 2, 2 -> 3, 3 -> 4, 4 -> 5, 5 -> 6 }
+
 ////////////////////////////////////////////////////////////////////////////////
 // Macros
 ////////////////////////////////////////////////////////////////////////////////
 
 /// A macro for composing row vectors.
+///
+/// Like an array expression the components can be listed, or a single
+/// component can be repeated. In the repeat form the length can be any
+/// constant expression, including a const generic parameter, and the component
+/// must be `Copy` or a constant.
+///
+/// ```
+/// # use vectrix::{row_vector, RowVector};
+/// #
+/// fn ones<const N: usize>() -> RowVector<i64, N> {
+///     row_vector![1; N]
+/// }
+///
+/// assert_eq!(ones::<3>(), row_vector![1, 1, 1]);
+/// ```
 #[macro_export]
 macro_rules! row_vector {
     ($repeat:expr; $n:expr) => {
@@ -81,6 +600,21 @@ macro_rules! row_vector {
 }
 
 /// A macro for composing vectors.
+///
+/// Like an array expression the components can be listed, or a single
+/// component can be repeated. In the repeat form the length can be any
+/// constant expression, including a const generic parameter, and the component
+/// must be `Copy` or a constant.
+///
+/// ```
+/// # use vectrix::{vector, Vector};
+/// #
+/// fn zeros<const N: usize>() -> Vector<f64, N> {
+///     vector![0.0; N]
+/// }
+///
+/// assert_eq!(zeros::<3>(), vector![0.0, 0.0, 0.0]);
+/// ```
 #[macro_export]
 macro_rules! vector {
     ($repeat:expr; $n:expr) => {
@@ -346,3 +880,112 @@ impl<T> From<(T, T, T, T, T, T)> for Vector<T, 6> {
         }
     }
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// Into array
+////////////////////////////////////////////////////////////////////////////////
+
+impl<T, const N: usize> From<Vector<T, N>> for [T; N] {
+    fn from(vector: Vector<T, N>) -> Self {
+        let [arr] = vector.data;
+        arr
+    }
+}
+
+// A generic implementation for row vectors would overlap with the one for
+// column vectors when `N` is 1, so these are implemented for each size.
+macro_rules! impl_row_vector_into_array {
+    ($($n:literal)+) => ($(
+        impl<T> From<RowVector<T, $n>> for [T; $n] {
+            fn from(vector: RowVector<T, $n>) -> Self {
+                vector.data.map(|[x]| x)
+            }
+        }
+    )+)
+}
+
+impl_row_vector_into_array! {
+    2 3 4 5 6 7 8 9 10 11 12 13 14 15 16
+    17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Into tuple
+////////////////////////////////////////////////////////////////////////////////
+
+impl<T> From<Matrix<T, 1, 1>> for (T,) {
+    fn from(matrix: Matrix<T, 1, 1>) -> Self {
+        let [[x]] = matrix.data;
+        (x,)
+    }
+}
+
+impl<T> From<RowVector<T, 2>> for (T, T) {
+    fn from(vector: RowVector<T, 2>) -> Self {
+        let [[x], [y]] = vector.data;
+        (x, y)
+    }
+}
+
+impl<T> From<RowVector<T, 3>> for (T, T, T) {
+    fn from(vector: RowVector<T, 3>) -> Self {
+        let [[x], [y], [z]] = vector.data;
+        (x, y, z)
+    }
+}
+
+impl<T> From<RowVector<T, 4>> for (T, T, T, T) {
+    fn from(vector: RowVector<T, 4>) -> Self {
+        let [[x], [y], [z], [w]] = vector.data;
+        (x, y, z, w)
+    }
+}
+
+impl<T> From<RowVector<T, 5>> for (T, T, T, T, T) {
+    fn from(vector: RowVector<T, 5>) -> Self {
+        let [[x], [y], [z], [w], [a]] = vector.data;
+        (x, y, z, w, a)
+    }
+}
+
+impl<T> From<RowVector<T, 6>> for (T, T, T, T, T, T) {
+    fn from(vector: RowVector<T, 6>) -> Self {
+        let [[x], [y], [z], [w], [a], [b]] = vector.data;
+        (x, y, z, w, a, b)
+    }
+}
+
+impl<T> From<Vector<T, 2>> for (T, T) {
+    fn from(vector: Vector<T, 2>) -> Self {
+        let [[x, y]] = vector.data;
+        (x, y)
+    }
+}
+
+impl<T> From<Vector<T, 3>> for (T, T, T) {
+    fn from(vector: Vector<T, 3>) -> Self {
+        let [[x, y, z]] = vector.data;
+        (x, y, z)
+    }
+}
+
+impl<T> From<Vector<T, 4>> for (T, T, T, T) {
+    fn from(vector: Vector<T, 4>) -> Self {
+        let [[x, y, z, w]] = vector.data;
+        (x, y, z, w)
+    }
+}
+
+impl<T> From<Vector<T, 5>> for (T, T, T, T, T) {
+    fn from(vector: Vector<T, 5>) -> Self {
+        let [[x, y, z, w, a]] = vector.data;
+        (x, y, z, w, a)
+    }
+}
+
+impl<T> From<Vector<T, 6>> for (T, T, T, T, T, T) {
+    fn from(vector: Vector<T, 6>) -> Self {
+        let [[x, y, z, w, a, b]] = vector.data;
+        (x, y, z, w, a, b)
+    }
+}
@@ -1,10 +1,14 @@
 //! Row and column slices of a matrix.
 
+use core::fmt;
 use core::iter::Sum;
-use core::ops::{Deref, DerefMut, Mul};
+use core::ops::{Add, Deref, DerefMut, Index, Mul};
 
 use stride::Stride;
 
+use crate::new;
+use crate::{Checked, Matrix, Zero};
+
 ////////////////////////////////////////////////////////////////////////////////
 // Row
 ////////////////////////////////////////////////////////////////////////////////
@@ -17,7 +21,7 @@ pub struct Row<T, const M: usize, const N: usize> {
 }
 
 impl<T, const M: usize, const N: usize> Row<T, M, N> {
-    pub(crate) fn new(data: &[T]) -> &Self {
+    pub(crate) const fn new(data: &[T]) -> &Self {
         // SAFETY: `Row` and `Stride` are both repr(transparent)
         unsafe { &*(data as *const [T] as *const Self) }
     }
@@ -42,6 +46,24 @@ impl<T, const M: usize, const N: usize> DerefMut for Row<T, M, N> {
     }
 }
 
+impl<'a, T, const M: usize, const N: usize> IntoIterator for &'a Row<T, M, N> {
+    type Item = &'a T;
+    type IntoIter = stride::Iter<'a, T, M>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
+
+impl<'a, T, const M: usize, const N: usize> IntoIterator for &'a mut Row<T, M, N> {
+    type Item = &'a mut T;
+    type IntoIter = stride::IterMut<'a, T, M>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter_mut()
+    }
+}
+
 impl<T, U, const M: usize, const N: usize, const S: usize> PartialEq<Stride<U, S>> for Row<T, M, N>
 where
     T: PartialEq<U>,
@@ -81,7 +103,7 @@ pub struct Column<T, const M: usize, const N: usize> {
 }
 
 impl<T, const M: usize, const N: usize> Column<T, M, N> {
-    pub(crate) fn new(data: &[T]) -> &Self {
+    pub(crate) const fn new(data: &[T]) -> &Self {
         // SAFETY: `Column` and `Stride` are both repr(transparent)
         unsafe { &*(data as *const [T] as *const Self) }
     }
@@ -106,6 +128,24 @@ impl<T, const M: usize, const N: usize> DerefMut for Column<T, M, N> {
     }
 }
 
+impl<'a, T, const M: usize, const N: usize> IntoIterator for &'a Column<T, M, N> {
+    type Item = &'a T;
+    type IntoIter = stride::Iter<'a, T, 1>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
+
+impl<'a, T, const M: usize, const N: usize> IntoIterator for &'a mut Column<T, M, N> {
+    type Item = &'a mut T;
+    type IntoIter = stride::IterMut<'a, T, 1>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter_mut()
+    }
+}
+
 impl<T, U, const M: usize, const N: usize, const S: usize> PartialEq<Stride<U, S>>
     for Column<T, M, N>
 where
@@ -134,6 +174,99 @@ where
     }
 }
 
+////////////////////////////////////////////////////////////////////////////////
+// Block
+////////////////////////////////////////////////////////////////////////////////
+
+/// A view of a rectangular `P × Q` block in a [`Matrix`].
+///
+/// This `struct` is created by the [`.block()`][Matrix::block] method on
+/// [`Matrix`].
+pub struct BlockView<'a, T, const M: usize, const N: usize, const P: usize, const Q: usize> {
+    matrix: &'a Matrix<T, M, N>,
+    row: usize,
+    col: usize,
+}
+
+impl<'a, T, const M: usize, const N: usize, const P: usize, const Q: usize>
+    BlockView<'a, T, M, N, P, Q>
+{
+    /// The caller must ensure that the block is in bounds.
+    pub(crate) fn new(matrix: &'a Matrix<T, M, N>, row: usize, col: usize) -> Self {
+        Self { matrix, row, col }
+    }
+
+    /// Returns a reference to the element at the given row and column
+    /// position in the block, or `None` if out of bounds.
+    #[inline]
+    pub fn get(&self, (i, j): (usize, usize)) -> Option<&'a T> {
+        (i < P && j < Q).then(|| &self.matrix.data[self.col + j][self.row + i])
+    }
+
+    /// Copies the block into a new matrix.
+    #[must_use]
+    #[inline]
+    pub fn to_matrix(&self) -> Matrix<T, P, Q>
+    where
+        T: Copy,
+    {
+        let data = &self.matrix.data;
+        let iter = (0..Q).flat_map(|j| data[self.col + j][self.row..self.row + P].iter().copied());
+        // SAFETY: the iterator yields exactly `P * Q` elements.
+        unsafe { new::collect_unchecked(iter) }
+    }
+}
+
+impl<T, const M: usize, const N: usize, const P: usize, const Q: usize> Clone
+    for BlockView<'_, T, M, N, P, Q>
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, const M: usize, const N: usize, const P: usize, const Q: usize> Copy
+    for BlockView<'_, T, M, N, P, Q>
+{
+}
+
+impl<T, const M: usize, const N: usize, const P: usize, const Q: usize> fmt::Debug
+    for BlockView<'_, T, M, N, P, Q>
+where
+    T: fmt::Debug,
+{
+    /// Formats the block as a list of columns.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let data = &self.matrix.data;
+        f.debug_list()
+            .entries((0..Q).map(|j| &data[self.col + j][self.row..self.row + P]))
+            .finish()
+    }
+}
+
+impl<T, const M: usize, const N: usize, const P: usize, const Q: usize> Index<(usize, usize)>
+    for BlockView<'_, T, M, N, P, Q>
+{
+    type Output = T;
+
+    #[track_caller]
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        match self.get((i, j)) {
+            Some(elem) => elem,
+            None => block_index_panic::<P, Q>(i, j),
+        }
+    }
+}
+
+#[cold]
+#[track_caller]
+fn block_index_panic<const P: usize, const Q: usize>(i: usize, j: usize) -> ! {
+    panic!(
+        "index ({}, {}) out of range for block with {} rows and {} columns",
+        i, j, P, Q
+    );
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // General
 ////////////////////////////////////////////////////////////////////////////////
@@ -141,6 +274,9 @@ where
 impl<T, const M: usize, const N: usize> Row<T, M, N> {
     /// Returns the dot product between a row and column.
     ///
+    /// The products are accumulated strictly from left to right starting from
+    /// the first product.
+    ///
     /// # Examples
     ///
     /// ```
@@ -157,8 +293,58 @@ impl<T, const M: usize, const N: usize> Row<T, M, N> {
     #[inline]
     pub fn dot<const P: usize>(&self, other: &Column<T, N, P>) -> T
     where
-        T: Copy + Mul<Output = T> + Sum,
+        T: Copy + Add<Output = T> + Mul<Output = T> + Sum,
+    {
+        crate::sum_products((0..N).map(|i| self[i] * other[i]))
+    }
+
+    /// Returns the dot product between a row and column or `None` if
+    /// overflow occurred.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{vector, row_vector};
+    /// #
+    /// let row_vector = row_vector![1, 2, 3];
+    /// assert_eq!(row_vector.row(0).checked_dot(vector![4, 5, 6].column(0)), Some(32));
+    ///
+    /// let row_vector = row_vector![i32::MAX, 1];
+    /// assert_eq!(row_vector.row(0).checked_dot(vector![1, 1].column(0)), None);
+    /// ```
+    #[inline]
+    pub fn checked_dot<const P: usize>(&self, other: &Column<T, N, P>) -> Option<T>
+    where
+        T: Copy + Zero + Checked,
+    {
+        (0..N).try_fold(T::zero(), |acc, i| {
+            acc.checked_add(self[i].checked_mul(other[i])?)
+        })
+    }
+
+    /// Returns the dot product between a row and column accumulated in the
+    /// type `A`.
+    ///
+    /// Each element is converted to `A` before multiplying, so choosing a
+    /// wider type than `T` avoids overflow for integers and improves
+    /// precision for floating point numbers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vectrix::{vector, row_vector};
+    /// #
+    /// let row_vector = row_vector![i16::MAX, i16::MAX];
+    /// let column_vector = vector![i16::MAX, i16::MAX];
+    /// let dot = row_vector.row(0).dot_with_acc::<i64, 1>(column_vector.column(0));
+    /// assert_eq!(dot, 2 * 32767 * 32767);
+    /// ```
+    #[inline]
+    pub fn dot_with_acc<A, const P: usize>(&self, other: &Column<T, N, P>) -> A
+    where
+        T: Copy + Into<A>,
+        A: Add<Output = A> + Mul<Output = A> + Sum,
     {
-        (0..N).map(|i| self[i] * other[i]).sum()
+        crate::sum_products((0..N).map(|i| self[i].into() * other[i].into()))
     }
 }
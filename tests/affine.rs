@@ -0,0 +1,69 @@
+use vectrix::{matrix, vector, Affine, Affine2, Affine3, Matrix};
+
+#[test]
+fn affine_identity() {
+    let a = Affine3::<i64>::identity();
+    assert_eq!(a.linear(), &Matrix::identity());
+    assert_eq!(a.translation(), &vector![0, 0, 0]);
+    assert_eq!(a.transform_point(&vector![1, 2, 3]), vector![1, 2, 3]);
+}
+
+#[test]
+fn affine_transform() {
+    let a = Affine2::new(matrix![2, 0; 0, 3], vector![10, 20]);
+    assert_eq!(a.transform_point(&vector![1, 1]), vector![12, 23]);
+    assert_eq!(a.transform_vector(&vector![1, 1]), vector![2, 3]);
+    let t = Affine2::from_translation(vector![5, 6]);
+    assert_eq!(t.transform_point(&vector![1, 1]), vector![6, 7]);
+    assert_eq!(t.transform_vector(&vector![1, 1]), vector![1, 1]);
+    let l = Affine2::from_linear(matrix![0, 1; 1, 0]);
+    assert_eq!(l.transform_point(&vector![1, 2]), vector![2, 1]);
+}
+
+#[test]
+fn affine_compose() {
+    let a = Affine2::new(matrix![0, -1; 1, 0], vector![1, 0]);
+    let b = Affine2::new(matrix![2, 0; 0, 2], vector![0, 3]);
+    let p = vector![4, 5];
+    assert_eq!(
+        (a * b).transform_point(&p),
+        a.transform_point(&b.transform_point(&p))
+    );
+    assert_eq!(
+        (b * a).transform_point(&p),
+        b.transform_point(&a.transform_point(&p))
+    );
+    assert_eq!(a * Affine::identity(), a);
+}
+
+#[test]
+fn affine_inverse() {
+    let a = Affine3::new(
+        matrix![1.0, 2.0, 0.0; 0.0, 1.0, 0.0; 0.0, 0.0, 2.0],
+        vector![1.0, -2.0, 3.0],
+    );
+    let inv = a.inverse().unwrap();
+    let p = vector![0.5, 7.0, -1.0];
+    assert!((inv.transform_point(&a.transform_point(&p)) - p).frobenius_norm() < 1e-12);
+    let i = a * inv;
+    assert!((*i.linear() - Matrix::identity()).frobenius_norm() < 1e-12);
+    assert!(i.translation().frobenius_norm() < 1e-12);
+}
+
+#[test]
+fn affine_inverse_singular() {
+    let a = Affine2::new(matrix![1.0, 2.0; 2.0, 4.0], vector![1.0, 1.0]);
+    assert_eq!(a.inverse(), None);
+}
+
+#[test]
+fn affine_into_homogeneous() {
+    let a = Affine2::new(matrix![1, 2; 3, 4], vector![5, 6]);
+    let m: Matrix<i64, 3, 3> = a.into();
+    assert_eq!(m, matrix![1, 2, 5; 3, 4, 6; 0, 0, 1]);
+    assert_eq!(m * vector![1, 1, 1], vector![8, 13, 1]);
+
+    let a = Affine3::from_translation(vector![1, 2, 3]);
+    let m: Matrix<i64, 4, 4> = a.into();
+    assert_eq!(m * vector![1, 1, 1, 1], vector![2, 3, 4, 1]);
+}
@@ -0,0 +1,8 @@
+#[test]
+fn assert_ui() {
+    let t = trybuild::TestCases::new();
+    // The assertions fail after monomorphization, which `cargo check` does not
+    // do. Having a passing test makes trybuild use `cargo build` instead.
+    t.pass("tests/ui/assert/diagonal_square.rs");
+    t.compile_fail("tests/ui/assert/diagonal_not_square.rs");
+}
@@ -0,0 +1,52 @@
+use vectrix::{matrix, vector, Error, LeBytes, Matrix, Vector};
+
+#[test]
+fn matrix_write_into() {
+    let m = matrix![1_i32, -2; 0x0102_0304, 4];
+    let mut buf = [0xff; 20];
+    assert_eq!(m.write_into(&mut buf), Ok(16));
+    assert_eq!(
+        buf,
+        [1, 0, 0, 0, 4, 3, 2, 1, 0xfe, 0xff, 0xff, 0xff, 4, 0, 0, 0, 0xff, 0xff, 0xff, 0xff]
+    );
+}
+
+#[test]
+fn matrix_write_into_short() {
+    let m = vector![1.0_f64, 2.0];
+    let mut buf = [0; 15];
+    assert_eq!(m.write_into(&mut buf), Err(Error::LengthMismatch));
+    assert_eq!(buf, [0; 15]);
+}
+
+#[test]
+fn matrix_read_from() {
+    let m = matrix![1.5_f32, -2.0, 0.25; 3.0, 1e10, -0.0];
+    let mut buf = [0; 24];
+    m.write_into(&mut buf).unwrap();
+    assert_eq!(Matrix::read_from(&buf), Ok(m));
+    assert_eq!(
+        Matrix::<f32, 2, 3>::read_from(&buf[1..]),
+        Err(Error::LengthMismatch)
+    );
+    assert_eq!(
+        Vector::<u8, 3>::read_from(&[7, 8, 9, 10]),
+        Ok(vector![7, 8, 9])
+    );
+}
+
+#[test]
+fn matrix_bytes_zero_sized() {
+    let m = Matrix::<u64, 0, 3>::zero();
+    assert_eq!(m.write_into(&mut []), Ok(0));
+    assert_eq!(Matrix::<u64, 0, 3>::read_from(&[]), Ok(m));
+}
+
+#[test]
+fn le_bytes() {
+    let mut buf = [0; 2];
+    0x1234_u16.write_le_bytes(&mut buf);
+    assert_eq!(buf, [0x34, 0x12]);
+    assert_eq!(u16::read_le_bytes(&buf), 0x1234);
+    assert_eq!(<i128 as LeBytes>::SIZE, 16);
+}
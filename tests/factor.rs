@@ -0,0 +1,50 @@
+use std::f64::consts::FRAC_PI_6;
+
+use vectrix::{matrix, vector, Matrix};
+
+#[test]
+fn matrix_householder() {
+    let v = vector![1.0, 2.0, 2.0];
+    let h = Matrix::householder(&v);
+    assert!(h.is_symmetric());
+    assert!((h * h - Matrix::identity()).frobenius_norm() < 1e-12);
+    assert!((h * v + v).frobenius_norm() < 1e-12);
+    let exp = matrix![
+        7.0, -4.0, -4.0;
+        -4.0, 1.0, -8.0;
+        -4.0, -8.0, 1.0;
+    ] / 9.0;
+    assert!((h - exp).frobenius_norm() < 1e-12);
+}
+
+#[test]
+fn matrix_householder_zero() {
+    let h = Matrix::householder(&vector![0.0, 0.0]);
+    assert_eq!(h, Matrix::identity());
+}
+
+#[test]
+fn matrix_givens() {
+    let g = Matrix::<f64, 3, 3>::givens(2, 0, FRAC_PI_6);
+    let (s, c) = (0.5, 3.0_f64.sqrt() / 2.0);
+    let exp = matrix![
+        c, 0.0, s;
+        0.0, 1.0, 0.0;
+        -s, 0.0, c;
+    ];
+    assert!((g - exp).frobenius_norm() < 1e-12);
+    let inv = Matrix::givens(2, 0, -FRAC_PI_6);
+    assert!((g * inv - Matrix::identity()).frobenius_norm() < 1e-12);
+}
+
+#[test]
+#[should_panic(expected = "axes (0, 3) out of bounds for `Matrix<_, 3, 3>`")]
+fn matrix_givens_out_of_bounds() {
+    let _ = Matrix::<f64, 3, 3>::givens(0, 3, 1.0);
+}
+
+#[test]
+#[should_panic(expected = "axes must be different, both are 1")]
+fn matrix_givens_same_axes() {
+    let _ = Matrix::<f64, 3, 3>::givens(1, 1, 1.0);
+}
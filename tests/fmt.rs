@@ -1,4 +1,4 @@
-use vectrix::{matrix, row_vector, vector};
+use vectrix::{matrix, row_vector, vector, Matrix};
 
 #[test]
 fn vector_debug() {
@@ -26,6 +26,42 @@ fn matrix_debug_precision() {
     );
 }
 
+#[test]
+fn matrix_debug_row_major() {
+    assert_eq!(
+        format!("{:+?}", matrix![-1, 3, 0; 0, 0, 0; -3, 24, 7]),
+        "[[-1, +3, +0], [+0, +0, +0], [-3, +24, +7]]"
+    );
+    assert_eq!(
+        format!("{:+.1?}", matrix![1.0, 2.0; 3.0, 4.25]),
+        "[[+1.0, +2.0], [+3.0, +4.2]]"
+    );
+    assert_eq!(
+        format!("{:+5?}", matrix![1, -2; 30, 4]),
+        "[[   +1,    -2], [  +30,    +4]]"
+    );
+    assert_eq!(format!("{:+?}", vector![1, -2]), "[+1, -2]");
+    assert_eq!(format!("{:+?}", Matrix::<i64, 0, 2>::zero()), "[]");
+    assert_eq!(format!("{:+?}", Matrix::<i64, 2, 0>::zero()), "[[], []]");
+}
+
+#[test]
+fn matrix_debug_row_major_pretty() {
+    assert_eq!(
+        format!("{:+#?}", matrix![1, 2; 3, 4]),
+        "[
+    [
+        +1,
+        +2,
+    ],
+    [
+        +3,
+        +4,
+    ],
+]"
+    );
+}
+
 #[test]
 fn vector_display() {
     assert_eq!(
@@ -154,3 +190,74 @@ fn matrix_display_precision() {
 "
     );
 }
+
+#[test]
+fn matrix_debug_zero_sized() {
+    assert_eq!(format!("{:?}", Matrix::<i64, 0, 0>::zero()), "[]");
+    assert_eq!(format!("{:?}", Matrix::<i64, 0, 2>::zero()), "[[], []]");
+    assert_eq!(format!("{:?}", Matrix::<i64, 2, 0>::zero()), "[]");
+}
+
+#[test]
+fn matrix_display_zero_sized() {
+    assert_eq!(
+        format!("{}", Matrix::<i64, 0, 0>::zero()),
+        "
+ ┌┐
+ └┘
+"
+    );
+    assert_eq!(
+        format!("{}", Matrix::<i64, 0, 2>::zero()),
+        "
+ ┌    ┐
+ └    ┘
+"
+    );
+    assert_eq!(
+        format!("{}", Matrix::<i64, 2, 0>::zero()),
+        "
+ ┌┐
+ ││
+ ││
+ └┘
+"
+    );
+}
+
+#[test]
+fn matrix_format_with() {
+    let m = matrix![1.5, -2.0; 0.0, 10.25];
+    assert_eq!(
+        format!("{}", m.format_with(|f, x| write!(f, "{}m", x))),
+        "
+ ┌              ┐
+ │ 1.5m     -2m │
+ │   0m  10.25m │
+ └              ┘
+"
+    );
+}
+
+#[test]
+fn matrix_format_with_ignores_outer_precision() {
+    let m = vector!["α", "βγ"];
+    assert_eq!(
+        format!("{:.1}", m.format_with(|f, s| write!(f, "<{}>", s))),
+        "
+ ┌      ┐
+ │  <α> │
+ │ <βγ> │
+ └      ┘
+"
+    );
+}
+
+#[test]
+fn matrix_format_with_zero_sized() {
+    let m = Matrix::<i64, 0, 2>::zero();
+    assert_eq!(
+        format!("{}", m.format_with(|f, x| write!(f, "{}", x))),
+        "\n ┌    ┐\n └    ┘\n"
+    );
+}
@@ -0,0 +1,140 @@
+#![cfg(feature = "testutils")]
+
+use std::f64::consts::FRAC_PI_2;
+
+use vectrix::testutils::assert_close;
+use vectrix::{
+    vector, Affine, EulerOrder, Isometry2, Isometry3, Matrix, Rotation2, Rotation3, Vector,
+};
+
+#[test]
+fn isometry_identity() {
+    let iso = Isometry3::<f64>::identity();
+    assert_eq!(iso.rotation(), &Rotation3::identity());
+    assert_eq!(iso.translation(), &Vector::zero());
+    assert_eq!(
+        iso.transform_point(&vector![1.0, 2.0, 3.0]),
+        vector![1.0, 2.0, 3.0]
+    );
+}
+
+#[test]
+fn isometry_transform() {
+    let iso = Isometry2::new(Rotation2::from_angle(FRAC_PI_2), vector![10.0, 20.0]);
+    assert_close(
+        &iso.transform_point(&vector![1.0, 0.0]),
+        &vector![10.0, 21.0],
+        1e-12,
+    );
+    assert_close(
+        &iso.transform_vector(&vector![1.0, 0.0]),
+        &vector![0.0, 1.0],
+        1e-12,
+    );
+    let t = Isometry2::from_translation(vector![5.0, 6.0]);
+    assert_eq!(t.transform_point(&vector![1.0, 1.0]), vector![6.0, 7.0]);
+    assert_eq!(t.transform_vector(&vector![1.0, 1.0]), vector![1.0, 1.0]);
+    let r = Isometry2::from_rotation(Rotation2::from_angle(FRAC_PI_2));
+    assert_close(
+        &r.transform_point(&vector![1.0, 2.0]),
+        &vector![-2.0, 1.0],
+        1e-12,
+    );
+}
+
+#[test]
+fn isometry_inverse_and_compose() {
+    let a = Isometry3::new(
+        Rotation3::from_euler_angles(EulerOrder::XYZ, 0.3, -0.2, 1.1),
+        vector![1.0, -2.0, 3.0],
+    );
+    let b = Isometry3::new(
+        Rotation3::from_euler_angles(EulerOrder::ZYX, -1.0, 0.5, 0.1),
+        vector![0.0, 4.0, -1.0],
+    );
+    let p = vector![0.5, 0.25, -2.0];
+    assert_close(
+        &a.inverse().transform_point(&a.transform_point(&p)),
+        &p,
+        1e-12,
+    );
+    assert_close(
+        &(a * b).transform_point(&p),
+        &a.transform_point(&b.transform_point(&p)),
+        1e-12,
+    );
+    let identity = a * a.inverse();
+    assert_close(
+        &identity.rotation().into_inner(),
+        &Matrix::identity(),
+        1e-12,
+    );
+    assert_close(identity.translation(), &Vector::zero(), 1e-12);
+}
+
+#[test]
+fn isometry_conversions() {
+    let iso = Isometry2::new(Rotation2::from_angle(FRAC_PI_2), vector![1.0, 2.0]);
+    let affine = Affine::from(iso);
+    let p = vector![3.0, 4.0];
+    assert_close(&affine.transform_point(&p), &iso.transform_point(&p), 1e-12);
+    let m = Matrix::from(iso);
+    let q = m * vector![3.0, 4.0, 1.0];
+    assert_close(&vector![q.x, q.y], &iso.transform_point(&p), 1e-12);
+}
+
+#[test]
+fn isometry_exp_log() {
+    for twist in [
+        vector![1.0, -2.0, 0.5, 0.3, 0.2, -0.1],
+        vector![0.5, 0.5, 0.5, 1e-7, 0.0, -1e-7],
+        vector![-1.0, 0.0, 2.0, 0.0, 3.0, 0.0],
+        vector![0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+    ] {
+        let iso = Isometry3::exp(&twist);
+        assert_close(&iso.log(), &twist, 1e-12);
+    }
+}
+
+#[test]
+fn isometry_exp_log_small_angles() {
+    // angles just above the cutoff where the series expansion is used
+    let cutoff = f64::EPSILON.sqrt().sqrt();
+    for theta in [cutoff * 1.01, 1.3e-4, 1e-3, 1e-2] {
+        let axis = vector![1.0, 2.0, 2.0] / 3.0;
+        let twist = vector![
+            1.0,
+            1.0,
+            1.0,
+            axis.x * theta,
+            axis.y * theta,
+            axis.z * theta
+        ];
+        let error = (Isometry3::exp(&twist).log() - twist).l2_norm();
+        assert!(error < 1e-14, "round trip error {error:e} at θ = {theta:e}");
+    }
+}
+
+#[test]
+fn isometry_exp_screw_motion() {
+    // a quarter turn about the z-axis while moving along the x-axis, rotates
+    // the translation half way around the arc
+    let twist = vector![FRAC_PI_2, 0.0, 0.0, 0.0, 0.0, FRAC_PI_2];
+    let iso = Isometry3::exp(&twist);
+    assert_close(
+        &iso.rotation().into_inner(),
+        &Matrix::from_rotation_z(FRAC_PI_2),
+        1e-12,
+    );
+    assert_close(iso.translation(), &vector![1.0, 1.0, 0.0], 1e-12);
+
+    // interpolating the twist gives poses along the same screw motion
+    let half = Isometry3::exp(&(twist * 0.5));
+    let composed = half * half;
+    assert_close(
+        &composed.rotation().into_inner(),
+        &iso.rotation().into_inner(),
+        1e-12,
+    );
+    assert_close(composed.translation(), iso.translation(), 1e-12);
+}
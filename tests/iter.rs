@@ -70,6 +70,17 @@ fn matrix_sum() {
     assert_eq!(matrix, matrix![1, 2; 3, 4]);
 }
 
+#[test]
+fn matrix_product() {
+    let a = matrix![1, 2; 3, 4];
+    let b = matrix![0, 1; 1, 0];
+    let c = matrix![2, 0; 0, 3];
+    let matrix: Matrix<_, 2, 2> = vec![a, b, c].into_iter().product();
+    assert_eq!(matrix, a * b * c);
+    let empty: Matrix<i64, 3, 3> = Vec::new().into_iter().product();
+    assert_eq!(empty, Matrix::identity());
+}
+
 #[test]
 fn matrix_iter_rows() {
     let m = matrix![1, 3; 3, 7];
@@ -131,6 +142,39 @@ fn matrix_iter_rows_mut() {
     assert_eq!(m, matrix![2, 3; 6, 7])
 }
 
+#[test]
+fn matrix_for_each_column() {
+    let m = matrix![1, 3, 5; 3, 7, 9];
+    let mut columns = Vec::new();
+    m.for_each_column(|c| columns.push(*c));
+    assert_eq!(columns, [[1, 3], [3, 7], [5, 9]]);
+}
+
+#[test]
+fn matrix_for_each_column_mut() {
+    let mut m = matrix![1, 3, 5; 3, 7, 9];
+    m.for_each_column_mut(|[a, b]| std::mem::swap(a, b));
+    assert_eq!(m, matrix![3, 7, 9; 1, 3, 5]);
+}
+
+#[test]
+fn matrix_fold_columns() {
+    let m = matrix![1, 3, 5; 3, 7, 9];
+    let dots = m.fold_columns(Vec::new(), |mut acc, &[a, b]| {
+        acc.push(a * b);
+        acc
+    });
+    assert_eq!(dots, [3, 21, 45]);
+    assert_eq!(
+        Matrix::<i64, 2, 0>::zero().fold_columns(7, |acc, _| acc + 1),
+        7
+    );
+    assert_eq!(
+        Matrix::<i64, 0, 3>::zero().fold_columns(7, |acc, _| acc + 1),
+        10
+    );
+}
+
 #[test]
 fn matrix_iter_columns_mut() {
     let mut m = matrix![1, 3; 3, 7];
@@ -139,3 +183,125 @@ fn matrix_iter_columns_mut() {
     }
     assert_eq!(m, matrix![2, 6; 3, 7])
 }
+
+#[test]
+fn matrix_iter_row_major() {
+    let m = matrix![1, 2, 3; 4, 5, 6];
+    let mut iter = m.iter_row_major();
+    assert_eq!(iter.len(), 6);
+    assert_eq!(iter.next(), Some(&1));
+    assert_eq!(iter.next_back(), Some(&6));
+    assert_eq!(iter.len(), 4);
+    assert_eq!(iter.copied().collect::<Vec<_>>(), [2, 3, 4, 5]);
+    assert_eq!(m.iter_row_major().last(), Some(&6));
+    assert_eq!(
+        m.iter_row_major().rev().copied().collect::<Vec<_>>(),
+        [6, 5, 4, 3, 2, 1]
+    );
+}
+
+#[test]
+fn matrix_windows_rows() {
+    let m = matrix![1, 2; 3, 4; 5, 6; 7, 8];
+    let mut iter = m.windows_rows::<2>();
+    assert_eq!(iter.len(), 3);
+    let [a, b] = iter.next().unwrap();
+    assert_eq!((a[0], a[1], b[0], b[1]), (1, 2, 3, 4));
+    let [a, b] = iter.next_back().unwrap();
+    assert_eq!((a[0], b[1]), (5, 8));
+    assert_eq!(iter.len(), 1);
+    let sums: Vec<i64> = m
+        .windows_rows::<3>()
+        .map(|rows| rows.iter().map(|r| r[1]).sum())
+        .collect();
+    assert_eq!(sums, [12, 18]);
+    assert_eq!(m.windows_rows::<4>().count(), 1);
+    assert_eq!(m.windows_rows::<5>().count(), 0);
+    assert_eq!(m.windows_rows::<1>().last().unwrap()[0][0], 7);
+}
+
+#[test]
+#[should_panic(expected = "window size must be non-zero")]
+fn matrix_windows_rows_zero() {
+    let m = matrix![1, 2; 3, 4];
+    let _ = m.windows_rows::<0>();
+}
+
+#[test]
+fn matrix_iter_zero_sized() {
+    let mut m = Matrix::<i64, 0, 2>::zero();
+    assert_eq!(m.iter().count(), 0);
+    assert_eq!(m.into_iter().count(), 0);
+    assert_eq!(m.iter_rows().count(), 0);
+    assert_eq!(m.iter_rows_mut().count(), 0);
+    assert_eq!(m.iter_columns().count(), 2);
+    assert!(m.iter_columns().all(|col| col.is_empty()));
+    assert_eq!(m.windows_rows::<1>().count(), 0);
+
+    let mut m = Matrix::<i64, 2, 0>::zero();
+    assert_eq!(m.iter().count(), 0);
+    assert_eq!(m.into_iter().count(), 0);
+    assert_eq!(m.iter_columns().count(), 0);
+    assert_eq!(m.iter_columns_mut().count(), 0);
+    assert_eq!(m.iter_rows().count(), 2);
+    assert!(m.iter_rows().all(|row| row.is_empty()));
+    assert_eq!(m.iter_rows_mut().count(), 2);
+    assert_eq!(m.iter_row_major().count(), 0);
+    assert_eq!(m.windows_rows::<2>().count(), 1);
+}
+
+#[test]
+fn matrix_indices() {
+    let m = matrix![1, 2, 3; 4, 5, 6];
+    let mut iter = m.indices();
+    assert_eq!(iter.len(), 6);
+    assert_eq!(iter.next(), Some((0, 0)));
+    assert_eq!(iter.next(), Some((1, 0)));
+    assert_eq!(iter.next_back(), Some((1, 2)));
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.collect::<Vec<_>>(), [(0, 1), (1, 1), (0, 2)]);
+    assert!(m.indices().zip(m.iter()).all(|(i, &x)| m[i] == x));
+    assert_eq!(Matrix::<i64, 0, 3>::zero().indices().count(), 0);
+}
+
+#[test]
+fn matrix_indices_row_major() {
+    let m = matrix![1, 2, 3; 4, 5, 6];
+    let mut iter = m.indices_row_major();
+    assert_eq!(iter.next(), Some((0, 0)));
+    assert_eq!(iter.next_back(), Some((1, 2)));
+    assert_eq!(iter.last(), Some((1, 1)));
+    assert!(m
+        .indices_row_major()
+        .zip(m.iter_row_major())
+        .all(|(i, &x)| m[i] == x));
+}
+
+#[test]
+fn matrix_iter_indexed() {
+    let m = matrix![1, 2, 3; 4, 5, 6];
+    let mut iter = m.iter_indexed();
+    assert_eq!(iter.len(), 6);
+    assert_eq!(iter.next(), Some(((0, 0), &1)));
+    assert_eq!(iter.next(), Some(((1, 0), &4)));
+    assert!(m.iter_indexed().all(|(i, &x)| m[i] == x));
+    assert_eq!(Matrix::<i64, 3, 0>::zero().iter_indexed().count(), 0);
+}
+
+#[test]
+fn matrix_iter_indexed_mut() {
+    let mut m = Matrix::<usize, 2, 3>::zero();
+    for ((i, j), x) in m.iter_indexed_mut() {
+        *x = 10 * i + j;
+    }
+    assert_eq!(m, matrix![0, 1, 2; 10, 11, 12]);
+}
+
+#[test]
+fn matrix_into_iter_indexed() {
+    let m = matrix![String::from("a"), String::from("b")];
+    assert_eq!(
+        m.into_iter_indexed().collect::<Vec<_>>(),
+        [((0, 0), String::from("a")), ((0, 1), String::from("b"))]
+    );
+}
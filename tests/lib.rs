@@ -1,4 +1,95 @@
-use vectrix::{matrix, vector, Matrix};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::mem::{align_of, size_of};
+
+use vectrix::{matrix, row_vector, vector, Column, Error, Matrix, RowVector};
+
+fn hash<T: Hash>(t: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    t.hash(&mut hasher);
+    hasher.finish()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Layout
+////////////////////////////////////////////////////////////////////////////////
+
+struct AssertLayout<T, const M: usize, const N: usize>(T);
+
+impl<T, const M: usize, const N: usize> AssertLayout<T, M, N> {
+    const OK: () = {
+        assert!(size_of::<Matrix<T, M, N>>() == M * N * size_of::<T>());
+        assert!(align_of::<Matrix<T, M, N>>() == align_of::<T>());
+    };
+}
+
+#[test]
+fn matrix_layout_generic() {
+    let () = AssertLayout::<u8, 3, 5>::OK;
+    let () = AssertLayout::<u64, 1, 7>::OK;
+    let () = AssertLayout::<(u8, u16), 4, 2>::OK;
+    let () = AssertLayout::<String, 2, 2>::OK;
+    let () = AssertLayout::<f32, 0, 9>::OK;
+}
+
+#[test]
+fn matrix_layout_element_offsets() {
+    let m = matrix![1, 2, 3; 4, 5, 6];
+    let base = m.as_slice().as_ptr();
+    for i in 0..2 {
+        for j in 0..3 {
+            let elem: *const i64 = &m[(i, j)];
+            assert_eq!(elem, base.wrapping_add(j * 2 + i));
+        }
+    }
+    let ptr = &m as *const Matrix<i64, 2, 3> as *const [i64; 6];
+    assert_eq!(unsafe { *ptr }, [1, 4, 2, 5, 3, 6]);
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Equality and hashing
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn matrix_eq() {
+    let m = matrix![1, 3; 3, 7];
+    assert_eq!(m, matrix![1, 3; 3, 7]);
+    assert_ne!(m, matrix![1, 3; 3, 8]);
+    assert_ne!(matrix![f64::NAN], matrix![f64::NAN]);
+}
+
+#[test]
+fn matrix_hash() {
+    let m = matrix![1, 3; 3, 7];
+    assert_eq!(hash(&m), hash(&matrix![1, 3; 3, 7]));
+    assert_ne!(hash(&m), hash(&matrix![1, 3; 7, 3]));
+}
+
+#[test]
+fn error_display() {
+    assert_eq!(
+        Error::IndexOutOfBounds.to_string(),
+        "an index is out of bounds"
+    );
+    assert_eq!(
+        Error::OverlappingIndices.to_string(),
+        "there were overlapping indices"
+    );
+    assert_eq!(
+        Error::LengthMismatch.to_string(),
+        "the length does not match the matrix size"
+    );
+}
+
+#[test]
+fn error_is_std_error() {
+    fn f() -> Result<(), Box<dyn std::error::Error>> {
+        let mut m = matrix![1, 2; 3, 4];
+        m.get_many_mut([0, 0])?;
+        Ok(())
+    }
+    assert!(f().is_err());
+}
 
 ////////////////////////////////////////////////////////////////////////////////
 // Matrix<T, M, N> methods
@@ -10,6 +101,33 @@ fn matrix_zero() {
     assert_eq!(m, matrix![0, 0; 0, 0]);
 }
 
+#[test]
+fn matrix_zero_const() {
+    const Z: Matrix<i64, 2, 3> = Matrix::ZERO;
+    static S: Matrix<f64, 3, 1> = Matrix::ZERO;
+    assert_eq!(Z, Matrix::zero());
+    assert_eq!(S, Matrix::zero());
+    assert!(Matrix::<u8, 0, 2>::ZERO.is_empty());
+}
+
+#[test]
+fn matrix_const_accessors() {
+    const M: Matrix<i64, 2, 3> = Matrix::from_column_major_order([[1, 4], [2, 5], [3, 6]]);
+    const SLICE: &[i64] = M.as_slice();
+    const LEN: usize = M.len();
+    const SHAPE: (usize, usize) = M.shape();
+    const SECOND: Option<&i64> = M.get_nth(1);
+    const OUT: Option<&i64> = M.get_nth(6);
+    const COLUMN: &Column<i64, 2, 3> = M.column(2);
+    assert_eq!(SLICE, &[1, 4, 2, 5, 3, 6]);
+    assert_eq!(LEN, 6);
+    assert_eq!(SHAPE, (2, 3));
+    assert_eq!(SECOND, Some(&4));
+    assert_eq!(OUT, None);
+    assert_eq!(COLUMN, M.column(2));
+    assert_eq!(Matrix::<i64, 0, 3>::zero().len(), 0);
+}
+
 #[test]
 fn matrix_repeat() {
     let m = Matrix::repeat(7);
@@ -69,6 +187,42 @@ fn matrix_iter_mut() {
     assert_eq!(m, matrix![1, 3; 3, 7]);
 }
 
+#[test]
+fn matrix_get_many_mut() {
+    let mut m = matrix![1, 2; 3, 4];
+    let [a, b, c] = m.get_many_mut([0, 3, 2]).unwrap();
+    *a = 7;
+    *b = 8;
+    *c = 9;
+    assert_eq!(m, matrix![7, 9; 3, 8]);
+}
+
+#[test]
+fn matrix_get_many_mut_tuple() {
+    let mut m = matrix![1, 2; 3, 4];
+    let [a, b] = m.get_many_mut([(0, 1), (1, 0)]).unwrap();
+    std::mem::swap(a, b);
+    assert_eq!(m, matrix![1, 3; 2, 4]);
+}
+
+#[test]
+fn matrix_get_many_mut_overlapping() {
+    let mut m = matrix![1, 2; 3, 4];
+    assert_eq!(m.get_many_mut([1, 2, 1]), Err(Error::OverlappingIndices));
+    assert_eq!(
+        m.get_many_mut([(0, 1), (0, 1)]),
+        Err(Error::OverlappingIndices)
+    );
+}
+
+#[test]
+fn matrix_get_many_mut_out_of_bounds() {
+    let mut m = matrix![1, 2; 3, 4];
+    assert_eq!(m.get_many_mut([0, 4]), Err(Error::IndexOutOfBounds));
+    assert_eq!(m.get_many_mut([(2, 0)]), Err(Error::IndexOutOfBounds));
+    assert_eq!(m.get_many_mut([(0, 2)]), Err(Error::IndexOutOfBounds));
+}
+
 #[test]
 fn matrix_row() {
     let m = matrix![1, 3; -3, 7];
@@ -90,6 +244,73 @@ fn matrix_row_mut() {
     assert_eq!(m, matrix![1, 3; -3, 7]);
 }
 
+#[test]
+#[should_panic(expected = "row index 2 out of range for matrix with 2 rows")]
+fn matrix_row_out_of_bounds() {
+    let m = matrix![1, 3; -3, 7];
+    m.row(2);
+}
+
+#[test]
+fn matrix_rows_many_mut() {
+    let mut m = matrix![1, 2; 3, 4; 5, 6];
+    let [a, b] = m.rows_many_mut([2, 0]).unwrap();
+    for (x, y) in a.iter_mut().zip(b.iter_mut()) {
+        std::mem::swap(x, y);
+    }
+    assert_eq!(m, matrix![5, 6; 3, 4; 1, 2]);
+}
+
+#[test]
+fn matrix_rows_many_mut_invalid() {
+    let mut m = matrix![1, 2; 3, 4; 5, 6];
+    assert!(matches!(
+        m.rows_many_mut([1, 1]),
+        Err(Error::OverlappingIndices)
+    ));
+    assert!(matches!(
+        m.rows_many_mut([0, 3]),
+        Err(Error::IndexOutOfBounds)
+    ));
+}
+
+#[test]
+fn matrix_rows_mut() {
+    let mut m = matrix![1, 2; 3, 4; 5, 6];
+    let (a, b) = m.rows_mut(2, 0);
+    a[0] = 7;
+    b[1] = 8;
+    assert_eq!(m, matrix![1, 8; 3, 4; 7, 6]);
+}
+
+#[test]
+#[should_panic(expected = "rows must be different, both are 1")]
+fn matrix_rows_mut_same() {
+    let mut m = matrix![1, 2; 3, 4];
+    m.rows_mut(1, 1);
+}
+
+#[test]
+#[should_panic(expected = "row index 2 out of range for matrix with 2 rows")]
+fn matrix_rows_mut_out_of_bounds() {
+    let mut m = matrix![1, 2; 3, 4];
+    m.rows_mut(0, 2);
+}
+
+#[test]
+fn matrix_row_into_iter() {
+    let mut m = matrix![1, 2, 3; 4, 5, 6];
+    let mut sum = 0;
+    for x in m.row(1) {
+        sum += x;
+    }
+    assert_eq!(sum, 15);
+    for x in m.row_mut(0) {
+        *x *= 10;
+    }
+    assert_eq!(m, matrix![10, 20, 30; 4, 5, 6]);
+}
+
 #[test]
 fn matrix_column() {
     let m = matrix![1, 3; -3, 7];
@@ -101,6 +322,17 @@ fn matrix_column() {
     assert_eq!(vec, &[&3, &7]);
 }
 
+#[test]
+fn matrix_column_into_iter() {
+    let mut m = matrix![1, 2, 3; 4, 5, 6];
+    let vec: Vec<_> = m.column(2).into_iter().copied().collect();
+    assert_eq!(vec, [3, 6]);
+    for x in m.column_mut(0) {
+        *x = -*x;
+    }
+    assert_eq!(m, matrix![-1, 2, 3; -4, 5, 6]);
+}
+
 #[test]
 fn matrix_column_mut() {
     let mut m = matrix![2, 3; -3, 8];
@@ -111,6 +343,345 @@ fn matrix_column_mut() {
     assert_eq!(m, matrix![1, 3; -3, 7]);
 }
 
+#[test]
+fn matrix_columns_mut() {
+    let mut m = matrix![1, 2, 3; 4, 5, 6];
+    let (a, b) = m.columns_mut(2, 0);
+    a[0] = 7;
+    b[1] = 8;
+    assert_eq!(m, matrix![1, 2, 7; 8, 5, 6]);
+
+    let (a, b) = m.columns_mut(0, 1);
+    a[0] = 9;
+    b[1] = 0;
+    assert_eq!(m, matrix![9, 2, 7; 8, 0, 6]);
+}
+
+#[test]
+#[should_panic(expected = "columns must be different, both are 0")]
+fn matrix_columns_mut_same() {
+    let mut m = matrix![1, 2; 3, 4];
+    m.columns_mut(0, 0);
+}
+
+#[test]
+#[should_panic(expected = "column index 3 out of range for matrix with 3 columns")]
+fn matrix_columns_mut_out_of_bounds() {
+    let mut m = matrix![1, 2, 3; 4, 5, 6];
+    m.columns_mut(3, 0);
+}
+
+#[test]
+fn matrix_shift_rows_up() {
+    let mut m = matrix![1, 10; 2, 20; 3, 30];
+    assert_eq!(m.shift_rows_up(row_vector![4, 40]), row_vector![1, 10]);
+    assert_eq!(m.shift_rows_up(row_vector![5, 50]), row_vector![2, 20]);
+    assert_eq!(m, matrix![3, 30; 4, 40; 5, 50]);
+}
+
+#[test]
+fn matrix_shift_rows_up_not_copy() {
+    let mut m = Matrix::<String, 2, 1>::repeat_with(String::new);
+    m.shift_rows_up(RowVector::from([String::from("a")]));
+    let old = m.shift_rows_up(RowVector::from([String::from("b")]));
+    assert_eq!(old[0], "");
+    assert_eq!(m.into_row_major_order(), [["a"], ["b"]]);
+}
+
+#[test]
+fn matrix_shift_rows_up_zero_sized() {
+    let mut m = Matrix::<i64, 0, 2>::zero();
+    assert_eq!(m.shift_rows_up(row_vector![1, 2]), row_vector![1, 2]);
+    let mut m = Matrix::<i64, 2, 0>::zero();
+    assert!(m.shift_rows_up(RowVector::zero()).is_empty());
+}
+
+#[test]
+fn matrix_rotate_rows() {
+    let mut m = matrix![1, 2; 3, 4; 5, 6];
+    m.rotate_rows(0);
+    assert_eq!(m, matrix![1, 2; 3, 4; 5, 6]);
+    m.rotate_rows(2);
+    assert_eq!(m, matrix![5, 6; 1, 2; 3, 4]);
+    m.rotate_rows(4);
+    assert_eq!(m, matrix![1, 2; 3, 4; 5, 6]);
+    let mut m = Matrix::<i64, 0, 3>::zero();
+    m.rotate_rows(1);
+}
+
+#[test]
+fn matrix_from_array() {
+    let m = Matrix::<i64, 2, 3>::from_array([1, 2, 3, 4, 5, 6]);
+    assert_eq!(m, matrix![1, 3, 5; 2, 4, 6]);
+    let m = Matrix::<String, 1, 2>::from_array([String::from("a"), String::from("b")]);
+    assert_eq!(m[1], "b");
+    let m = Matrix::<u8, 0, 4>::from_array([]);
+    assert!(m.is_empty());
+}
+
+#[test]
+fn matrix_into_array() {
+    let m = matrix![1, 3, 5; 2, 4, 6];
+    assert_eq!(m.into_array(), [1, 2, 3, 4, 5, 6]);
+    let m = Matrix::<String, 2, 1>::from_array([String::from("a"), String::from("b")]);
+    assert_eq!(m.into_array(), [String::from("a"), String::from("b")]);
+}
+
+#[test]
+fn matrix_as_array() {
+    let mut m = matrix![1, 3; 2, 4];
+    assert_eq!(m.as_array(), &[1, 2, 3, 4]);
+    m.as_mut_array::<4>()[3] = 7;
+    assert_eq!(m, matrix![1, 3; 2, 7]);
+}
+
+#[test]
+fn matrix_apply() {
+    let mut m = matrix![String::from("a"), String::from("b"); String::from("c"), String::from("d")];
+    let mut order = Vec::new();
+    m.apply(|s| {
+        order.push(s.clone());
+        s.push('!');
+    });
+    assert_eq!(order, ["a", "c", "b", "d"]);
+    assert_eq!(m[(1, 0)], "c!");
+}
+
+#[test]
+fn matrix_apply_with() {
+    let mut m = Matrix::<usize, 2, 3>::zero();
+    m.apply_with(|i, j, x| *x = 10 * i + j);
+    assert_eq!(m, matrix![0, 1, 2; 10, 11, 12]);
+
+    let mut visited = Vec::new();
+    m.apply_with(|i, j, _| visited.push((i, j)));
+    assert_eq!(visited, [(0, 0), (1, 0), (0, 1), (1, 1), (0, 2), (1, 2)]);
+}
+
+#[test]
+fn matrix_map_with_location() {
+    let m = matrix![1, 2, 3; 4, 5, 6];
+    assert_eq!(
+        m.map_with_location(|i, j, x| (i, j, x)),
+        matrix![(0, 0, 1), (0, 1, 2), (0, 2, 3); (1, 0, 4), (1, 1, 5), (1, 2, 6)]
+    );
+    let mut order = Vec::new();
+    m.map_with_location(|i, j, _| order.push((i, j)));
+    assert_eq!(order, [(0, 0), (1, 0), (0, 1), (1, 1), (0, 2), (1, 2)]);
+    let identity = Matrix::<f64, 3, 3>::zero().map_with_location(|i, j, _| (i == j) as i32 as f64);
+    assert_eq!(identity, Matrix::identity());
+}
+
+#[test]
+fn matrix_zip_map() {
+    let a = matrix![1, 2, 3; 4, 5, 6];
+    let b = matrix![6.0, 5.0, 4.0; 3.0, 2.0, 1.0];
+    assert_eq!(
+        a.zip_map(b, |x, y| (x, y)),
+        matrix![(1, 6.0), (2, 5.0), (3, 4.0); (4, 3.0), (5, 2.0), (6, 1.0)]
+    );
+    assert_eq!(
+        a.zip_map(b, |x, y| x as f64 * y),
+        matrix![6.0, 10.0, 12.0; 12.0, 10.0, 6.0]
+    );
+}
+
+#[test]
+fn matrix_zip_with() {
+    let a = matrix![String::from("a"), String::from("b")];
+    let b = matrix![1, 2];
+    assert_eq!(
+        a.zip_with(&b, |s, &n| s.repeat(n)),
+        matrix![String::from("a"), String::from("bb")]
+    );
+    let mut order = Vec::new();
+    let c = matrix![1, 2; 3, 4];
+    c.zip_with(&c, |&x, &y| order.push(x + y));
+    assert_eq!(order, [2, 6, 4, 8]);
+}
+
+#[test]
+fn matrix_fold() {
+    let m = matrix![1, 2, 3; 4, 5, 6];
+    let order = m.fold(Vec::new(), |mut acc, &x| {
+        acc.push(x);
+        acc
+    });
+    assert_eq!(order, [1, 4, 2, 5, 3, 6]);
+}
+
+#[test]
+fn matrix_sum_product() {
+    let m = matrix![1.5, 2.0; -3.0, 4.0];
+    assert_eq!(m.sum(), 4.5);
+    assert_eq!(m.product(), -36.0);
+    let empty = Matrix::<i64, 0, 3>::zero();
+    assert_eq!(empty.sum(), 0);
+    assert_eq!(empty.product(), 1);
+}
+
+#[test]
+fn matrix_sum_rows_columns() {
+    let m = matrix![1, 2, 3; 4, 5, 6];
+    assert_eq!(m.sum_rows(), vector![6, 15]);
+    assert_eq!(m.sum_columns(), row_vector![5, 7, 9]);
+    let empty = Matrix::<i64, 2, 0>::zero();
+    assert_eq!(empty.sum_rows(), vector![0, 0]);
+    assert_eq!(empty.sum_columns(), Matrix::zero());
+}
+
+#[test]
+fn matrix_mean_rows_columns() {
+    let m = matrix![1.0, 2.0; -3.0, 5.0; 8.0, 0.0];
+    assert_eq!(m.mean_rows(), vector![1.5, 1.0, 4.0]);
+    assert_eq!(m.mean_columns(), row_vector![2.0, 7.0 / 3.0]);
+    assert!(Matrix::<f64, 2, 0>::zero()
+        .mean_rows()
+        .iter()
+        .all(|x| x.is_nan()));
+}
+
+#[test]
+fn matrix_min_max_element() {
+    let m = matrix![3, -1, 7; 2, 7, -1];
+    assert_eq!(m.min_element(), Some(-1));
+    assert_eq!(m.max_element(), Some(7));
+    assert_eq!(m.argmin(), Some((0, 1)));
+    assert_eq!(m.argmax(), Some((1, 1)));
+    let empty = Matrix::<i64, 0, 2>::zero();
+    assert_eq!(empty.min_element(), None);
+    assert_eq!(empty.argmax(), None);
+}
+
+#[test]
+fn matrix_min_max_element_nan() {
+    let m = matrix![1.0, f64::NAN; -2.0, 4.0];
+    assert_eq!(m.argmin(), Some((1, 0)));
+    assert_eq!(m.argmax(), Some((1, 1)));
+    assert_eq!(m.argmin_total(), Some((1, 0)));
+    assert_eq!(m.argmax_total(), Some((0, 1)));
+    assert!(m.max_element_total().unwrap().is_nan());
+    assert_eq!(m.min_element_total(), Some(-2.0));
+    assert_eq!((-m).argmin_total(), Some((0, 1)));
+    let z = matrix![0.0, -0.0];
+    assert_eq!(z.argmin(), Some((0, 0)));
+    assert_eq!(z.argmin_total(), Some((0, 1)));
+    assert_eq!(Matrix::<f64, 2, 0>::zero().max_element_total(), None);
+}
+
+#[test]
+fn matrix_zip_fold() {
+    let a = matrix![1, 2, 3; 4, 5, 6];
+    let b = matrix![6.0, 5.0, 4.0; 3.0, 2.0, 1.0];
+    let order = a.zip_fold(&b, Vec::new(), |mut acc, &x, &y| {
+        acc.push((x, y));
+        acc
+    });
+    assert_eq!(
+        order,
+        [(1, 6.0), (4, 3.0), (2, 5.0), (5, 2.0), (3, 4.0), (6, 1.0)]
+    );
+    let dot = a.zip_fold(&b, 0.0, |acc, &x, &y| acc + x as f64 * y);
+    assert_eq!(dot, 56.0);
+}
+
+#[test]
+fn matrix_zip_fold_empty() {
+    let a = Matrix::<i64, 0, 2>::zero();
+    assert_eq!(a.zip_fold(&a, 7, |acc, x, y| acc + x + y), 7);
+}
+
+#[test]
+fn matrix_clamp_elements() {
+    let m = matrix![-2, 5, 1; 0, 3, 9];
+    assert_eq!(m.clamp_elements(0, 3), matrix![0, 3, 1; 0, 3, 3]);
+    assert_eq!(m.clamp_elements(1, 1), matrix![1, 1, 1; 1, 1, 1]);
+    let m = matrix![f64::NAN, -1.0].clamp_elements(0.0, 1.0);
+    assert!(m[(0, 0)].is_nan());
+    assert_eq!(m[(0, 1)], 0.0);
+}
+
+#[test]
+#[should_panic(expected = "`min` must not be greater than `max`")]
+fn matrix_clamp_elements_invalid() {
+    let _ = matrix![1, 2].clamp_elements(3, 2);
+}
+
+#[test]
+fn matrix_clamp_ord() {
+    let m = matrix![3, -1; 0, 0];
+    let min = matrix![0, 0; 0, 0];
+    let max = matrix![2, 2; 2, 2];
+    assert_eq!(m.clamp(min, max), max);
+}
+
+#[test]
+fn matrix_abs() {
+    assert_eq!(matrix![-1, 2; -3, 0].abs(), matrix![1, 2; 3, 0]);
+    assert_eq!(vector![-1.5, 2.5].abs(), vector![1.5, 2.5]);
+}
+
+#[test]
+fn matrix_signum() {
+    assert_eq!(matrix![-7, 0; 3, 1].signum(), matrix![-1, 0; 1, 1]);
+    let m = matrix![-0.0, f64::NAN, 0.25].signum();
+    assert_eq!(m[(0, 0)], 0.0);
+    assert!(m[(0, 1)].is_nan());
+    assert_eq!(m[(0, 2)], 1.0);
+}
+
+#[test]
+fn matrix_float_math() {
+    let m = matrix![4.0, 0.25; 1.0, 16.0];
+    assert_eq!(m.sqrt(), matrix![2.0, 0.5; 1.0, 4.0]);
+    assert_eq!(m.powf(0.5), m.sqrt());
+    assert_eq!(m.powf(-1.0), m.recip());
+    assert_eq!(m.recip(), matrix![0.25, 4.0; 1.0, 0.0625]);
+    assert!((m.ln().exp_elementwise() - m).frobenius_norm() < 1e-12);
+    assert_eq!(matrix![0.0f32].exp_elementwise(), matrix![1.0]);
+    let m = matrix![-1.0f64, 0.0].sqrt();
+    assert!(m[(0, 0)].is_nan());
+    assert_eq!(matrix![0.0].recip(), matrix![f64::INFINITY]);
+    assert_eq!(matrix![0.0].ln(), matrix![f64::NEG_INFINITY]);
+}
+
+#[test]
+fn matrix_lerp() {
+    let a = matrix![0.0, 2.0; -4.0, 8.0];
+    let b = matrix![1.0, 4.0; 4.0, 0.0];
+    assert_eq!(a.lerp(b, 0.0), a);
+    assert_eq!(a.lerp(b, 1.0), b);
+    assert_eq!(a.lerp(b, 0.5), matrix![0.5, 3.0; 0.0, 4.0]);
+    assert_eq!(a.lerp(b, 2.0), matrix![2.0, 6.0; 12.0, -8.0]);
+}
+
+#[test]
+fn matrix_smoothstep() {
+    let a = matrix![0.0, 2.0; -4.0, 8.0];
+    let b = matrix![1.0, 4.0; 4.0, 0.0];
+    assert_eq!(a.smoothstep(b, 0.0), a);
+    assert_eq!(a.smoothstep(b, 1.0), b);
+    assert_eq!(a.smoothstep(b, 0.5), matrix![0.5, 3.0; 0.0, 4.0]);
+    assert_eq!(a.smoothstep(b, -1.0), a);
+    assert_eq!(a.smoothstep(b, 2.0), b);
+}
+
+#[test]
+fn matrix_move_towards() {
+    let a = vector![1.0, 1.0, 1.0];
+    let b = vector![1.0, 4.0, 5.0];
+    assert_eq!(a.move_towards(&b, 0.0), a);
+    assert_eq!(a.move_towards(&b, 2.5), vector![1.0, 2.5, 3.0]);
+    assert_eq!(a.move_towards(&b, 5.0), b);
+    assert_eq!(b.move_towards(&b, 1.0), b);
+
+    let mut c = a;
+    for _ in 0..3 {
+        c = c.move_towards(&b, 2.0);
+    }
+    assert_eq!(c, b);
+}
+
 #[test]
 fn matrix_l1_norm() {
     let m = matrix![-1, 3; -3, 7];
@@ -126,6 +697,142 @@ fn matrix_l1_norm_vectors() {
     assert_eq!(m.l1_norm(), 14);
 }
 
+#[test]
+fn matrix_l1_norm_float() {
+    let m = matrix![-1.5, 3.0; -3.0, 7.0];
+    assert_eq!(m.l1_norm(), 10.0);
+}
+
+#[test]
+fn matrix_linf_norm() {
+    let m = matrix![-1, 3; -3, 7];
+    assert_eq!(m.linf_norm(), 10);
+
+    let m = matrix![-1.5, 3.0; -3.0, 7.0];
+    assert_eq!(m.linf_norm(), 10.0);
+}
+
+#[test]
+fn matrix_linf_norm_vectors() {
+    let m = matrix![-1, 3, -3, 7];
+    assert_eq!(m.linf_norm(), 14);
+
+    let m = matrix![-1; 3; -3; 7];
+    assert_eq!(m.linf_norm(), 7);
+}
+
+#[test]
+fn matrix_frobenius_norm() {
+    let m = matrix![1.0, -1.0; 3.0, -5.0];
+    assert_eq!(m.frobenius_norm(), 6.0);
+
+    let m = matrix![2.0, 4.0, 4.0];
+    assert_eq!(m.frobenius_norm(), 6.0);
+}
+
+#[test]
+fn matrix_sum_squared_diff() {
+    let a = matrix![1, 2, 3; 4, 5, 6];
+    let b = matrix![0, 2, 5; 4, 8, 6];
+    assert_eq!(a.sum_squared_diff(&b), 14);
+    assert_eq!(b.sum_squared_diff(&a), 14);
+    assert_eq!(a.sum_squared_diff(&a), 0);
+    let a = matrix![1_u8, 9];
+    let b = matrix![5_u8, 7];
+    assert_eq!(a.sum_squared_diff(&b), 20);
+    assert!(matrix![1.0, f64::NAN]
+        .sum_squared_diff(&matrix![1.0, 1.0])
+        .is_nan());
+}
+
+#[test]
+fn matrix_mse() {
+    let a = vector![1.0, 2.0, 3.0, 4.0];
+    let b = vector![1.5, 2.0, 1.0, 4.0];
+    assert_eq!(a.mse(&b), 1.0625);
+    assert!(Matrix::<f64, 0, 0>::zero().mse(&Matrix::zero()).is_nan());
+}
+
+#[test]
+fn matrix_max_abs_diff() {
+    let a = matrix![1.0, -2.0; 3.0, 4.0];
+    let b = matrix![1.5, 2.0; 3.0, 3.0];
+    assert_eq!(a.max_abs_diff(&b), 4.0);
+    let a = matrix![1_u8, 9];
+    let b = matrix![5_u8, 7];
+    assert_eq!(a.max_abs_diff(&b), 4);
+    assert_eq!(Matrix::<i64, 3, 0>::zero().max_abs_diff(&Matrix::zero()), 0);
+}
+
+#[test]
+fn matrix_max_abs_diff_nan() {
+    let b = matrix![1.0, 1.0, 1.0];
+    assert!(matrix![f64::NAN, 1.0, 1.0].max_abs_diff(&b).is_nan());
+    assert!(matrix![5.0, f64::NAN, 1.0].max_abs_diff(&b).is_nan());
+    assert!(matrix![1.0, 1.0, f64::NAN].max_abs_diff(&b).is_nan());
+    assert!(b
+        .max_abs_diff(&matrix![1.0, f64::INFINITY, f64::NAN])
+        .is_nan());
+}
+
+#[test]
+fn matrix_l2_norm() {
+    let m = matrix![
+        3.0, 0.0;
+        4.0, 5.0;
+    ];
+    assert!((m.l2_norm() - 45f64.sqrt()).abs() < 1e-12);
+
+    let m = matrix![
+        2.0_f64, 0.0, 0.0;
+        0.0, -7.0, 0.0;
+    ];
+    assert!((m.l2_norm() - 7.0).abs() < 1e-12);
+
+    let m = matrix![
+        1.0, 2.0;
+        2.0, 4.0;
+        3.0, 6.0;
+    ];
+    assert!((m.l2_norm() - 70f64.sqrt()).abs() < 1e-12);
+}
+
+#[test]
+fn matrix_l2_norm_vectors() {
+    assert_eq!(matrix![3.0, 4.0].l2_norm(), 5.0);
+    assert_eq!(matrix![3.0; 4.0].l2_norm(), 5.0);
+}
+
+#[test]
+fn matrix_is_empty() {
+    assert!(Matrix::<i64, 0, 0>::zero().is_empty());
+    assert!(Matrix::<i64, 0, 3>::zero().is_empty());
+    assert!(Matrix::<i64, 3, 0>::zero().is_empty());
+    assert!(!Matrix::<i64, 1, 1>::zero().is_empty());
+}
+
+#[test]
+fn matrix_zero_sized_rows() {
+    let m = Matrix::<i64, 2, 0>::zero();
+    assert!(m.row(1).is_empty());
+    assert_eq!(m.as_slice(), &[]);
+}
+
+#[test]
+fn matrix_zero_sized_norms() {
+    let m = Matrix::<f64, 0, 3>::zero();
+    assert_eq!(m.l1_norm(), 0.0);
+    assert_eq!(m.linf_norm(), 0.0);
+    assert_eq!(m.l2_norm(), 0.0);
+    assert_eq!(m.frobenius_norm(), 0.0);
+
+    let m = Matrix::<f64, 3, 0>::zero();
+    assert_eq!(m.l1_norm(), 0.0);
+    assert_eq!(m.linf_norm(), 0.0);
+    assert_eq!(m.l2_norm(), 0.0);
+    assert_eq!(m.frobenius_norm(), 0.0);
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Matrix<T, N, N> methods
 ////////////////////////////////////////////////////////////////////////////////
@@ -160,6 +867,52 @@ fn matrix_identity() {
     );
 }
 
+#[test]
+fn matrix_identity_const() {
+    const I: Matrix<i64, 3, 3> = Matrix::IDENTITY;
+    static F: Matrix<f32, 4, 4> = Matrix::IDENTITY;
+    assert_eq!(I, Matrix::identity());
+    assert_eq!(F, Matrix::identity());
+    assert_eq!(
+        Matrix::<bool, 2, 2>::IDENTITY,
+        matrix![true, false; false, true]
+    );
+    assert!(Matrix::<i64, 0, 0>::IDENTITY.is_empty());
+}
+
+#[test]
+fn matrix_identity_zero_sized() {
+    let m = Matrix::<i64, 0, 0>::identity();
+    assert!(m.is_empty());
+    assert_eq!(m.diagonal().as_slice(), &[]);
+}
+
+#[test]
+fn matrix_from_diagonal() {
+    let m = Matrix::from_diagonal(&vector![1.5, -2.0]);
+    assert_eq!(m, matrix![1.5, 0.0; 0.0, -2.0]);
+    assert_eq!(m.diagonal(), vector![1.5, -2.0]);
+    assert!(m.is_diagonal());
+    let m = Matrix::<i64, 0, 0>::from_diagonal(&vector![]);
+    assert!(m.is_empty());
+}
+
+#[test]
+fn matrix_from_diagonal_element() {
+    assert_eq!(
+        Matrix::from_diagonal_element(3),
+        matrix![
+            3, 0, 0;
+            0, 3, 0;
+            0, 0, 3;
+        ]
+    );
+    assert_eq!(
+        Matrix::<i64, 4, 4>::from_diagonal_element(1),
+        Matrix::identity()
+    );
+}
+
 #[test]
 fn matrix_diagonal() {
     let m = matrix![
@@ -169,3 +922,165 @@ fn matrix_diagonal() {
     ];
     assert_eq!(m.diagonal(), vector![1, 2, 3]);
 }
+
+#[test]
+fn matrix_diagonal_mut() {
+    let mut m = matrix![
+        1, 2, 3;
+        4, 5, 6;
+        7, 8, 9;
+    ];
+    let d = m.diagonal_mut::<4>();
+    assert_eq!(d.len(), 3);
+    d[2] = 0;
+    for x in d.iter_mut() {
+        *x *= 10;
+    }
+    assert_eq!(m, matrix![10, 2, 3; 4, 50, 6; 7, 8, 0]);
+
+    let mut m = Matrix::<i64, 1, 1>::zero();
+    assert_eq!(m.diagonal_mut::<2>().len(), 1);
+    let mut m = Matrix::<i64, 0, 0>::zero();
+    assert!(m.diagonal_mut::<1>().is_empty());
+}
+
+#[test]
+fn matrix_set_diagonal() {
+    let mut m = matrix![1, 2; 3, 4];
+    m.set_diagonal(&vector![7, 8]);
+    assert_eq!(m, matrix![7, 2; 3, 8]);
+    assert_eq!(m.diagonal(), vector![7, 8]);
+}
+
+#[test]
+fn matrix_fill_diagonal() {
+    let mut m = Matrix::<f64, 3, 3>::zero();
+    m.fill_diagonal(1.0);
+    assert_eq!(m, Matrix::identity());
+}
+
+#[test]
+fn matrix_block() {
+    let m = matrix![
+        1, 2, 3, 4;
+        5, 6, 7, 8;
+        9, 10, 11, 12;
+    ];
+    let b = m.block::<2, 3>(1, 1);
+    assert_eq!(b[(0, 0)], 6);
+    assert_eq!(b[(1, 2)], 12);
+    assert_eq!(b.get((2, 0)), None);
+    assert_eq!(b.get((0, 3)), None);
+    assert_eq!(b.to_matrix(), matrix![6, 7, 8; 10, 11, 12]);
+    assert_eq!(format!("{:?}", b), "[[6, 10], [7, 11], [8, 12]]");
+    assert_eq!(m.block::<3, 4>(0, 0).to_matrix(), m);
+    assert_eq!(
+        m.block::<0, 0>(3, 4).to_matrix(),
+        Matrix::<i64, 0, 0>::zero()
+    );
+}
+
+#[test]
+#[should_panic(expected = "index (2, 0) out of range for block with 2 rows and 2 columns")]
+fn matrix_block_index_out_of_bounds() {
+    let m = matrix![1, 2, 3; 4, 5, 6; 7, 8, 9];
+    let _ = m.block::<2, 2>(0, 0)[(2, 0)];
+}
+
+#[test]
+#[should_panic(
+    expected = "block of size 2x2 at (2, 0) out of range for matrix with 3 rows and 3 columns"
+)]
+fn matrix_block_out_of_bounds() {
+    let m = matrix![1, 2, 3; 4, 5, 6; 7, 8, 9];
+    let _ = m.block::<2, 2>(2, 0);
+}
+
+#[test]
+fn matrix_fixed_slice() {
+    let m = matrix![
+        1, 2, 3, 4;
+        5, 6, 7, 8;
+        9, 10, 11, 12;
+        13, 14, 15, 16;
+    ];
+    let r: Matrix<_, 3, 3> = m.fixed_slice(0, 0);
+    assert_eq!(r, matrix![1, 2, 3; 5, 6, 7; 9, 10, 11]);
+    let t: Matrix<_, 3, 1> = m.fixed_slice(0, 3);
+    assert_eq!(t, vector![4, 8, 12]);
+}
+
+#[test]
+fn matrix_swap_rows() {
+    let mut m = matrix![1, 2, 3; 4, 5, 6; 7, 8, 9];
+    m.swap_rows(0, 2);
+    assert_eq!(m, matrix![7, 8, 9; 4, 5, 6; 1, 2, 3]);
+    m.swap_rows(1, 1);
+    assert_eq!(m, matrix![7, 8, 9; 4, 5, 6; 1, 2, 3]);
+}
+
+#[test]
+#[should_panic(expected = "row index 3 out of range for matrix with 3 rows")]
+fn matrix_swap_rows_out_of_bounds() {
+    let mut m = matrix![1, 2, 3; 4, 5, 6; 7, 8, 9];
+    m.swap_rows(0, 3);
+}
+
+#[test]
+fn matrix_swap_columns() {
+    let mut m = matrix![1, 2, 3; 4, 5, 6];
+    m.swap_columns(2, 0);
+    assert_eq!(m, matrix![3, 2, 1; 6, 5, 4]);
+}
+
+#[test]
+#[should_panic(expected = "column index 3 out of range for matrix with 3 columns")]
+fn matrix_swap_columns_out_of_bounds() {
+    let mut m = matrix![1, 2, 3; 4, 5, 6];
+    m.swap_columns(3, 0);
+}
+
+#[test]
+fn matrix_swap() {
+    let mut m = matrix![1, 2, 3; 4, 5, 6];
+    m.swap((0, 0), (1, 2));
+    assert_eq!(m, matrix![6, 2, 3; 4, 5, 1]);
+    m.swap((1, 1), (1, 1));
+    assert_eq!(m, matrix![6, 2, 3; 4, 5, 1]);
+}
+
+#[test]
+#[should_panic(expected = "index (2, 0) out of bounds for `Matrix<_, 2, 3>`")]
+fn matrix_swap_out_of_bounds() {
+    let mut m = matrix![1, 2, 3; 4, 5, 6];
+    m.swap((0, 0), (2, 0));
+}
+
+#[test]
+fn matrix_row_vector() {
+    let m = matrix![1, 2, 3; 4, 5, 6];
+    assert_eq!(m.row_vector(0), row_vector![1, 2, 3]);
+    assert_eq!(m.row_vector(1), row_vector![4, 5, 6]);
+    assert_eq!(Matrix::<i64, 2, 0>::zero().row_vector(1), RowVector::zero());
+}
+
+#[test]
+#[should_panic(expected = "row index 2 out of range for matrix with 2 rows")]
+fn matrix_row_vector_out_of_bounds() {
+    let m = matrix![1, 2, 3; 4, 5, 6];
+    let _ = m.row_vector(2);
+}
+
+#[test]
+fn matrix_column_vector() {
+    let m = matrix![1, 2, 3; 4, 5, 6];
+    assert_eq!(m.column_vector(0), vector![1, 4]);
+    assert_eq!(m.column_vector(2), vector![3, 6]);
+}
+
+#[test]
+#[should_panic(expected = "column index 3 out of range for matrix with 3 columns")]
+fn matrix_column_vector_out_of_bounds() {
+    let m = matrix![1, 2, 3; 4, 5, 6];
+    let _ = m.column_vector(3);
+}
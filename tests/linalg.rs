@@ -0,0 +1,363 @@
+use vectrix::{matrix, vector, Matrix, Vector};
+
+////////////////////////////////////////////////////////////////////////////////
+// Orthonormalization
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn matrix_orthonormalize() {
+    let m = matrix![
+        1.0, 1.0, 0.0;
+        1.0, 0.0, 1.0;
+        0.0, 1.0, 1.0;
+    ];
+    let q = m.orthonormalize();
+    let qtq: Matrix<f64, 3, 3> = Matrix::from_iter((0..9).map(|i| {
+        q.column(i % 3)
+            .iter()
+            .zip(q.column(i / 3).iter())
+            .map(|(a, b)| a * b)
+            .sum()
+    }));
+    assert!((qtq - Matrix::identity()).frobenius_norm() < 1e-12);
+}
+
+#[test]
+fn matrix_orthonormalize_with_r() {
+    let m = matrix![
+        12.0, -51.0, 4.0;
+        6.0, 167.0, -68.0;
+        -4.0, 24.0, -41.0;
+    ];
+    let (q, r) = m.orthonormalize_with_r();
+    let exp = matrix![
+        14.0, 21.0, -14.0;
+        0.0, 175.0, -70.0;
+        0.0, 0.0, 35.0;
+    ];
+    assert!((r - exp).frobenius_norm() < 1e-9);
+    assert!((q * r - m).frobenius_norm() < 1e-9);
+}
+
+#[test]
+fn matrix_orthonormalize_dependent_columns() {
+    let m = matrix![
+        1.0, 2.0, 0.0;
+        1.0, 2.0, 1.0;
+        0.0, 0.0, 1.0;
+    ];
+    let (q, r) = m.orthonormalize_with_r();
+    assert_eq!(q.column(1), &[0.0, 0.0, 0.0]);
+    assert_eq!(r[(1, 1)], 0.0);
+    assert!((q * r - m).frobenius_norm() < 1e-12);
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Null space and pseudoinverse
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn matrix_null_space() {
+    let m = matrix![
+        1.0, 2.0, 3.0;
+        4.0, 5.0, 6.0;
+        7.0, 8.0, 9.0;
+    ];
+    let (basis, nullity) = m.null_space();
+    assert_eq!(nullity, 1);
+    let x = basis.column(0);
+    let norm: f64 = x.iter().map(|d| d * d).sum();
+    assert!((norm - 1.0).abs() < 1e-12);
+    assert!((m * basis).frobenius_norm() < 1e-12);
+    assert_eq!(basis.column(1), &[0.0, 0.0, 0.0]);
+    assert_eq!(basis.column(2), &[0.0, 0.0, 0.0]);
+}
+
+#[test]
+fn matrix_null_space_full_rank() {
+    let m = matrix![
+        2.0, 1.0;
+        1.0, 3.0;
+    ];
+    let (basis, nullity) = m.null_space();
+    assert_eq!(nullity, 0);
+    assert_eq!(basis, Matrix::zero());
+}
+
+#[test]
+fn matrix_null_space_wide() {
+    let m = matrix![
+        1.0, 0.0, 0.0, 0.0;
+        0.0, 1.0, 1.0, 0.0;
+    ];
+    let (basis, nullity) = m.null_space();
+    assert_eq!(nullity, 2);
+    assert!((m * basis).frobenius_norm() < 1e-12);
+}
+
+#[test]
+fn matrix_null_space_zero() {
+    let m = Matrix::<f64, 2, 3>::zero();
+    let (basis, nullity) = m.null_space();
+    assert_eq!(nullity, 3);
+    assert_eq!(basis, Matrix::identity());
+}
+
+#[test]
+fn matrix_pseudo_inverse_square() {
+    let m = matrix![
+        4.0, 7.0;
+        2.0, 6.0;
+    ];
+    let exp = matrix![
+        0.6, -0.7;
+        -0.2, 0.4;
+    ];
+    assert!((m.pseudo_inverse() - exp).frobenius_norm() < 1e-12);
+}
+
+#[test]
+fn matrix_pseudo_inverse_tall() {
+    let m = matrix![
+        1.0, 2.0;
+        3.0, 4.0;
+        5.0, 6.0;
+    ];
+    let p = m.pseudo_inverse();
+    assert!((p * m - Matrix::identity()).frobenius_norm() < 1e-12);
+    assert!((m * p * m - m).frobenius_norm() < 1e-12);
+}
+
+#[test]
+fn matrix_pseudo_inverse_wide() {
+    let m = matrix![
+        1.0, 0.0, 2.0;
+        0.0, 1.0, 1.0;
+    ];
+    let p = m.pseudo_inverse();
+    assert!((m * p - Matrix::identity()).frobenius_norm() < 1e-12);
+    assert!((p * m * p - p).frobenius_norm() < 1e-12);
+}
+
+#[test]
+fn matrix_pseudo_inverse_rank_deficient() {
+    let m = matrix![
+        1.0, 2.0;
+        2.0, 4.0;
+    ];
+    let p = m.pseudo_inverse();
+    let exp = matrix![
+        0.04, 0.08;
+        0.08, 0.16;
+    ];
+    assert!((p - exp).frobenius_norm() < 1e-12);
+    assert!((m * p * m - m).frobenius_norm() < 1e-12);
+}
+
+#[test]
+fn matrix_pseudo_inverse_zero() {
+    let m = Matrix::<f64, 2, 3>::zero();
+    assert_eq!(m.pseudo_inverse(), Matrix::zero());
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Matrix exponential
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn matrix_exp_zero() {
+    let m = Matrix::<f64, 3, 3>::zero();
+    assert_eq!(m.exp(), Matrix::identity());
+}
+
+#[test]
+fn matrix_exp_diagonal() {
+    let m = matrix![
+        1.0, 0.0;
+        0.0, -2.0;
+    ];
+    let exp = matrix![
+        1f64.exp(), 0.0;
+        0.0, (-2f64).exp();
+    ];
+    assert!((m.exp() - exp).frobenius_norm() < 1e-12);
+}
+
+#[test]
+fn matrix_exp_rotation() {
+    let t = 2.5_f64;
+    let m = matrix![
+        0.0, -t;
+        t, 0.0;
+    ];
+    let exp = matrix![
+        t.cos(), -t.sin();
+        t.sin(), t.cos();
+    ];
+    assert!((m.exp() - exp).frobenius_norm() < 1e-12);
+}
+
+#[test]
+fn matrix_exp_large_norm() {
+    let m = matrix![
+        10.0, 0.0;
+        0.0, 20.0;
+    ];
+    let e = m.exp();
+    assert!((e[(0, 0)] / 10f64.exp() - 1.0).abs() < 1e-12);
+    assert!((e[(1, 1)] / 20f64.exp() - 1.0).abs() < 1e-12);
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Numerical differentiation
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn matrix_jacobian_linear() {
+    let a = matrix![1.0, -2.0, 0.5; 3.0, 0.0, 4.0];
+    let j = Matrix::jacobian(|x| a * x, &vector![0.3, -1.0, 2.0], 1e-3);
+    assert!((j - a).frobenius_norm() < 1e-12);
+}
+
+#[test]
+fn matrix_jacobian_nonlinear() {
+    // polar to cartesian coordinates
+    let f = |p: Vector<f64, 2>| vector![p.x * p.y.cos(), p.x * p.y.sin()];
+    let (r, theta) = (2.0, 0.7);
+    let j = Matrix::jacobian(f, &vector![r, theta], 1e-6);
+    let exp = matrix![
+        theta.cos(), -r * theta.sin();
+        theta.sin(), r * theta.cos();
+    ];
+    assert!((j - exp).frobenius_norm() < 1e-9);
+}
+
+#[test]
+fn matrix_jacobian_evaluations() {
+    let mut calls = 0;
+    let j: Matrix<f64, 1, 4> = Matrix::jacobian(
+        |x: Vector<f64, 4>| {
+            calls += 1;
+            vector![x.sum()]
+        },
+        &Vector::zero(),
+        0.5,
+    );
+    assert_eq!(calls, 8);
+    assert_eq!(j, matrix![1.0, 1.0, 1.0, 1.0]);
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Nonlinear systems
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn vector_solve_newton() {
+    // x² - 2 = 0 converges quadratically to √2
+    let mut iterations = 0;
+    let x = Vector::solve_newton(
+        |x: Vector<f64, 1>| {
+            iterations += 1;
+            vector![x[0] * x[0] - 2.0]
+        },
+        |x| matrix![2.0 * x[0]],
+        &vector![1.0],
+        1e-14,
+        50,
+    )
+    .unwrap();
+    assert!((x[0] - 2f64.sqrt()).abs() < 1e-14);
+    assert!(iterations < 10);
+}
+
+#[test]
+fn vector_solve_newton_numerical_jacobian() {
+    let f = |v: Vector<f64, 3>| {
+        vector![
+            v.x + v.y + v.z - 6.0,
+            v.x * v.y * v.z - 6.0,
+            v.x * v.x + v.y - 3.0
+        ]
+    };
+    let jacobian = |v| Matrix::jacobian(f, &v, 1e-7);
+    let x = Vector::solve_newton(f, jacobian, &vector![0.8, 2.3, 2.9], 1e-10, 50).unwrap();
+    assert!(f(x).l2_norm() <= 1e-10);
+    assert!((x - vector![1.0, 2.0, 3.0]).l2_norm() < 1e-8);
+}
+
+#[test]
+fn vector_solve_newton_failure() {
+    // singular Jacobian at the starting point
+    let f = |x: Vector<f64, 1>| vector![x[0] * x[0] + 1.0];
+    let jacobian = |x: Vector<f64, 1>| matrix![2.0 * x[0]];
+    assert_eq!(
+        Vector::solve_newton(f, jacobian, &vector![0.0], 1e-12, 50),
+        None
+    );
+    // no real root, so it never converges
+    assert_eq!(
+        Vector::solve_newton(f, jacobian, &vector![0.5], 1e-12, 50),
+        None
+    );
+    // already converged
+    let x = Vector::solve_newton(|x| x, |_| matrix![1.0], &vector![0.0], 0.0, 0);
+    assert_eq!(x, Some(vector![0.0]));
+}
+
+#[test]
+fn vector_gradient_descent_step() {
+    let r = vector![1.0, -1.0, 2.0];
+    let j = matrix![
+        1.0, 0.0;
+        0.0, 2.0;
+        1.0, 1.0;
+    ];
+    // Jᵀ * r = [3, 0]
+    let dx = Vector::gradient_descent_step(&r, &j, 0.1);
+    assert!((dx - vector![-0.3, 0.0]).l2_norm() < 1e-12);
+}
+
+#[test]
+fn vector_gauss_newton_step() {
+    // fit y = a * t + b to the points exactly, the problem is linear so a
+    // single step from anywhere lands on the solution
+    let residuals =
+        |p: Vector<f64, 2>| vector![p[1] - 1.0, p[0] + p[1] - 3.0, 2.0 * p[0] + p[1] - 5.0];
+    let j = matrix![
+        0.0, 1.0;
+        1.0, 1.0;
+        2.0, 1.0;
+    ];
+    let p = vector![-3.0, 4.0];
+    let dx = Vector::gauss_newton_step(&residuals(p), &j).unwrap();
+    assert!((p + dx - vector![2.0, 1.0]).l2_norm() < 1e-12);
+    // rank deficient
+    let j = matrix![1.0, 2.0; 2.0, 4.0; 3.0, 6.0];
+    assert_eq!(Vector::gauss_newton_step(&vector![1.0, 1.0, 1.0], &j), None);
+}
+
+#[test]
+fn vector_levenberg_marquardt_step() {
+    let j = matrix![
+        1.0, 0.0;
+        1.0, 1.0;
+        1.0, 2.0;
+    ];
+    let r = vector![-1.0, -3.0, -5.0];
+    // approaches the Gauss–Newton step as lambda goes to zero
+    let gn = Vector::gauss_newton_step(&r, &j).unwrap();
+    let lm = Vector::levenberg_marquardt_step(&r, &j, 1e-9).unwrap();
+    assert!((lm - gn).l2_norm() < 1e-6);
+    // approaches the scaled gradient descent step as lambda grows
+    let lambda = 1e9;
+    let lm = Vector::levenberg_marquardt_step(&r, &j, lambda).unwrap();
+    let gd = Vector::gradient_descent_step(&r, &j, 1.0 / lambda);
+    assert!((lm - gd).l2_norm() < 1e-15);
+    // singular without damping
+    let j = matrix![1.0, 1.0; 1.0, 1.0];
+    assert_eq!(
+        Vector::levenberg_marquardt_step(&vector![1.0, 1.0], &j, 0.0),
+        None
+    );
+    assert!(Vector::levenberg_marquardt_step(&vector![1.0, 1.0], &j, 0.5).is_some());
+}
@@ -0,0 +1,55 @@
+use vectrix::{matrix, vector, Matrix};
+
+#[test]
+fn matrix_comparisons() {
+    let a = matrix![1, 5, 3; 4, 2, 6];
+    let b = matrix![2, 5, 1; 4, 3, 0];
+    assert_eq!(
+        a.lt_mask(&b),
+        matrix![true, false, false; false, true, false]
+    );
+    assert_eq!(a.le_mask(&b), matrix![true, true, false; true, true, false]);
+    assert_eq!(
+        a.gt_mask(&b),
+        matrix![false, false, true; false, false, true]
+    );
+    assert_eq!(a.ge_mask(&b), matrix![false, true, true; true, false, true]);
+    assert_eq!(
+        a.eq_mask(&b),
+        matrix![false, true, false; true, false, false]
+    );
+    // the derived comparisons are still lexicographic
+    assert!(a < b);
+    assert!(a.lt(&b));
+    assert!(!a.ge(&b));
+}
+
+#[test]
+fn matrix_comparisons_nan() {
+    let a = vector![f64::NAN, 1.0];
+    let b = vector![0.0, f64::NAN];
+    assert!(!a.lt_mask(&b).any());
+    assert!(!a.ge_mask(&b).any());
+    assert!(!a.eq_mask(&a).all());
+}
+
+#[test]
+fn matrix_mask_reductions() {
+    let mask = matrix![true, false; true, true];
+    assert!(mask.any());
+    assert!(!mask.all());
+    assert_eq!(mask.count_true(), 3);
+    let empty = Matrix::<bool, 0, 3>::repeat(false);
+    assert!(!empty.any());
+    assert!(empty.all());
+    assert_eq!(empty.count_true(), 0);
+}
+
+#[test]
+fn matrix_select() {
+    let a = matrix![1, 2; 3, 4];
+    let b = matrix![10, 20; 30, 40];
+    let mask = matrix![true, false; false, true];
+    assert_eq!(Matrix::select(&mask, &a, &b), matrix![1, 20; 30, 4]);
+    assert_eq!(Matrix::select(&a.lt_mask(&b), &b, &a), b);
+}
@@ -1,4 +1,4 @@
-use vectrix::{matrix, Matrix};
+use vectrix::{matrix, row_vector, vector, Error, Matrix, RowVector};
 
 #[test]
 fn matrix_macro_const() {
@@ -16,6 +16,111 @@ fn matrix_macro_vector() {
     let _m: Matrix<i64, 4, 1> = matrix![1; 3; 3; 7];
 }
 
+#[test]
+fn matrix_macro_trailing_punctuation() {
+    let m = matrix![1, 2,; 3, 4,;];
+    assert_eq!(m, matrix![1, 2; 3, 4]);
+    let m = matrix![1, 2, 3,];
+    assert_eq!(m, matrix![1, 2, 3]);
+}
+
+#[test]
+fn matrix_macro_comments() {
+    let m = matrix![
+        // the first row
+        1, 2; /* inline */ 3, 4;
+        // the last row
+        5, /* six */ 6;
+    ];
+    assert_eq!(m, matrix![1, 2; 3, 4; 5, 6]);
+}
+
+#[test]
+fn matrix_macro_cfg_rows() {
+    let m = matrix![
+        1, 2;
+        #[cfg(not(test))]
+        3, 4;
+        #[cfg(test)]
+        5, 6;
+    ];
+    assert_eq!(m, matrix![1, 2; 5, 6]);
+}
+
+#[test]
+fn matrix_macro_ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
+
+#[test]
+fn matrix_macro_expressions() {
+    let p = matrix![1, 2; 3, 4];
+    let m = matrix![p[(0, 0)] + 1, -p[1]; p.row(1)[0], { p[3] * 2 }];
+    assert_eq!(m, matrix![2, -3; 3, 8]);
+}
+
+#[test]
+fn matrix_macro_array_elements() {
+    let m: Matrix<[i32; 2], 1, 2> = matrix![[1, 2], [3, 4]];
+    assert_eq!(m[(0, 1)], [3, 4]);
+}
+
+#[test]
+fn matrix_macro_rows() {
+    let m = matrix![@rows [[1, 2, 3], [4, 5, 6]]];
+    assert_eq!(m, matrix![1, 2, 3; 4, 5, 6]);
+    let m = matrix![@rows [[1, 2, 3]]];
+    assert_eq!(m, matrix![1, 2, 3]);
+    let m = matrix![@rows [[1], [2], [3]]];
+    assert_eq!(m, matrix![1; 2; 3]);
+    let data = [[1.5, 2.5], [3.5, 4.5]];
+    assert_eq!(matrix![@rows data], matrix![1.5, 2.5; 3.5, 4.5]);
+}
+
+#[test]
+fn matrix_macro_rows_const() {
+    const DATA: [[i64; 3]; 2] = [[1, 3, 3], [7, 0, 1]];
+    const MATRIX: Matrix<i64, 2, 3> = matrix![@rows DATA];
+    assert_eq!(MATRIX, matrix![1, 3, 3; 7, 0, 1]);
+    const EMPTY: Matrix<i64, 2, 0> = matrix![@rows [[], []]];
+    assert!(EMPTY.is_empty());
+}
+
+#[test]
+fn matrix_macro_repeat() {
+    let m = matrix![1.5; 2, 3];
+    assert_eq!(m, Matrix::<f64, 2, 3>::repeat(1.5));
+    let m: Matrix<i64, 0, 4> = matrix![0; 0, 4];
+    assert!(m.is_empty());
+}
+
+#[test]
+fn matrix_macro_repeat_const() {
+    const N: usize = 3;
+    const MATRIX: Matrix<i64, N, { N + 1 }> = matrix![7; N, N + 1];
+    assert_eq!(MATRIX, Matrix::repeat(7));
+}
+
+fn repeat_generic<const M: usize, const N: usize>() -> Matrix<u8, M, N> {
+    matrix![1; M, N]
+}
+
+#[test]
+fn matrix_macro_repeat_generic() {
+    assert_eq!(repeat_generic::<2, 5>(), Matrix::repeat(1));
+}
+
+#[test]
+fn matrix_from_column_major_order() {
+    const MATRIX: Matrix<i64, 3, 2> = Matrix::from_column_major_order([[1, 2, 3], [4, 5, 6]]);
+    assert_eq!(MATRIX, matrix![1, 4; 2, 5; 3, 6]);
+    assert_eq!(
+        MATRIX.column(1).iter().copied().collect::<Vec<_>>(),
+        [4, 5, 6]
+    );
+}
+
 #[test]
 fn matrix_default() {
     let m = Matrix::default();
@@ -47,3 +152,160 @@ fn matrix_from_iter_long() {
 fn matrix_from_iter_short() {
     let _m = Matrix::<i64, 2, 2>::from_iter(vec![1, 2, 3]);
 }
+
+#[test]
+fn matrix_try_from_iter() {
+    let m = Matrix::<i64, 2, 2>::try_from_iter(vec![1, 2, 3, 4]);
+    assert_eq!(m, Ok(matrix![1, 3; 2, 4]));
+    let mut iter = 1..;
+    let m = Matrix::<i64, 1, 2>::try_from_iter(&mut iter);
+    assert_eq!(m, Ok(matrix![1, 2]));
+    assert_eq!(iter.next(), Some(3));
+}
+
+#[test]
+fn matrix_try_from_iter_short() {
+    let err = Matrix::<i64, 2, 3>::try_from_iter(vec![1, 2, 3]).unwrap_err();
+    assert_eq!((err.yielded(), err.expected()), (3, 6));
+    assert_eq!(
+        err.to_string(),
+        "iterator yielded 3 elements but 6 were expected"
+    );
+    assert_eq!(Error::from(err), Error::LengthMismatch);
+}
+
+#[test]
+fn matrix_try_from_iter_short_drops() {
+    use std::rc::Rc;
+    let rc = Rc::new(());
+    let items = vec![rc.clone(), rc.clone(), rc.clone()];
+    assert_eq!(Rc::strong_count(&rc), 4);
+    assert!(Matrix::<Rc<()>, 2, 2>::try_from_iter(items).is_err());
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn matrix_from_column_slice() {
+    let m = Matrix::<i64, 3, 2>::from_column_slice(&[1, 2, 3, 4, 5, 6]);
+    assert_eq!(m, Ok(matrix![1, 4; 2, 5; 3, 6]));
+    let m = Matrix::<i64, 0, 3>::from_column_slice(&[]);
+    assert!(m.unwrap().is_empty());
+}
+
+#[test]
+fn matrix_from_column_slice_wrong_length() {
+    let m = Matrix::<i64, 2, 2>::from_column_slice(&[1, 2, 3]);
+    assert_eq!(m, Err(Error::LengthMismatch));
+    let m = Matrix::<i64, 2, 2>::from_column_slice(&[1, 2, 3, 4, 5]);
+    assert_eq!(m, Err(Error::LengthMismatch));
+}
+
+#[test]
+fn matrix_from_row_slice() {
+    let m = Matrix::<i64, 3, 2>::from_row_slice(&[1, 2, 3, 4, 5, 6]);
+    assert_eq!(m, Ok(matrix![1, 2; 3, 4; 5, 6]));
+    let m = Matrix::<i64, 1, 3>::from_row_slice(&[1, 2]);
+    assert_eq!(m, Err(Error::LengthMismatch));
+}
+
+#[test]
+fn matrix_try_from_slice() {
+    let data = vec![1.0, 2.0, 3.0, 4.0];
+    let m = Matrix::<f64, 2, 2>::try_from(data.as_slice());
+    assert_eq!(m, Ok(matrix![1.0, 3.0; 2.0, 4.0]));
+    let m: Result<Matrix<f64, 3, 3>, _> = data[..].try_into();
+    assert_eq!(m, Err(Error::LengthMismatch));
+}
+
+#[test]
+fn matrix_from_row_major_order() {
+    let m = Matrix::from_row_major_order([[1, 2], [3, 4], [5, 6]]);
+    assert_eq!(m, matrix![1, 2; 3, 4; 5, 6]);
+    let m = Matrix::<i64, 0, 3>::from_row_major_order([]);
+    assert!(m.is_empty());
+    let m = Matrix::<i64, 2, 0>::from_row_major_order([[], []]);
+    assert!(m.is_empty());
+}
+
+#[test]
+fn matrix_from_row_major_order_not_copy() {
+    let m = Matrix::from_row_major_order([
+        [String::from("a"), String::from("b")],
+        [String::from("c"), String::from("d")],
+    ]);
+    assert_eq!(m[(0, 1)], "b");
+    assert_eq!(m[(1, 0)], "c");
+    assert_eq!(
+        m.into_row_major_order(),
+        [["a", "b"], ["c", "d"]].map(|r| r.map(String::from))
+    );
+}
+
+#[test]
+fn matrix_into_row_major_order() {
+    let m = matrix![1, 2, 3; 4, 5, 6];
+    assert_eq!(m.into_row_major_order(), [[1, 2, 3], [4, 5, 6]]);
+    let m = matrix![1; 2; 3];
+    assert_eq!(m.into_row_major_order(), [[1], [2], [3]]);
+    assert_eq!(Matrix::from_row_major_order(m.into_row_major_order()), m);
+}
+
+#[test]
+fn matrix_to_vec() {
+    let m = matrix![1, 2, 3; 4, 5, 6];
+    assert_eq!(m.to_vec(), [1, 4, 2, 5, 3, 6]);
+    assert_eq!(m.to_row_major_vec(), [1, 2, 3, 4, 5, 6]);
+    assert_eq!(m.to_nested_vec(), [[1, 2, 3], [4, 5, 6]]);
+    assert!(Matrix::<i64, 0, 3>::zero().to_nested_vec().is_empty());
+    let empty: Vec<Vec<i64>> = vec![vec![], vec![]];
+    assert_eq!(Matrix::<i64, 2, 0>::zero().to_nested_vec(), empty);
+}
+
+#[test]
+fn matrix_into_vec() {
+    let m = Matrix::from_row_major_order([
+        [String::from("a"), String::from("b")],
+        [String::from("c"), String::from("d")],
+    ]);
+    let v: Vec<String> = m.clone().into();
+    assert_eq!(v, ["a", "c", "b", "d"]);
+    let v: Vec<Vec<String>> = m.into();
+    assert_eq!(v, [["a", "b"], ["c", "d"]]);
+}
+
+#[test]
+fn matrix_from_rows() {
+    let rows = [row_vector![1, 2, 3], row_vector![4, 5, 6]];
+    let m = Matrix::<i64, 2, 3>::from_rows(rows);
+    assert_eq!(m, matrix![1, 2, 3; 4, 5, 6]);
+    let m = Matrix::<i64, 3, 1>::from_rows((1..).map(|x| RowVector::from([x])));
+    assert_eq!(m, matrix![1; 2; 3]);
+}
+
+#[test]
+fn matrix_from_rows_not_copy() {
+    let m = Matrix::<String, 2, 1>::from_rows(
+        ["a", "b"]
+            .into_iter()
+            .map(|s| RowVector::from([String::from(s)])),
+    );
+    assert_eq!(m[(1, 0)], "b");
+}
+
+#[test]
+#[should_panic(expected = "collect iterator of 1 rows into `Matrix<_, 2, 3>`")]
+fn matrix_from_rows_short() {
+    let _ = Matrix::<i64, 2, 3>::from_rows([row_vector![1, 2, 3]]);
+}
+
+#[test]
+fn matrix_from_columns() {
+    let m = Matrix::<_, 2, 3>::from_columns([vector![1, 4], vector![2, 5], vector![3, 6]]);
+    assert_eq!(m, matrix![1, 2, 3; 4, 5, 6]);
+}
+
+#[test]
+#[should_panic(expected = "collect iterator of 2 columns into `Matrix<_, 2, 3>`")]
+fn matrix_from_columns_short() {
+    let _ = Matrix::<_, 2, 3>::from_columns([vector![1, 4], vector![2, 5]]);
+}
@@ -60,6 +60,93 @@ fn matrix_index_tuple() {
     assert_eq!(m[(1, 2)], 6);
 }
 
+#[test]
+#[should_panic(expected = "index (2, 0) out of bounds for `Matrix<_, 2, 3>`")]
+fn matrix_index_tuple_row_out_of_bounds() {
+    let m: Matrix<_, 2, 3> = matrix![
+        1, 3, 5;
+        2, 4, 6;
+    ];
+    let _ = m[(2, 0)];
+}
+
+#[test]
+#[should_panic(expected = "index (0, 3) out of bounds for `Matrix<_, 2, 3>`")]
+fn matrix_index_tuple_column_out_of_bounds() {
+    let mut m: Matrix<_, 2, 3> = matrix![
+        1, 3, 5;
+        2, 4, 6;
+    ];
+    m[(0, 3)] = 7;
+}
+
+#[test]
+#[should_panic(expected = "index (3, 0) out of bounds for `Vector<_, 3>`")]
+fn vector_index_tuple_out_of_bounds() {
+    let m = matrix![1; 2; 3];
+    let _ = m[(3, 0)];
+}
+
+#[test]
+#[should_panic(expected = "index (0, 3) out of bounds for `RowVector<_, 3>`")]
+fn row_vector_index_tuple_out_of_bounds() {
+    let m = matrix![1, 2, 3];
+    let _ = m[(0, 3)];
+}
+
+#[test]
+fn matrix_get_tuple() {
+    let mut m: Matrix<_, 2, 3> = matrix![
+        1, 3, 5;
+        2, 4, 6;
+    ];
+    assert_eq!(m.get((1, 2)), Some(&6));
+    assert_eq!(m.get((2, 0)), None);
+    assert_eq!(m.get_mut((0, 3)), None);
+}
+
+#[test]
+fn matrix_index_range() {
+    let mut m: Matrix<_, 2, 3> = matrix![
+        1, 3, 5;
+        2, 4, 6;
+    ];
+    assert_eq!(&m[0..2], &[[1, 2], [3, 4]]);
+    assert_eq!(&m[1..], &[[3, 4], [5, 6]]);
+    assert_eq!(&m[..1], &[[1, 2]]);
+    assert_eq!(&m[..=1], &[[1, 2], [3, 4]]);
+    assert_eq!(&m[2..=2], &[[5, 6]]);
+    assert_eq!(m[..].len(), 3);
+    assert!(m[3..].is_empty());
+    for column in &mut m[1..] {
+        column[0] = 0;
+    }
+    assert_eq!(m, matrix![1, 0, 0; 2, 4, 6]);
+}
+
+#[test]
+fn matrix_get_range() {
+    let mut m: Matrix<_, 2, 3> = matrix![
+        1, 3, 5;
+        2, 4, 6;
+    ];
+    assert_eq!(m.get(1..3), Some(&[[3, 4], [5, 6]][..]));
+    assert_eq!(m.get(2..4), None);
+    assert_eq!(m.get(..=3), None);
+    assert_eq!(m.get_mut(1..2), Some(&mut [[3, 4]][..]));
+    assert_eq!(unsafe { m.get_unchecked(..1) }, &[[1, 2]]);
+}
+
+#[test]
+#[should_panic(expected = "range end index 4 out of range for slice of length 3")]
+fn matrix_index_range_out_of_bounds() {
+    let m: Matrix<_, 2, 3> = matrix![
+        1, 3, 5;
+        2, 4, 6;
+    ];
+    let _ = &m[1..4];
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Matrix + T
 ////////////////////////////////////////////////////////////////////////////////
@@ -241,6 +328,88 @@ fn matrix_mul_dot_product() {
     for_each_op_assert_eq! { a, *, b, c }
 }
 
+#[test]
+fn matrix_mul_accumulates_left_to_right() {
+    let a = matrix![1e16, 1.0, -1e16];
+    let b = matrix![1.0; 1.0; 1.0];
+    assert_eq!(a * b, matrix![0.0]);
+}
+
+#[test]
+fn matrix_mul_negative_zero() {
+    let a = matrix![-0.0_f64, -0.0];
+    let b = matrix![1.0; 1.0];
+    assert!((a * b)[0].is_sign_negative());
+}
+
+#[test]
+fn matrix_dot_products_negative_zero() {
+    use vectrix::Dot;
+
+    let a = matrix![-0.0_f32, -0.0];
+    let b = matrix![1.0; 1.0];
+    assert!(a.widening_mul(&b)[0].is_sign_negative());
+    assert!(a
+        .row(0)
+        .dot_with_acc::<f64, 1>(b.column(0))
+        .is_sign_negative());
+    assert!(a.dot(&a.map(|x| -x)).is_sign_negative());
+}
+
+#[test]
+fn matrix_checked_mul() {
+    let a = matrix![1, 2, 3; 4, 5, 6];
+    let b = matrix![7, 8; 9, 10; 11, 12];
+    assert_eq!(a.checked_mul(&b), Some(a * b));
+    assert_eq!(
+        Matrix::<u8, 0, 0>::zero().checked_mul(&Matrix::<u8, 0, 0>::zero()),
+        Some(Matrix::zero())
+    );
+}
+
+#[test]
+fn matrix_checked_mul_overflow() {
+    let a = matrix![i64::MAX / 2 + 1, 1; 0, 1];
+    let b = matrix![2, 0; 1, 0];
+    assert_eq!(a.checked_mul(&b), None);
+    let a = matrix![i64::MIN, 1];
+    let b = matrix![-1; 0];
+    assert_eq!(a.checked_mul(&b), None);
+    let a = matrix![200u8, 100];
+    let b = matrix![1; 1];
+    assert_eq!(a.checked_mul(&b), None);
+}
+
+#[test]
+fn matrix_widening_mul() {
+    let a: Matrix<i16, 2, 2> = matrix![i16::MAX, i16::MIN; 1, 2];
+    let b: Matrix<i16, 2, 1> = matrix![i16::MAX; i16::MAX];
+    let c: Matrix<i32, 2, 1> = a.widening_mul(&b);
+    assert_eq!(c, matrix![-32767; 3 * 32767]);
+
+    let a: Matrix<u8, 1, 2> = matrix![255, 1];
+    let b: Matrix<u8, 2, 1> = matrix![255; 255];
+    assert_eq!(a.widening_mul(&b), matrix![65280_u16]);
+}
+
+#[test]
+fn row_dot_with_acc() {
+    let a = matrix![i16::MIN, i16::MIN; 1, 2];
+    let b = matrix![i16::MIN; i16::MIN];
+    assert_eq!(
+        a.row(0).dot_with_acc::<i64, 1>(b.column(0)),
+        2 * 32768 * 32768
+    );
+    assert_eq!(a.row(1).dot_with_acc::<i32, 1>(b.column(0)), -3 * 32768);
+
+    let a = matrix![1e-8_f32, 1.0];
+    let b = matrix![1.0_f32; 1.0];
+    assert_eq!(
+        a.row(0).dot_with_acc::<f64, 1>(b.column(0)),
+        1.0 + 1e-8_f32 as f64
+    );
+}
+
 #[test]
 fn matrix_mul_n_by_n() {
     let a = matrix![1, 2; 4, 5];
@@ -268,6 +437,25 @@ fn matrix_mul_0_by_m() {
     for_each_op_assert_eq! { a, *, b, c }
 }
 
+#[test]
+fn matrix_mul_m_by_0() {
+    let a = Matrix::<_, 2, 0>::zero();
+    let b = Matrix::<_, 0, 3>::zero();
+    let c = matrix![0, 0, 0; 0, 0, 0];
+
+    for_each_op_assert_eq! { a, *, b, c }
+}
+
+#[test]
+fn matrix_add_zero_sized() {
+    let a = Matrix::<i64, 0, 3>::zero();
+    let b = Matrix::<i64, 0, 3>::zero();
+
+    for_each_op_assert_eq! { a, +, b, a }
+    for_each_op_assert_eq! { a, *, 2, a }
+    assert_eq!(-a, a);
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Matrix += Matrix
 ////////////////////////////////////////////////////////////////////////////////
@@ -305,3 +493,66 @@ fn matrix_not() {
     assert_eq!(!a, matrix![-2, 2; -4, 6]);
     assert_eq!(!&a, matrix![-2, 2; -4, 6]);
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// Block matrices
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn matrix_block_add() {
+    let a = matrix![1, 2; 3, 4];
+    let b = matrix![0, 1; 1, 0];
+    let x = matrix![a, b; b, a];
+    let y = matrix![b, a; Matrix::zero(), Matrix::identity()];
+    let exp = matrix![a + b, a + b; b, a + Matrix::identity()];
+    for_each_op_assert_eq! { x, +, y, exp }
+    for_each_op_assert_eq! { x, -, x, Matrix::zero() }
+    assert_eq!(-x, matrix![-a, -b; -b, -a]);
+}
+
+#[test]
+fn matrix_block_mul() {
+    let a = matrix![1, 2; 3, 4];
+    let b = matrix![0, 1; 1, 0];
+    let x = matrix![a, b; b, a];
+    let y = matrix![b; a];
+    let exp = matrix![a * b + b * a; b * b + a * a];
+    for_each_op_assert_eq! { x, *, y, exp }
+
+    // The block product agrees with the product of the flattened matrices.
+    let flat = matrix![
+        1, 2, 0, 1;
+        3, 4, 1, 0;
+        0, 1, 1, 2;
+        1, 0, 3, 4;
+    ];
+    let flat_y = matrix![
+        0, 1;
+        1, 0;
+        1, 2;
+        3, 4;
+    ];
+    let p = flat * flat_y;
+    for i in 0..4 {
+        for j in 0..2 {
+            assert_eq!(exp[i / 2][(i % 2, j)], p[(i, j)]);
+        }
+    }
+}
+
+#[test]
+fn matrix_block_identity() {
+    let a = matrix![1, 2; 3, 4];
+    let x = matrix![a, a; a, a];
+    let id = Matrix::<Matrix<i64, 2, 2>, 2, 2>::identity();
+    assert_eq!(x * id, x);
+    assert_eq!(id * x, x);
+}
+
+#[test]
+fn matrix_block_scalar() {
+    let a = matrix![1, 2; 3, 4];
+    let x = matrix![a, a];
+    let s = matrix![2, 0; 0, 2];
+    assert_eq!(x * s, matrix![a * 2, a * 2]);
+}
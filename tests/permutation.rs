@@ -0,0 +1,78 @@
+use vectrix::{matrix, Error, Matrix, Permutation};
+
+#[test]
+fn permutation_new() {
+    assert_eq!(Permutation::new([]), Ok(Permutation::<0>::identity()));
+    assert_eq!(Permutation::new([0, 1, 2]), Ok(Permutation::identity()));
+    assert_eq!(Permutation::new([0, 3, 2]), Err(Error::IndexOutOfBounds));
+    assert_eq!(Permutation::new([2, 0, 2]), Err(Error::OverlappingIndices));
+}
+
+#[test]
+fn permutation_default() {
+    assert_eq!(Permutation::<3>::default().as_array(), &[0, 1, 2]);
+}
+
+#[test]
+fn permutation_swap() {
+    let mut p = Permutation::<4>::identity();
+    p.swap(0, 3);
+    p.swap(1, 3);
+    assert_eq!(p.as_array(), &[3, 0, 2, 1]);
+}
+
+#[test]
+fn permutation_inverse() {
+    let p = Permutation::new([3, 0, 2, 1]).unwrap();
+    assert_eq!(p.inverse().as_array(), &[1, 3, 2, 0]);
+    assert_eq!(p.inverse().inverse(), p);
+}
+
+#[test]
+fn permutation_is_even() {
+    assert!(Permutation::<0>::identity().is_even());
+    assert!(Permutation::<3>::identity().is_even());
+    assert!(!Permutation::new([1, 0, 2]).unwrap().is_even());
+    assert!(Permutation::new([1, 2, 0]).unwrap().is_even());
+    assert!(!Permutation::new([3, 0, 1, 2]).unwrap().is_even());
+}
+
+#[test]
+fn permutation_into_matrix() {
+    let p = Permutation::new([1, 2, 0]).unwrap();
+    let m: Matrix<i64, 3, 3> = p.into();
+    assert_eq!(
+        m,
+        matrix![
+            0, 1, 0;
+            0, 0, 1;
+            1, 0, 0;
+        ]
+    );
+}
+
+#[test]
+fn matrix_permute_rows() {
+    let p = Permutation::new([1, 2, 0]).unwrap();
+    let m = matrix![
+        1, 2;
+        3, 4;
+        5, 6;
+    ];
+    let exp = matrix![
+        3, 4;
+        5, 6;
+        1, 2;
+    ];
+    assert_eq!(m.permute_rows(&p), exp);
+    assert_eq!(Matrix::from(p) * m, exp);
+}
+
+#[test]
+fn matrix_permute_columns() {
+    let p = Permutation::new([1, 2, 0]).unwrap();
+    let m = matrix![1, 2, 3; 4, 5, 6];
+    let exp = matrix![2, 3, 1; 5, 6, 4];
+    assert_eq!(m.permute_columns(&p), exp);
+    assert_eq!(m * Matrix::from(p.inverse()), exp);
+}
@@ -0,0 +1,23 @@
+use vectrix::{vector, Plane};
+
+#[test]
+fn plane_from_coefficients() {
+    let plane = Plane::from_coefficients(vector![1, 2, 3, 4]);
+    assert_eq!(plane, Plane::new(vector![1, 2, 3], 4));
+    assert_eq!(plane.normal(), &vector![1, 2, 3]);
+    assert_eq!(plane.d(), 4);
+}
+
+#[test]
+fn plane_normalize() {
+    let plane = Plane::new(vector![3.0, 0.0, 4.0], 10.0).normalize();
+    assert_eq!(plane, Plane::new(vector![0.6, 0.0, 0.8], 2.0));
+}
+
+#[test]
+fn plane_signed_distance() {
+    let plane = Plane::new(vector![0.0, 0.0, 1.0], -2.0);
+    assert_eq!(plane.signed_distance(&vector![7.0, -3.0, 5.0]), 3.0);
+    assert_eq!(plane.signed_distance(&vector![1.0, 1.0, 2.0]), 0.0);
+    assert_eq!(plane.signed_distance(&vector![0.0, 0.0, 0.0]), -2.0);
+}
@@ -0,0 +1,47 @@
+use vectrix::{vector, Point, Point2, Point3, Vector};
+
+#[test]
+fn point_origin() {
+    let o = Point3::<i64>::origin();
+    assert_eq!(o.coords(), &vector![0, 0, 0]);
+    assert_eq!(o, Point::default());
+}
+
+#[test]
+fn point_conversions() {
+    let p = Point::new(vector![1, 2, 3]);
+    assert_eq!(p, Point3::from([1, 2, 3]));
+    assert_eq!(p, Point::from(vector![1, 2, 3]));
+    assert_eq!(Vector::from(p), vector![1, 2, 3]);
+    assert_eq!(p.into_vector(), vector![1, 2, 3]);
+}
+
+#[test]
+fn point_coords() {
+    let mut p = Point3::from([1, 2, 3]);
+    assert_eq!((p.x, p.y, p.z), (1, 2, 3));
+    p.y = 5;
+    assert_eq!(p, Point3::from([1, 5, 3]));
+    let p = Point::from([1, 2, 3, 4, 5, 6, 7]);
+    assert_eq!(p.coords()[6], 7);
+}
+
+#[test]
+fn point_ops() {
+    let a = Point2::from([1.0, 2.0]);
+    let b = Point2::from([4.0, 6.0]);
+    assert_eq!(b - a, vector![3.0, 4.0]);
+    assert_eq!(a - b, vector![-3.0, -4.0]);
+    assert_eq!(a + vector![3.0, 4.0], b);
+    assert_eq!(b - vector![3.0, 4.0], a);
+    assert_eq!((b - a).l2_norm(), 5.0);
+}
+
+#[test]
+fn point_op_assign() {
+    let mut p = Point2::from([1, 2]);
+    p += vector![2, 2];
+    assert_eq!(p, Point2::from([3, 4]));
+    p -= vector![3, 5];
+    assert_eq!(p, Point2::from([0, -1]));
+}
@@ -0,0 +1,91 @@
+use vectrix::{matrix, Matrix};
+
+////////////////////////////////////////////////////////////////////////////////
+// Matrix<T, M, N> predicates
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn matrix_is_diagonal() {
+    assert!(matrix![1, 0; 0, 2].is_diagonal());
+    assert!(matrix![1, 0, 0; 0, 2, 0].is_diagonal());
+    assert!(!matrix![1, 0; 3, 2].is_diagonal());
+    assert!(Matrix::<i64, 0, 0>::zero().is_diagonal());
+}
+
+#[test]
+fn matrix_is_diagonal_eps() {
+    assert!(matrix![1.0, 1e-9; -1e-9, 2.0].is_diagonal_eps(1e-6));
+    assert!(!matrix![1.0, 1e-3; 0.0, 2.0].is_diagonal_eps(1e-6));
+    assert!(matrix![1_u32, 1; 0, 2].is_diagonal_eps(1));
+}
+
+#[test]
+fn matrix_is_upper_triangular() {
+    assert!(matrix![1, 2, 3; 0, 4, 5; 0, 0, 6].is_upper_triangular());
+    assert!(matrix![1, 2, 3; 0, 4, 5].is_upper_triangular());
+    assert!(!matrix![1, 2; 3, 4].is_upper_triangular());
+    assert!(matrix![1.0, 2.0; 1e-9, 4.0].is_upper_triangular_eps(1e-6));
+}
+
+#[test]
+fn matrix_is_lower_triangular() {
+    assert!(matrix![1, 0, 0; 2, 3, 0; 4, 5, 6].is_lower_triangular());
+    assert!(matrix![1, 0; 2, 3; 4, 5].is_lower_triangular());
+    assert!(!matrix![1, 2; 3, 4].is_lower_triangular());
+    assert!(matrix![1.0, 1e-9; 3.0, 4.0].is_lower_triangular_eps(1e-6));
+}
+
+#[test]
+fn matrix_is_finite() {
+    assert!(matrix![1.0, -2.0; 3.0, f64::MAX].is_finite());
+    assert!(!matrix![1.0, f64::NEG_INFINITY].is_finite());
+    assert!(!matrix![f32::NAN].is_finite());
+    assert!(Matrix::<f64, 0, 0>::zero().is_finite());
+}
+
+#[test]
+fn matrix_assert_finite() {
+    matrix![1.0, 2.0; 3.0, 4.0].assert_finite();
+    matrix![1.0, 2.0; 3.0, 4.0].expect_finite("velocity");
+}
+
+#[test]
+#[should_panic(expected = "element at (1, 0) is not finite: NaN")]
+fn matrix_assert_finite_nan() {
+    matrix![1.0, 2.0; f64::NAN, f64::INFINITY].assert_finite();
+}
+
+#[test]
+#[should_panic(expected = "velocity: element at (0, 1) is not finite: inf")]
+fn matrix_expect_finite_inf() {
+    matrix![1.0, f64::INFINITY; 3.0, 4.0].expect_finite("velocity");
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Matrix<T, N, N> predicates
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn matrix_is_symmetric() {
+    assert!(matrix![1, 2, 3; 2, 4, 5; 3, 5, 6].is_symmetric());
+    assert!(!matrix![1, 2, 3; 2, 4, 5; 3, 4, 6].is_symmetric());
+}
+
+#[test]
+fn matrix_is_symmetric_eps() {
+    assert!(matrix![1.0, 2.0; 2.0 + 1e-9, 4.0].is_symmetric_eps(1e-6));
+    assert!(!matrix![1.0, 2.0; 2.1, 4.0].is_symmetric_eps(1e-6));
+}
+
+#[test]
+fn matrix_is_identity() {
+    assert!(Matrix::<i64, 4, 4>::identity().is_identity());
+    assert!(!Matrix::<i64, 4, 4>::zero().is_identity());
+    assert!(!matrix![1, 0; 1, 1].is_identity());
+}
+
+#[test]
+fn matrix_is_identity_eps() {
+    assert!(matrix![1.0 + 1e-9, 0.0; 1e-9, 1.0].is_identity_eps(1e-6));
+    assert!(!matrix![1.1, 0.0; 0.0, 1.0].is_identity_eps(1e-6));
+}
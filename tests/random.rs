@@ -0,0 +1,51 @@
+#![cfg(feature = "rand")]
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use vectrix::{vector, Matrix, Vector};
+
+#[test]
+fn vector_sample_index() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let weights = vector![1.0, 0.0, 3.0, 4.0];
+    let mut counts = [0_i32; 4];
+    for _ in 0..8000 {
+        counts[weights.sample_index(&mut rng).unwrap()] += 1;
+    }
+    assert_eq!(counts[1], 0);
+    for (count, expected) in counts.iter().zip([1000, 0, 3000, 4000]) {
+        assert!((count - expected).abs() < 200, "{:?}", counts);
+    }
+}
+
+#[test]
+fn vector_sample_index_single() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let weights = vector![0.0_f32, 0.0, 2.5, 0.0];
+    for _ in 0..100 {
+        assert_eq!(weights.sample_index(&mut rng), Some(2));
+    }
+}
+
+#[test]
+fn vector_sample_index_none() {
+    let mut rng = StdRng::seed_from_u64(0);
+    assert_eq!(Vector::<f64, 0>::zero().sample_index(&mut rng), None);
+    assert_eq!(vector![0.0, 0.0].sample_index(&mut rng), None);
+    assert_eq!(vector![f64::NAN, 1.0].sample_index(&mut rng), None);
+}
+
+#[test]
+fn matrix_random_with() {
+    let m = Matrix::<f64, 3, 4>::random_with(&mut StdRng::seed_from_u64(1));
+    assert!(m.iter().all(|x| (0.0..1.0).contains(x)));
+    let n: Matrix<f64, 3, 4> = StdRng::seed_from_u64(1).gen();
+    assert_eq!(m, n);
+    assert_ne!(m, Matrix::random_with(&mut StdRng::seed_from_u64(2)));
+}
+
+#[test]
+fn matrix_random() {
+    let v = Vector::<u64, 8>::random();
+    assert_ne!(v, Vector::random());
+}
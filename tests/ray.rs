@@ -0,0 +1,16 @@
+use vectrix::{vector, Ray};
+
+#[test]
+fn ray_accessors() {
+    let ray = Ray::new(vector![1, 2, 3], vector![0, 1, 0]);
+    assert_eq!(ray.origin(), &vector![1, 2, 3]);
+    assert_eq!(ray.direction(), &vector![0, 1, 0]);
+}
+
+#[test]
+fn ray_at() {
+    let ray = Ray::new(vector![1.0, 2.0, 3.0], vector![0.5, 0.0, -1.0]);
+    assert_eq!(ray.at(0.0), vector![1.0, 2.0, 3.0]);
+    assert_eq!(ray.at(4.0), vector![3.0, 2.0, -1.0]);
+    assert_eq!(ray.at(-2.0), vector![0.0, 2.0, 5.0]);
+}
@@ -0,0 +1,287 @@
+#![cfg(feature = "testutils")]
+
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_3, FRAC_PI_6};
+
+use vectrix::testutils::assert_close;
+use vectrix::{matrix, vector, Det, EulerOrder, Matrix, Rotation, Rotation2, Rotation3, Unit};
+
+fn assert_rotation(r: Matrix<f64, 3, 3>) {
+    let t = Matrix::from_column_major_order(r.into_row_major_order());
+    assert_close(&(r * t), &Matrix::identity(), 1e-12);
+    assert!((r.det() - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn matrix_from_rotation_axes() {
+    let x = vector![1.0, 0.0, 0.0];
+    let y = vector![0.0, 1.0, 0.0];
+    let z = vector![0.0, 0.0, 1.0];
+    assert_close(&(Matrix::from_rotation_x(FRAC_PI_2) * y), &z, 1e-12);
+    assert_close(&(Matrix::from_rotation_y(FRAC_PI_2) * z), &x, 1e-12);
+    assert_close(&(Matrix::from_rotation_z(FRAC_PI_2) * x), &y, 1e-12);
+    let (s, c) = (0.5, 3.0_f64.sqrt() / 2.0);
+    assert_close(
+        &Matrix::from_rotation_x(FRAC_PI_6),
+        &matrix![1.0, 0.0, 0.0; 0.0, c, -s; 0.0, s, c],
+        1e-12,
+    );
+    assert_close(
+        &Matrix::from_rotation_y(FRAC_PI_6),
+        &matrix![c, 0.0, s; 0.0, 1.0, 0.0; -s, 0.0, c],
+        1e-12,
+    );
+    assert_close(
+        &Matrix::from_rotation_z(FRAC_PI_6),
+        &matrix![c, -s, 0.0; s, c, 0.0; 0.0, 0.0, 1.0],
+        1e-12,
+    );
+}
+
+#[test]
+fn matrix_from_axis_angle() {
+    for angle in [0.3, -1.2, FRAC_PI_3] {
+        let r = Matrix::from_axis_angle(&vector![2.0, 0.0, 0.0], angle);
+        assert_close(&r, &Matrix::from_rotation_x(angle), 1e-12);
+        let r = Matrix::from_axis_angle(&vector![0.0, 0.5, 0.0], angle);
+        assert_close(&r, &Matrix::from_rotation_y(angle), 1e-12);
+        let r = Matrix::from_axis_angle(&vector![0.0, 0.0, -1.0], angle);
+        assert_close(&r, &Matrix::from_rotation_z(-angle), 1e-12);
+    }
+    let axis = vector![1.0, -2.0, 0.5];
+    let r = Matrix::from_axis_angle(&axis, 0.7);
+    assert_rotation(r);
+    assert_close(&(r * axis), &axis, 1e-12);
+}
+
+#[test]
+fn matrix_from_unit_axis_angle() {
+    let axis = vector![1.0, -2.0, 0.5];
+    let unit = Unit::new_normalize(axis).unwrap();
+    assert_close(
+        &Matrix::from_unit_axis_angle(&unit, 0.7),
+        &Matrix::from_axis_angle(&axis, 0.7),
+        1e-12,
+    );
+    assert_close(
+        &Matrix::from_unit_axis_angle(&-unit, 0.7),
+        &Matrix::from_axis_angle(&axis, -0.7),
+        1e-12,
+    );
+}
+
+#[test]
+fn matrix_from_axis_angle_zero_axis() {
+    let r = Matrix::from_axis_angle(&vector![0.0, 0.0, 0.0], 1.0);
+    assert_eq!(r, Matrix::identity());
+}
+
+#[test]
+fn matrix_from_euler() {
+    let (yaw, pitch, roll) = (0.4, -0.9, 1.3);
+    let r = Matrix::from_euler(yaw, pitch, roll);
+    assert_rotation(r);
+    assert_close(
+        &r,
+        &(Matrix::from_rotation_z(yaw)
+            * Matrix::from_rotation_y(pitch)
+            * Matrix::from_rotation_x(roll)),
+        1e-12,
+    );
+    // Pitching up by 90 degrees points the x-axis down.
+    let r = Matrix::from_euler(0.0, FRAC_PI_2, 0.0);
+    assert_close(
+        &(r * vector![1.0, 0.0, 0.0]),
+        &vector![0.0, 0.0, -1.0],
+        1e-12,
+    );
+    // Yaw then roll
+    let r = Matrix::from_euler(FRAC_PI_2, 0.0, FRAC_PI_2);
+    assert_close(
+        &(r * vector![0.0, 1.0, 0.0]),
+        &vector![0.0, 0.0, 1.0],
+        1e-12,
+    );
+}
+
+#[test]
+fn matrix_try_into_rotation() {
+    let m = Matrix::from_euler(0.3, -1.1, 2.0);
+    let r = m.try_into_rotation(1e-12).unwrap();
+    assert_eq!(*r, m);
+    assert_eq!(r.into_inner(), m);
+
+    // scaled, sheared, reflected and non-finite matrices are rejected
+    assert!((m * 1.001).try_into_rotation(1e-6).is_none());
+    assert!(matrix![1.0, 0.1, 0.0; 0.0, 1.0, 0.0; 0.0, 0.0, 1.0]
+        .try_into_rotation(1e-6)
+        .is_none());
+    assert!((m * -1.0).try_into_rotation(1e-12).is_none());
+    assert!(Matrix::<f64, 3, 3>::repeat(f64::NAN)
+        .try_into_rotation(1e-12)
+        .is_none());
+
+    // small errors are within the tolerance
+    let mut n = m;
+    n[(0, 0)] += 1e-9;
+    assert!(n.try_into_rotation(1e-12).is_none());
+    assert!(n.try_into_rotation(1e-6).is_some());
+}
+
+#[test]
+fn rotation3_inverse_and_compose() {
+    let a = Rotation3::try_new(Matrix::from_rotation_x(FRAC_PI_6), 1e-12).unwrap();
+    let b = Rotation3::try_new(Matrix::from_rotation_z(FRAC_PI_3), 1e-12).unwrap();
+    assert_close(&*(a * a.inverse()), &Matrix::identity(), 1e-12);
+    assert_close(&*(a * b), &(*a * *b), 1e-12);
+    assert!(Rotation3::try_new((a * b).into_inner(), 1e-12).is_some());
+}
+
+#[test]
+fn rotation3_mul_vector() {
+    let r = Matrix::from_rotation_z(FRAC_PI_2)
+        .try_into_rotation(1e-12)
+        .unwrap();
+    assert_close(
+        &(r * vector![1.0, 0.0, 0.0]),
+        &vector![0.0, 1.0, 0.0],
+        1e-12,
+    );
+    assert_close(
+        &(r.inverse() * vector![0.0, 1.0, 0.0]),
+        &vector![1.0, 0.0, 0.0],
+        1e-12,
+    );
+}
+
+const ORDERS: [EulerOrder; 6] = [
+    EulerOrder::XYZ,
+    EulerOrder::XZY,
+    EulerOrder::YXZ,
+    EulerOrder::YZX,
+    EulerOrder::ZXY,
+    EulerOrder::ZYX,
+];
+
+#[test]
+fn matrix_from_euler_angles() {
+    let (a, b, c) = (0.4, -0.7, 1.3);
+    assert_close(
+        &Matrix::from_euler_angles(EulerOrder::XYZ, a, b, c),
+        &(Matrix::from_rotation_x(a) * Matrix::from_rotation_y(b) * Matrix::from_rotation_z(c)),
+        1e-12,
+    );
+    assert_close(
+        &Matrix::from_euler_angles(EulerOrder::YZX, a, b, c),
+        &(Matrix::from_rotation_y(a) * Matrix::from_rotation_z(b) * Matrix::from_rotation_x(c)),
+        1e-12,
+    );
+    assert_close(
+        &Matrix::from_euler_angles(EulerOrder::ZYX, a, b, c),
+        &Matrix::from_euler(a, b, c),
+        1e-12,
+    );
+}
+
+#[test]
+fn matrix_to_euler_angles_round_trip() {
+    for order in ORDERS {
+        for &(a, b, c) in &[(0.4, -0.7, 1.3), (-2.5, 1.2, -0.1), (3.0, 0.0, -3.0f64)] {
+            let r = Matrix::from_euler_angles(order, a, b, c);
+            let (x, y, z) = r.to_euler_angles(order);
+            assert!(
+                (x - a).abs() < 1e-12 && (y - b).abs() < 1e-12 && (z - c).abs() < 1e-12,
+                "{:?}: {:?} != {:?}",
+                order,
+                (x, y, z),
+                (a, b, c)
+            );
+        }
+    }
+}
+
+#[test]
+fn matrix_to_euler_angles_gimbal_lock() {
+    for order in ORDERS {
+        for b in [FRAC_PI_2, -FRAC_PI_2] {
+            let r = Matrix::from_euler_angles(order, 0.3, b, 0.5);
+            let (x, y, z) = r.to_euler_angles(order);
+            assert!((y - b).abs() < 1e-6, "{:?}", order);
+            assert_eq!(z, 0.0);
+            assert_close(&Matrix::from_euler_angles(order, x, y, z), &r, 1e-12);
+        }
+    }
+}
+
+#[test]
+fn matrix_to_axis_angle() {
+    for &angle in &[0.1, FRAC_PI_2, 2.0, 3.1, std::f64::consts::PI] {
+        for axis in [
+            vector![1.0, 2.0, 3.0],
+            vector![-1.0, 0.0, 0.5],
+            vector![0.0, 0.0, -1.0],
+        ] {
+            let r = Matrix::from_axis_angle(&axis, angle);
+            let (k, theta) = r.to_axis_angle();
+            assert!((theta - angle).abs() < 1e-12);
+            assert_close(&Matrix::from_unit_axis_angle(&k, theta), &r, 1e-12);
+            if angle < 3.0 {
+                assert_close(&*k, &(axis / axis.l2_norm()), 1e-12);
+            }
+        }
+    }
+}
+
+#[test]
+fn matrix_to_axis_angle_identity() {
+    let (axis, angle) = Matrix::<f64, 3, 3>::identity().to_axis_angle();
+    assert_eq!(*axis, vector![1.0, 0.0, 0.0]);
+    assert_eq!(angle, 0.0);
+}
+
+#[test]
+fn rotation2() {
+    let r = Rotation2::from_angle(FRAC_PI_6);
+    assert!((r.angle() - FRAC_PI_6).abs() < 1e-12);
+    assert!(((r * r * r).angle() - FRAC_PI_2).abs() < 1e-12);
+    assert!((r.inverse().angle() + FRAC_PI_6).abs() < 1e-12);
+    assert_close(&(r * vector![2.0, 0.0]), &vector![3f64.sqrt(), 1.0], 1e-12);
+    assert_close(&(r * r.inverse()).into_inner(), &Matrix::identity(), 1e-12);
+    assert_eq!(Rotation2::<f64>::identity().angle(), 0.0);
+    assert!(Rotation::try_new(*r, 1e-12).is_some());
+    assert!(matrix![0.0, 1.0; 1.0, 0.0]
+        .try_into_rotation(1e-12)
+        .is_none());
+}
+
+#[test]
+fn rotation3_constructors() {
+    let axis = Unit::new_normalize(vector![1.0, -2.0, 0.5]).unwrap();
+    let r = Rotation3::from_axis_angle(&axis, 1.2);
+    assert_close(&*r, &Matrix::from_unit_axis_angle(&axis, 1.2), 1e-12);
+    let r = Rotation3::from_euler_angles(EulerOrder::ZYX, 0.1, 0.2, 0.3);
+    assert_close(&*r, &Matrix::from_euler(0.1, 0.2, 0.3), 1e-12);
+    assert_eq!(*Rotation3::<f64>::identity(), Matrix::identity());
+}
+
+#[test]
+fn rotation3_exp_log() {
+    for omega in [
+        vector![0.1, -0.2, 0.3],
+        vector![1e-9, 2e-9, -1e-9],
+        vector![0.0, 3.0, 0.0],
+        vector![2.0, 2.0, -1.0],
+    ] {
+        let r = Rotation3::exp(&omega);
+        assert_close(
+            &*r,
+            &Matrix::from_axis_angle(&omega, omega.l2_norm()),
+            1e-12,
+        );
+        assert_close(&r.log(), &omega, 1e-12);
+    }
+    assert_eq!(
+        Rotation3::exp(&vector![0.0, 0.0, 0.0]),
+        Rotation3::identity()
+    );
+    assert_eq!(Rotation3::<f64>::identity().log(), vector![0.0, 0.0, 0.0]);
+}
@@ -0,0 +1,31 @@
+#![cfg(feature = "std")]
+
+use vectrix::{matrix, vector, Matrix, TransformStack};
+
+#[test]
+fn transform_stack_new() {
+    let stack = TransformStack::<f64>::new();
+    assert_eq!(stack.current(), &Matrix::identity());
+    assert_eq!(stack.depth(), 0);
+    assert_eq!(stack, TransformStack::default());
+}
+
+#[test]
+fn transform_stack_push_pop() {
+    let translate = matrix![1, 0, 5; 0, 1, -3; 0, 0, 1];
+    let scale = matrix![3, 0, 0; 0, 2, 0; 0, 0, 1];
+    let mut stack = TransformStack::new();
+    stack.push(&translate);
+    stack.push(&scale);
+    assert_eq!(stack.depth(), 2);
+    assert_eq!(stack.current(), &(translate * scale));
+    assert_eq!(stack.apply_point(&vector![1, 1]), vector![8, -1]);
+
+    assert_eq!(stack.pop(), Some(translate * scale));
+    assert_eq!(stack.current(), &translate);
+    assert_eq!(stack.apply_point(&vector![1, 1]), vector![6, -2]);
+    assert_eq!(stack.pop(), Some(translate));
+    assert_eq!(stack.pop(), None);
+    assert_eq!(stack.depth(), 0);
+    assert_eq!(stack.apply_point(&vector![1, 1]), vector![1, 1]);
+}
@@ -0,0 +1,69 @@
+#![cfg(feature = "testutils")]
+
+use vectrix::{matrix, testutils, Matrix};
+
+#[test]
+fn random_is_deterministic() {
+    let a = testutils::random::<f64, 3, 4>(7);
+    assert_eq!(a, testutils::random::<f64, 3, 4>(7));
+    assert_ne!(a, testutils::random::<f64, 3, 4>(8));
+    assert!(a.iter().all(|&x| (-1.0..1.0).contains(&x)));
+}
+
+#[test]
+fn well_conditioned_is_diagonally_dominant() {
+    let a = testutils::well_conditioned::<f64, 5>(42);
+    for i in 0..5 {
+        let off: f64 = (0..5).filter(|&j| j != i).map(|j| a[(i, j)].abs()).sum();
+        assert!(a[(i, i)].abs() > off);
+    }
+}
+
+#[test]
+fn well_conditioned_pseudo_inverse() {
+    let a = testutils::well_conditioned::<f64, 6>(1);
+    testutils::assert_close(&(a * a.pseudo_inverse()), &Matrix::identity(), 1e-12);
+}
+
+#[test]
+fn hilbert() {
+    let h = testutils::hilbert::<f64, 3>();
+    let exp = matrix![
+        1.0, 1.0 / 2.0, 1.0 / 3.0;
+        1.0 / 2.0, 1.0 / 3.0, 1.0 / 4.0;
+        1.0 / 3.0, 1.0 / 4.0, 1.0 / 5.0;
+    ];
+    assert_eq!(h, exp);
+}
+
+#[test]
+fn hilbert_inverse() {
+    let exp = matrix![
+        9.0, -36.0, 30.0;
+        -36.0, 192.0, -180.0;
+        30.0, -180.0, 180.0;
+    ];
+    assert_eq!(testutils::hilbert_inverse::<f64, 3>(), exp);
+    let h = testutils::hilbert::<f64, 5>();
+    let inv = testutils::hilbert_inverse::<f64, 5>();
+    testutils::assert_close(&(h * inv), &Matrix::identity(), 1e-9);
+}
+
+#[test]
+fn assert_close_within_tolerance() {
+    let a = matrix![1.0, 100.0];
+    let b = matrix![1.0 + 1e-10, 100.0 + 1e-8];
+    testutils::assert_close(&a, &b, 1e-9);
+}
+
+#[test]
+#[should_panic(expected = "matrices differ at (0, 1): 2.0 != 2.5 with tolerance 0.1")]
+fn assert_close_panics() {
+    testutils::assert_close(&matrix![1.0, 2.0], &matrix![1.0, 2.5], 0.1);
+}
+
+#[test]
+#[should_panic(expected = "matrices differ at (0, 0): NaN != 1.0")]
+fn assert_close_nan() {
+    testutils::assert_close(&matrix![f64::NAN], &matrix![1.0], 0.1);
+}
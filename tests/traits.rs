@@ -0,0 +1,163 @@
+use vectrix::{
+    matrix, row_vector, vector, ConstOne, ConstZero, Det, Dot, Matrix, Norm, One, Permutation,
+    Trace, Transpose, Zero,
+};
+
+fn sum<T: Copy + Zero + std::ops::Add<Output = T>>(items: &[T]) -> T {
+    items.iter().fold(T::zero(), |acc, &x| acc + x)
+}
+
+fn product<T: Copy + One + std::ops::Mul<Output = T>>(items: &[T]) -> T {
+    items.iter().fold(T::one(), |acc, &x| acc * x)
+}
+
+#[test]
+fn matrix_zero() {
+    let m: Matrix<i64, 2, 3> = Zero::zero();
+    assert_eq!(m, Matrix::zero());
+}
+
+#[test]
+fn matrix_one() {
+    let m: Matrix<i64, 3, 3> = One::one();
+    assert_eq!(m, Matrix::identity());
+}
+
+#[test]
+fn const_zero_one() {
+    const ZERO: (i32, f64, bool) = (ConstZero::ZERO, ConstZero::ZERO, ConstZero::ZERO);
+    const ONE: (u8, f32, bool) = (ConstOne::ONE, ConstOne::ONE, ConstOne::ONE);
+    assert_eq!(ZERO, (0, 0.0, false));
+    assert_eq!(ONE, (1, 1.0, true));
+}
+
+#[test]
+fn matrix_generic_sum() {
+    let a = matrix![1, 2; 3, 4];
+    let b = matrix![5, 6; 7, 8];
+    assert_eq!(sum(&[a, b]), matrix![6, 8; 10, 12]);
+    assert_eq!(sum::<Matrix<i64, 2, 2>>(&[]), Matrix::zero());
+}
+
+#[test]
+fn matrix_generic_product() {
+    let a = matrix![1, 2; 3, 4];
+    let b = matrix![0, 1; 1, 0];
+    assert_eq!(product(&[a, b]), matrix![2, 1; 4, 3]);
+    assert_eq!(product::<Matrix<i64, 2, 2>>(&[]), Matrix::identity());
+}
+
+#[test]
+fn matrix_trace() {
+    assert_eq!(Matrix::<i64, 0, 0>::zero().trace(), 0);
+    assert_eq!(matrix![7].trace(), 7);
+    assert_eq!(matrix![1, 2, 3; 4, 5, 6; 7, 8, 9].trace(), 15);
+    assert_eq!(matrix![1.5, 2.0; 3.0, 4.0].trace(), 5.5);
+}
+
+#[test]
+fn matrix_trace_block() {
+    let a = matrix![1, 2; 3, 4];
+    let b = matrix![0, 1; 1, 0];
+    let m = matrix![a, b; b, b];
+    assert_eq!(m.trace(), a.trace() + b.trace());
+}
+
+#[test]
+fn matrix_det() {
+    assert_eq!(Matrix::<i64, 0, 0>::zero().det(), 1);
+    assert_eq!(matrix![7].det(), 7);
+    assert_eq!(matrix![1, 2; 3, 4].det(), -2);
+    assert_eq!(matrix![2, -3, 1; 2, 0, -1; 1, 4, 5].det(), 49);
+    assert_eq!(matrix![1, 2, 3; 4, 5, 6; 7, 8, 9].det(), 0);
+    assert_eq!(
+        matrix![
+            1, 0, 2, -1;
+            3, 0, 0, 5;
+            2, 1, 4, -3;
+            1, 0, 5, 0;
+        ]
+        .det(),
+        30
+    );
+    assert_eq!(Matrix::<i64, 5, 5>::identity().det(), 1);
+}
+
+#[test]
+fn matrix_det_float() {
+    let m = matrix![
+        4.0_f64, 3.0, 2.0;
+        1.0, 3.0, 1.0;
+        2.0, 1.0, 5.0;
+    ];
+    assert!((m.det() - 37.0).abs() < 1e-12);
+}
+
+#[test]
+fn matrix_det_product() {
+    let a = matrix![1, 2, 0; -1, 3, 4; 2, 2, 1];
+    let b = matrix![0, 1, 1; 5, -2, 3; 1, 1, 1];
+    assert_eq!((a * b).det(), a.det() * b.det());
+}
+
+#[test]
+fn matrix_det_permutation() {
+    for map in [
+        [0, 1, 2, 3],
+        [1, 0, 2, 3],
+        [1, 2, 3, 0],
+        [3, 2, 1, 0],
+        [2, 3, 1, 0],
+    ] {
+        let p = Permutation::new(map).unwrap();
+        let m: Matrix<i64, 4, 4> = p.into();
+        assert_eq!(m.det(), if p.is_even() { 1 } else { -1 });
+    }
+}
+
+#[test]
+fn matrix_det_block() {
+    // The blocks are all polynomials in `a` so they commute.
+    let a = matrix![1, 2; 3, 4];
+    let i = Matrix::identity();
+    let m = matrix![a, i; a * a, a + i];
+    let flat = matrix![
+        1, 2, 1, 0;
+        3, 4, 0, 1;
+        7, 10, 2, 2;
+        15, 22, 3, 5;
+    ];
+    assert_eq!(m.det(), flat.det());
+}
+
+fn generic_dot<A: Dot<B> + ?Sized, B: ?Sized>(a: &A, b: &B) -> A::Output {
+    a.dot(b)
+}
+
+#[test]
+fn matrix_dot() {
+    assert_eq!(generic_dot(&vector![1, 2, 3], &vector![4, 5, 6]), 32);
+    assert_eq!(generic_dot(&row_vector![1, 2], &row_vector![3, 4]), 11);
+    assert_eq!(generic_dot(&matrix![1, 2; 3, 4], &matrix![5, 6; 7, 8]), 70);
+    let a = matrix![1, 2; 3, 4];
+    let b = matrix![5, 6; 7, 8];
+    assert_eq!(generic_dot(a.row(1), b.column(0)), 43);
+}
+
+#[test]
+fn matrix_norm() {
+    assert_eq!(Norm::norm(&vector![3.0, 4.0]), 5.0);
+    assert_eq!(Norm::norm(&matrix![1.0, -1.0; 3.0, -5.0]), 6.0);
+}
+
+#[test]
+fn matrix_transpose() {
+    let m = matrix![1, 2, 3; 4, 5, 6];
+    assert_eq!(m.transpose(), matrix![1, 4; 2, 5; 3, 6]);
+    assert_eq!(m.transpose().transpose(), m);
+    assert_eq!(vector![1, 2, 3].transpose(), row_vector![1, 2, 3]);
+    assert_eq!(
+        Matrix::<i64, 0, 2>::zero().transpose(),
+        Matrix::<i64, 2, 0>::zero()
+    );
+}
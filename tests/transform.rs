@@ -0,0 +1,339 @@
+#![cfg(feature = "testutils")]
+
+use std::f64::consts::FRAC_PI_2;
+
+use vectrix::testutils::assert_close;
+use vectrix::{matrix, vector, Dot, Matrix, Transpose, Vector};
+
+fn project(m: Matrix<f64, 4, 4>, p: Vector<f64, 3>) -> Vector<f64, 3> {
+    let q = m * vector![p.x, p.y, p.z, 1.0];
+    vector![q.x / q.w, q.y / q.w, q.z / q.w]
+}
+
+#[test]
+fn matrix_from_translation() {
+    let m = Matrix::from_translation(&vector![1.0, -2.0, 3.0]);
+    assert_eq!(
+        m,
+        matrix![
+            1.0, 0.0, 0.0, 1.0;
+            0.0, 1.0, 0.0, -2.0;
+            0.0, 0.0, 1.0, 3.0;
+            0.0, 0.0, 0.0, 1.0;
+        ]
+    );
+    // Directions are not translated.
+    assert_eq!(m * vector![1.0, 1.0, 1.0, 0.0], vector![1.0, 1.0, 1.0, 0.0]);
+}
+
+#[test]
+fn matrix_from_scale() {
+    let m = Matrix::from_scale(&vector![2.0, 0.5, -1.0]);
+    assert_eq!(m.diagonal(), vector![2.0, 0.5, -1.0, 1.0]);
+    assert_eq!(
+        m * vector![1.0, 2.0, 3.0, 1.0],
+        vector![2.0, 1.0, -3.0, 1.0]
+    );
+}
+
+#[test]
+fn matrix_look_at_rh() {
+    let eye = vector![1.0, 2.0, 3.0];
+    let target = vector![1.0, 2.0, -7.0];
+    let m = Matrix::look_at_rh(&eye, &target, &vector![0.0, 1.0, 0.0]);
+    assert_close(&m, &Matrix::from_translation(&-eye), 1e-12);
+
+    let m = Matrix::look_at_rh(
+        &vector![0.0, 0.0, 0.0],
+        &vector![1.0, 0.0, 0.0],
+        &vector![0.0, 0.0, 1.0],
+    );
+    // forward is -z, up is +y and right is +x in view space
+    assert_close(
+        &project(m, vector![2.0, 0.0, 0.0]),
+        &vector![0.0, 0.0, -2.0],
+        1e-12,
+    );
+    assert_close(
+        &project(m, vector![0.0, 0.0, 3.0]),
+        &vector![0.0, 3.0, 0.0],
+        1e-12,
+    );
+    assert_close(
+        &project(m, vector![0.0, -1.0, 0.0]),
+        &vector![1.0, 0.0, 0.0],
+        1e-12,
+    );
+}
+
+#[test]
+fn matrix_look_at_lh() {
+    let m = Matrix::look_at_lh(
+        &vector![0.0, 0.0, 0.0],
+        &vector![1.0, 0.0, 0.0],
+        &vector![0.0, 0.0, 1.0],
+    );
+    // forward is +z, up is +y and right is +x in view space
+    assert_close(
+        &project(m, vector![2.0, 0.0, 0.0]),
+        &vector![0.0, 0.0, 2.0],
+        1e-12,
+    );
+    assert_close(
+        &project(m, vector![0.0, 0.0, 3.0]),
+        &vector![0.0, 3.0, 0.0],
+        1e-12,
+    );
+    assert_close(
+        &project(m, vector![0.0, 1.0, 0.0]),
+        &vector![1.0, 0.0, 0.0],
+        1e-12,
+    );
+}
+
+#[test]
+fn matrix_perspective() {
+    let m = Matrix::perspective(FRAC_PI_2, 2.0, 1.0, 100.0);
+    assert_close(
+        &project(m, vector![0.0, 0.0, -1.0]),
+        &vector![0.0, 0.0, -1.0],
+        1e-12,
+    );
+    assert_close(
+        &project(m, vector![0.0, 0.0, -100.0]),
+        &vector![0.0, 0.0, 1.0],
+        1e-12,
+    );
+    // The corners of the near plane map to the corners of clip space.
+    assert_close(
+        &project(m, vector![2.0, 1.0, -1.0]),
+        &vector![1.0, 1.0, -1.0],
+        1e-12,
+    );
+    let p = project(m, vector![-20.0, -10.0, -10.0]);
+    assert_close(&vector![p.x, p.y], &vector![-1.0, -1.0], 1e-12);
+}
+
+#[test]
+fn matrix_orthographic() {
+    let m = Matrix::orthographic(-2.0, 6.0, -1.0, 1.0, 0.5, 10.5);
+    assert_close(
+        &project(m, vector![-2.0, -1.0, -0.5]),
+        &vector![-1.0, -1.0, -1.0],
+        1e-12,
+    );
+    assert_close(
+        &project(m, vector![6.0, 1.0, -10.5]),
+        &vector![1.0, 1.0, 1.0],
+        1e-12,
+    );
+    assert_close(
+        &project(m, vector![2.0, 0.0, -5.5]),
+        &vector![0.0, 0.0, 0.0],
+        1e-12,
+    );
+}
+
+#[test]
+fn vector_to_homogeneous() {
+    assert_eq!(vector![1, 2, 3].to_homogeneous(), vector![1, 2, 3, 1]);
+}
+
+#[test]
+fn vector_from_homogeneous() {
+    assert_eq!(
+        vector![2.0, -4.0, 1.0, 0.5].from_homogeneous(),
+        vector![4.0, -8.0, 2.0]
+    );
+    let p = vector![1.0, 2.0, 3.0];
+    assert_eq!(p.to_homogeneous().from_homogeneous(), p);
+}
+
+#[test]
+fn matrix_to_homogeneous() {
+    let m = matrix![1, 2, 3; 4, 5, 6; 7, 8, 9];
+    assert_eq!(
+        m.to_homogeneous(),
+        matrix![
+            1, 2, 3, 0;
+            4, 5, 6, 0;
+            7, 8, 9, 0;
+            0, 0, 0, 1;
+        ]
+    );
+    let p = vector![1, 1, 1];
+    assert_eq!(
+        m.to_homogeneous() * p.to_homogeneous(),
+        (m * p).to_homogeneous()
+    );
+}
+
+#[test]
+fn matrix_decompose_trs() {
+    let r = Matrix::from_rotation_y(0.3) * Matrix::from_rotation_z(-1.2);
+    let m = Matrix::from_scale_rotation_translation(
+        &vector![2.0, 0.5, 3.0],
+        &r,
+        &vector![1.0, 2.0, 3.0],
+    );
+    let trs = m.decompose_trs().unwrap();
+    assert_eq!(trs.translation, vector![1.0, 2.0, 3.0]);
+    assert_close(&trs.rotation, &r, 1e-12);
+    assert_close(&trs.scale, &vector![2.0, 0.5, 3.0], 1e-12);
+    assert!(!trs.has_shear(1e-12));
+    assert_close(
+        &Matrix::from_scale_rotation_translation(&trs.scale, &trs.rotation, &trs.translation),
+        &m,
+        1e-12,
+    );
+}
+
+#[test]
+fn matrix_decompose_trs_reflection() {
+    let m = Matrix::from_scale(&vector![-1.0, 2.0, 3.0]);
+    let trs = m.decompose_trs().unwrap();
+    assert_close(&trs.rotation, &Matrix::identity(), 1e-12);
+    assert_close(&trs.scale, &vector![-1.0, 2.0, 3.0], 1e-12);
+}
+
+#[test]
+fn matrix_decompose_trs_shear() {
+    let m = matrix![
+        2.0, 1.0, 0.0, 0.0;
+        0.0, 1.0, 0.0, 0.0;
+        0.0, 0.0, 1.0, 0.0;
+        0.0, 0.0, 0.0, 1.0;
+    ];
+    let trs = m.decompose_trs().unwrap();
+    assert!(trs.has_shear(1e-12));
+    assert_close(&trs.rotation, &Matrix::identity(), 1e-12);
+    assert_close(&trs.scale, &vector![2.0, 1.0, 1.0], 1e-12);
+    assert_close(&trs.shear, &vector![0.5, 0.0, 0.0], 1e-12);
+}
+
+#[test]
+fn matrix_decompose_trs_invalid() {
+    assert!(Matrix::from_scale(&vector![1.0, 0.0, 1.0])
+        .decompose_trs()
+        .is_none());
+    assert!(Matrix::perspective(FRAC_PI_2, 1.0, 0.1, 10.0)
+        .decompose_trs()
+        .is_none());
+}
+
+#[test]
+fn matrix_normal_matrix() {
+    let r = Matrix::from_rotation_x(0.7) * Matrix::from_rotation_z(0.2);
+    let m = Matrix::from_scale_rotation_translation(
+        &vector![1.0, 3.0, -2.0],
+        &r,
+        &vector![5.0, 6.0, 7.0],
+    );
+    let mut upper = Matrix::<f64, 3, 3>::zero();
+    for (i, j) in upper.indices() {
+        upper[(i, j)] = m[(i, j)];
+    }
+    let n = m.normal_matrix().unwrap();
+    assert_close(&(n.transpose() * upper), &Matrix::identity(), 1e-12);
+
+    // normals stay perpendicular to transformed tangents
+    let (tangent, normal) = (vector![1.0, 1.0, 0.0], vector![1.0, -1.0, 2.0]);
+    assert!(((upper * tangent).dot(&(n * normal))).abs() < 1e-12);
+}
+
+#[test]
+fn matrix_normal_matrix_singular() {
+    assert!(Matrix::from_scale(&vector![1.0, 0.0, 1.0])
+        .normal_matrix()
+        .is_none());
+}
+
+#[test]
+fn matrix_extract_frustum_planes() {
+    let proj = Matrix::perspective(FRAC_PI_2, 1.0, 1.0, 10.0);
+    let view = Matrix::from_translation(&vector![0.0, 0.0, -5.0]);
+    let planes = (proj * view).extract_frustum_planes();
+    let inside = |p: Vector<f64, 3>| planes.iter().all(|plane| plane.signed_distance(&p) >= 0.0);
+
+    for plane in &planes {
+        assert!((plane.normal().l2_norm() - 1.0).abs() < 1e-12);
+    }
+    assert!(inside(vector![0.0, 0.0, 0.0]));
+    assert!(inside(vector![2.9, 2.9, 2.0]));
+    assert!(!inside(vector![3.1, 0.0, 2.0]));
+    assert!(!inside(vector![0.0, -3.1, 2.0]));
+    assert!(!inside(vector![0.0, 0.0, 4.5]));
+    assert!(!inside(vector![0.0, 0.0, -5.5]));
+    assert!((planes[4].signed_distance(&vector![0.0, 0.0, 3.0]) - 1.0).abs() < 1e-12);
+    assert!((planes[5].signed_distance(&vector![0.0, 0.0, 3.0]) - 8.0).abs() < 1e-12);
+}
+
+#[test]
+fn matrix_viewport() {
+    let m = Matrix::viewport(10.0, 20.0, 800.0, 600.0);
+    assert_eq!(
+        m * vector![-1.0, -1.0, -1.0, 1.0],
+        vector![10.0, 20.0, 0.0, 1.0]
+    );
+    assert_eq!(
+        m * vector![1.0, 1.0, 1.0, 1.0],
+        vector![810.0, 620.0, 1.0, 1.0]
+    );
+    let ndc = vector![0.25, -0.5, 0.5];
+    assert_eq!(
+        m.project_point(&ndc),
+        ndc.ndc_to_screen(10.0, 20.0, 800.0, 600.0)
+    );
+    assert_eq!(
+        ndc.ndc_to_screen(10.0, 20.0, 800.0, 600.0)
+            .screen_to_ndc(10.0, 20.0, 800.0, 600.0),
+        ndc
+    );
+}
+
+#[test]
+fn matrix_project_point_pipeline() {
+    let proj = Matrix::perspective(FRAC_PI_2, 2.0, 1.0, 10.0);
+    let view = Matrix::look_at_rh(
+        &vector![0.0, 0.0, 5.0],
+        &vector![0.0, 0.0, 0.0],
+        &vector![0.0, 1.0, 0.0],
+    );
+    let pipeline = Matrix::viewport(0.0, 0.0, 200.0, 100.0) * proj * view;
+    let center = pipeline.project_point(&vector![0.0, 0.0, 0.0]);
+    assert_close(&vector![center.x, center.y], &vector![100.0, 50.0], 1e-12);
+    let corner = pipeline.project_point(&vector![10.0, 5.0, 0.0]);
+    assert_close(&vector![corner.x, corner.y], &vector![200.0, 100.0], 1e-12);
+}
+
+#[test]
+fn matrix_screen_to_ray() {
+    let proj = Matrix::perspective(FRAC_PI_2, 2.0, 1.0, 10.0);
+    let view = Matrix::look_at_rh(
+        &vector![0.0, 0.0, 5.0],
+        &vector![0.0, 0.0, 0.0],
+        &vector![0.0, 1.0, 0.0],
+    );
+    let view_proj = proj * view;
+    let inv = view_proj.pseudo_inverse();
+
+    // the ray through a projected point passes through that point
+    let point = vector![1.5, -0.5, 1.0];
+    let screen = view_proj
+        .project_point(&point)
+        .ndc_to_screen(0.0, 0.0, 200.0, 100.0);
+    assert_close(
+        &inv.unproject(&screen, 0.0, 0.0, 200.0, 100.0),
+        &point,
+        1e-12,
+    );
+    let ray = inv.screen_to_ray(&vector![screen.x, screen.y], 0.0, 0.0, 200.0, 100.0);
+    assert!((ray.direction().l2_norm() - 1.0).abs() < 1e-12);
+    let t = (point - ray.origin()).l2_norm();
+    assert!((ray.at(t) - point).l2_norm() < 1e-9);
+
+    // rays start at the camera's near plane
+    let ray = inv.screen_to_ray(&vector![100.0, 50.0], 0.0, 0.0, 200.0, 100.0);
+    assert!((ray.origin() - vector![0.0, 0.0, 4.0]).l2_norm() < 1e-9);
+    assert!((ray.direction() - vector![0.0, 0.0, -1.0]).l2_norm() < 1e-9);
+}
@@ -0,0 +1,6 @@
+use vectrix::matrix;
+
+fn main() {
+    let m = matrix![1, 2, 3; 4, 5, 6];
+    let _d = m.diagonal();
+}
@@ -0,0 +1,6 @@
+use vectrix::{matrix, vector};
+
+fn main() {
+    let m = matrix![1, 2; 3, 4];
+    assert_eq!(m.diagonal(), vector![1, 4]);
+}
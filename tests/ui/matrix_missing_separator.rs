@@ -0,0 +1,5 @@
+use vectrix::matrix;
+
+fn main() {
+    let _m = matrix![1 2; 3, 4];
+}
@@ -0,0 +1,5 @@
+use vectrix::matrix;
+
+fn main() {
+    let _m = matrix![@rows [[1], [2, 3]]];
+}
@@ -0,0 +1,9 @@
+use vectrix::matrix;
+
+fn main() {
+    let _m = matrix![
+        1, 2;
+        #[allow(unused)]
+        3, 4;
+    ];
+}
@@ -0,0 +1,41 @@
+use vectrix::{vector, Unit};
+
+#[test]
+fn unit_new_normalize() {
+    let u = Unit::new_normalize(vector![0.0_f64, 3.0, -4.0]).unwrap();
+    assert_eq!(*u, vector![0.0, 0.6, -0.8]);
+    assert_eq!((u.x, u.y, u.z), (0.0, 0.6, -0.8));
+    assert!((u.l2_norm() - 1.0).abs() < 1e-12);
+    assert_eq!(u.into_inner(), vector![0.0, 0.6, -0.8]);
+}
+
+#[test]
+fn unit_new_normalize_zero() {
+    assert_eq!(Unit::new_normalize(vector![0.0_f32, 0.0]), None);
+}
+
+#[test]
+fn unit_new_normalize_not_finite() {
+    assert_eq!(Unit::new_normalize(vector![f64::NAN, 1.0]), None);
+    assert_eq!(Unit::new_normalize(vector![f64::INFINITY, 1.0]), None);
+}
+
+#[test]
+fn unit_try_new() {
+    let v = vector![1.0, 1.0] / 2.0_f64.sqrt();
+    assert_eq!(Unit::try_new(v, 1e-12).map(Unit::into_inner), Some(v));
+    assert_eq!(Unit::try_new(vector![1.0, 1.0], 1e-12), None);
+    assert!(Unit::try_new(vector![1.001, 0.0], 0.01).is_some());
+    // the tolerance applies to the length, not the squared length
+    assert!(Unit::try_new(vector![1.0015, 0.0], 0.002).is_some());
+    assert!(Unit::try_new(vector![0.0, 0.9985], 0.002).is_some());
+    assert!(Unit::try_new(vector![1.0025, 0.0], 0.002).is_none());
+    assert!(Unit::try_new(vector![f64::NAN, 0.0], 0.1).is_none());
+}
+
+#[test]
+fn unit_neg() {
+    let u = Unit::new_unchecked(vector![1.0, 0.0]);
+    assert_eq!(*-u, vector![-1.0, 0.0]);
+    assert_eq!(u.as_ref(), &vector![1.0, 0.0]);
+}
@@ -1,4 +1,4 @@
-use vectrix::{matrix, row_vector, vector, RowVector, Vector};
+use vectrix::{matrix, row_vector, vector, RowVector, Vector, X, XY, XYZ, XYZW, XYZWA, XYZWAB};
 
 ////////////////////////////////////////////////////////////////////////////////
 // Accessors
@@ -60,6 +60,141 @@ fn vector_components_mut() {
     assert_eq!(v[5], 0);
 }
 
+#[test]
+fn vector_comp() {
+    let v = vector![1, 2, 3, 4, 5, 6, 7, 8];
+    assert_eq!(*v.comp::<0>(), 1);
+    assert_eq!(*v.comp::<6>(), 7);
+    assert_eq!(*v.comp::<7>(), 8);
+}
+
+#[test]
+fn vector_comp_mut() {
+    let mut v = row_vector![1, 2, 3, 4, 5, 6, 7, 0];
+    *v.comp_mut::<7>() = 8;
+    assert_eq!(v, row_vector![1, 2, 3, 4, 5, 6, 7, 8]);
+}
+
+#[test]
+fn matrix_comp() {
+    let m = matrix![1, 2; 3, 4];
+    assert_eq!(*m.comp::<1>(), 3);
+}
+
+#[test]
+fn vector_destructure() {
+    let v = vector![1, 3, 3];
+    let XYZ { x, y, z } = *v;
+    assert_eq!((x, y, z), (1, 3, 3));
+
+    let XYZ { x, y, z } = XYZ::from(v);
+    assert_eq!((x, y, z), (1, 3, 3));
+}
+
+#[test]
+fn coord_new() {
+    assert_eq!(X::new(1), X { x: 1 });
+    assert_eq!(XY::new(1, 2), XY { x: 1, y: 2 });
+    assert_eq!(XYZ::new(1, 2, 3), XYZ { x: 1, y: 2, z: 3 });
+    assert_eq!(
+        XYZW::new(1, 2, 3, 4),
+        XYZW {
+            x: 1,
+            y: 2,
+            z: 3,
+            w: 4
+        }
+    );
+    assert_eq!(
+        XYZWA::new(1, 2, 3, 4, 5),
+        XYZWA {
+            x: 1,
+            y: 2,
+            z: 3,
+            w: 4,
+            a: 5
+        }
+    );
+    assert_eq!(
+        XYZWAB::new(1, 2, 3, 4, 5, 6),
+        XYZWAB {
+            x: 1,
+            y: 2,
+            z: 3,
+            w: 4,
+            a: 5,
+            b: 6
+        }
+    );
+}
+
+#[test]
+fn coord_from_vector() {
+    assert_eq!(X::from(vector![1]), X::new(1));
+    assert_eq!(XY::from(vector![1, 2]), XY::new(1, 2));
+    assert_eq!(XYZ::from(vector![1, 2, 3]), XYZ::new(1, 2, 3));
+    assert_eq!(XYZW::from(row_vector![1, 2, 3, 4]), XYZW::new(1, 2, 3, 4));
+    assert_eq!(
+        XYZWA::from(row_vector![1, 2, 3, 4, 5]),
+        XYZWA::new(1, 2, 3, 4, 5)
+    );
+    assert_eq!(
+        XYZWAB::from(vector![1, 2, 3, 4, 5, 6]),
+        XYZWAB::new(1, 2, 3, 4, 5, 6)
+    );
+}
+
+#[test]
+fn vector_from_coord() {
+    assert_eq!(Vector::from(XY::new(1, 2)), vector![1, 2]);
+    assert_eq!(RowVector::from(XY::new(1, 2)), row_vector![1, 2]);
+    assert_eq!(Vector::from(XYZ::new(1, 2, 3)), vector![1, 2, 3]);
+    assert_eq!(RowVector::from(XYZ::new(1, 2, 3)), row_vector![1, 2, 3]);
+    assert_eq!(
+        Vector::from(XYZWAB::new(1, 2, 3, 4, 5, 6)),
+        vector![1, 2, 3, 4, 5, 6]
+    );
+}
+
+#[test]
+fn coord_from_vector_not_copy() {
+    let v = vector![Box::new(1), Box::new(2)];
+    let XY { x, y } = XY::from(v);
+    assert_eq!((*x, *y), (1, 2));
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Reinterpretation
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn vector_as_row_vector() {
+    let v = vector![1, 3, 3, 7];
+    assert_eq!(v.as_row_vector(), &row_vector![1, 3, 3, 7]);
+    assert_eq!(v.as_row_vector().as_vector(), &v);
+}
+
+#[test]
+fn vector_as_mut_row_vector() {
+    let mut v = vector![1, 3, 3, 0];
+    v.as_mut_row_vector()[(0, 3)] = 7;
+    assert_eq!(v, vector![1, 3, 3, 7]);
+}
+
+#[test]
+fn row_vector_as_vector() {
+    let v = row_vector![1, 3, 3, 7];
+    assert_eq!(v.as_vector(), &vector![1, 3, 3, 7]);
+    assert_eq!(v.as_vector().as_row_vector(), &v);
+}
+
+#[test]
+fn row_vector_as_mut_vector() {
+    let mut v = row_vector![1, 3, 3, 0];
+    v.as_mut_vector()[(3, 0)] = 7;
+    assert_eq!(v, row_vector![1, 3, 3, 7]);
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Constructors
 ////////////////////////////////////////////////////////////////////////////////
@@ -76,6 +211,18 @@ fn row_vector_macro_repeat() {
     assert_eq!(v, matrix![7, 7, 7, 7]);
 }
 
+#[test]
+fn row_vector_macro_repeat_const_len() {
+    const LEN: usize = 3;
+    const V: RowVector<i64, { LEN * 2 }> = row_vector![7; LEN * 2];
+    assert_eq!(V, matrix![7, 7, 7, 7, 7, 7]);
+    assert_eq!(row_vector_repeat_generic::<2>(5), matrix![5, 5]);
+}
+
+fn row_vector_repeat_generic<const N: usize>(x: u8) -> RowVector<u8, N> {
+    row_vector![x; N]
+}
+
 #[test]
 fn row_vector_new() {
     type V<const N: usize> = RowVector<i64, N>;
@@ -109,6 +256,32 @@ fn row_vector_from_tuple() {
     assert_eq!(V::from((1, 2, 3, 4, 5, 6)), matrix![1, 2, 3, 4, 5, 6]);
 }
 
+#[test]
+fn row_vector_into_array() {
+    assert_eq!(<[i64; 1]>::from(matrix![1]), [1]);
+    assert_eq!(<[i64; 2]>::from(matrix![1, 2]), [1, 2]);
+    assert_eq!(<[i64; 3]>::from(matrix![1, 2, 3]), [1, 2, 3]);
+    assert_eq!(<[i64; 4]>::from(matrix![1, 2, 3, 4]), [1, 2, 3, 4]);
+    assert_eq!(<[i64; 5]>::from(matrix![1, 2, 3, 4, 5]), [1, 2, 3, 4, 5]);
+    assert_eq!(
+        <[i64; 6]>::from(matrix![1, 2, 3, 4, 5, 6]),
+        [1, 2, 3, 4, 5, 6]
+    );
+    assert_eq!(
+        <[i64; 7]>::from(row_vector![1, 2, 3, 4, 5, 6, 7]),
+        [1, 2, 3, 4, 5, 6, 7]
+    );
+}
+
+#[test]
+fn row_vector_into_tuple() {
+    assert_eq!(<(i64,)>::from(matrix![1]), (1,));
+    assert_eq!(<(i64, i64)>::from(matrix![1, 2]), (1, 2));
+    assert_eq!(<(i64, i64, i64)>::from(matrix![1, 2, 3]), (1, 2, 3));
+    let t: (i64, i64, i64, i64, i64, i64) = matrix![1, 2, 3, 4, 5, 6].into();
+    assert_eq!(t, (1, 2, 3, 4, 5, 6));
+}
+
 #[test]
 fn vector_macro() {
     let v = vector![1, 3, 3, 7];
@@ -121,6 +294,19 @@ fn vector_macro_repeat() {
     assert_eq!(v, matrix![7; 7; 7; 7]);
 }
 
+#[test]
+fn vector_macro_repeat_const_len() {
+    const LEN: usize = 3;
+    const V: Vector<i64, { LEN - 1 }> = vector![7; LEN - 1];
+    assert_eq!(V, matrix![7; 7]);
+    assert_eq!(vector_repeat_generic::<4>(2), matrix![2; 2; 2; 2]);
+    assert!(vector_repeat_generic::<0>(2).is_empty());
+}
+
+fn vector_repeat_generic<const M: usize>(x: u8) -> Vector<u8, M> {
+    vector![x; M]
+}
+
 #[test]
 fn vector_new() {
     type V<const M: usize> = Vector<i64, M>;
@@ -153,3 +339,174 @@ fn vector_from_tuple() {
     assert_eq!(V::from((1, 2, 3, 4, 5)), matrix![1; 2; 3; 4; 5]);
     assert_eq!(V::from((1, 2, 3, 4, 5, 6)), matrix![1; 2; 3; 4; 5; 6]);
 }
+
+#[test]
+fn vector_into_array() {
+    assert_eq!(<[i64; 2]>::from(matrix![1; 2]), [1, 2]);
+    assert_eq!(<[i64; 3]>::from(matrix![1; 2; 3]), [1, 2, 3]);
+    assert_eq!(<[i64; 4]>::from(matrix![1; 2; 3; 4]), [1, 2, 3, 4]);
+    assert_eq!(<[i64; 5]>::from(matrix![1; 2; 3; 4; 5]), [1, 2, 3, 4, 5]);
+    assert_eq!(
+        <[i64; 6]>::from(matrix![1; 2; 3; 4; 5; 6]),
+        [1, 2, 3, 4, 5, 6]
+    );
+    let v = vector![1.0_f32, 2.0, 3.0];
+    let arr: [f32; 3] = v.into();
+    assert_eq!(arr, [1.0, 2.0, 3.0]);
+    let arr: [i64; 8] = Vector::<i64, 8>::from_range(0, 1).into();
+    assert_eq!(arr, [0, 1, 2, 3, 4, 5, 6, 7]);
+}
+
+#[test]
+fn vector_into_tuple() {
+    assert_eq!(<(i64, i64)>::from(matrix![1; 2]), (1, 2));
+    assert_eq!(<(i64, i64, i64)>::from(matrix![1; 2; 3]), (1, 2, 3));
+    let t: (i64, i64, i64, i64, i64) = matrix![1; 2; 3; 4; 5].into();
+    assert_eq!(t, (1, 2, 3, 4, 5));
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Scans
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn vector_scan_in_place() {
+    let mut v = vector![1, 2, 3, 4];
+    v.scan_in_place(|prev, curr| *curr *= prev);
+    assert_eq!(v, vector![1, 2, 6, 24]);
+
+    let mut calls = 0;
+    let mut v = vector![5];
+    v.scan_in_place(|_, _| calls += 1);
+    assert_eq!((v, calls), (vector![5], 0));
+
+    let mut v: Vector<i64, 0> = vector![];
+    v.scan_in_place(|_, _| unreachable!());
+}
+
+#[test]
+fn vector_scan_in_place_not_copy() {
+    let mut v = vector![String::from("a"), String::from("b"), String::from("c")];
+    v.scan_in_place(|prev, curr| curr.insert_str(0, prev));
+    assert_eq!(v, vector!["a", "ab", "abc"].map(String::from));
+}
+
+#[test]
+fn vector_cumsum() {
+    assert_eq!(vector![1, 2, 3, 4].cumsum(), vector![1, 3, 6, 10]);
+    assert_eq!(vector![-1.5, 0.5, 1.0].cumsum(), vector![-1.5, -1.0, 0.0]);
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Sequences
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn vector_from_range() {
+    assert_eq!(Vector::<i64, 3>::from_range(0, 1), vector![0, 1, 2]);
+    assert_eq!(Vector::<i64, 4>::from_range(5, -2), vector![5, 3, 1, -1]);
+    assert_eq!(
+        Vector::<f32, 3>::from_range(1.0, 0.5),
+        vector![1.0, 1.5, 2.0]
+    );
+    assert!(Vector::<u8, 0>::from_range(1, 1).is_empty());
+}
+
+#[test]
+fn vector_linspace() {
+    assert_eq!(
+        Vector::<f64, 3>::linspace(-1.0, 1.0),
+        vector![-1.0, 0.0, 1.0]
+    );
+    assert_eq!(
+        Vector::<f64, 4>::linspace(3.0, 0.0),
+        vector![3.0, 2.0, 1.0, 0.0]
+    );
+    assert_eq!(Vector::<f64, 1>::linspace(2.0, 5.0), vector![2.0]);
+    assert!(Vector::<f64, 0>::linspace(2.0, 5.0).is_empty());
+    let v = Vector::<f32, 11>::linspace(0.0, 0.1);
+    assert_eq!(v[10], 0.1);
+    assert!((v[3] - 0.03).abs() < 1e-6);
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Cross product matrices
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn vector_skew_symmetric() {
+    let a = vector![2, -1, 5];
+    let m = a.skew_symmetric();
+    assert_eq!(m, a.hat());
+    for i in 0..3 {
+        for j in 0..3 {
+            assert_eq!(m[(i, j)], -m[(j, i)]);
+        }
+    }
+    // a × b computed by hand
+    let b = vector![3, 4, -2];
+    assert_eq!(m * b, vector![-18, 19, 11]);
+}
+
+#[test]
+fn matrix_vee() {
+    let m = matrix![
+        0.0, -3.0, 2.0;
+        3.0, 0.0, -1.0;
+        -2.0, 1.0, 0.0;
+    ];
+    assert_eq!(m.vee(), vector![1.0, 2.0, 3.0]);
+    assert_eq!(m.vee().hat(), m);
+}
+
+#[test]
+fn vector_swizzle() {
+    let v = vector![1, 2];
+    assert_eq!(v.yx(), vector![2, 1]);
+    assert_eq!(v.xyx(), vector![1, 2, 1]);
+    assert_eq!(v.xy0(), vector![1, 2, 0]);
+    assert_eq!(v.xy1(), vector![1, 2, 1]);
+
+    let v = vector![1, 2, 3];
+    assert_eq!(v.xz(), vector![1, 3]);
+    assert_eq!(v.zyx(), vector![3, 2, 1]);
+    assert_eq!(v.xyz0(), vector![1, 2, 3, 0]);
+    assert_eq!(v.xyz1(), vector![1, 2, 3, 1]);
+
+    let v = vector![1, 2, 3, 4];
+    assert_eq!(v.wz(), vector![4, 3]);
+    assert_eq!(v.xyz(), vector![1, 2, 3]);
+    assert_eq!(v.wwx(), vector![4, 4, 1]);
+}
+
+#[test]
+fn vector_extend() {
+    assert_eq!(vector![1].extend(2), vector![1, 2]);
+    assert_eq!(vector![1, 2].extend(3), vector![1, 2, 3]);
+    assert_eq!(vector![1, 2, 3].extend(4), vector![1, 2, 3, 4]);
+    assert_eq!(vector![1, 2, 3, 4, 5].extend(6), vector![1, 2, 3, 4, 5, 6]);
+}
+
+#[test]
+fn vector_truncate() {
+    assert_eq!(vector![1, 2].truncate(), vector![1]);
+    assert_eq!(vector![1, 2, 3, 4].truncate(), vector![1, 2, 3]);
+    assert_eq!(vector![1, 2, 3, 4, 5, 6].truncate(), vector![1, 2, 3, 4, 5]);
+    let v = vector![String::from("a"), String::from("b")];
+    assert_eq!(
+        v.truncate().extend(String::from("c")),
+        vector![String::from("a"), String::from("c")]
+    );
+}
+
+#[test]
+fn vector_clamp_length() {
+    let v = vector![0.0, -3.0, 4.0];
+    assert_eq!(v.clamp_length(0.0, 2.5), vector![0.0, -1.5, 2.0]);
+    assert_eq!(v.clamp_length(10.0, 100.0), vector![0.0, -6.0, 8.0]);
+    assert_eq!(v.clamp_length(5.0, 5.0), v);
+    assert_eq!(
+        Vector::<f64, 3>::zero().clamp_length(1.0, 2.0),
+        Vector::zero()
+    );
+}